@@ -0,0 +1,176 @@
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        image::{GImageWidgetExt, GImageWidgetRefExt},
+        label::GLabelWidgetExt,
+        loading::GLoadingWidgetExt,
+        markdown::{GMarkdownRef, GMarkdownWidgetExt},
+        view::{GView, GViewWidgetExt},
+    },
+    inherits_view_livehook, inherits_view_widget_node,
+    prop::{BubbleRole, manuel::SECONDARY},
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GBubbleBase = {{GBubble}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Right,
+                spacing: 8.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// a message of the chat, the template contains:
+/// - `avatar`: `GImage`
+/// - `main`: `name` label and `content` view, the `content` holds
+///   `loading` (`GLoading`, shown when typing) and `text` (`GMarkdown`)
+///
+/// use `append` to stream the tokens of the message into the bubble, the markdown is parsed
+/// incrementally (see `GMarkdown::append`) so a long answer costs the same for each token
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GBubble {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub role: BubbleRole,
+    /// markdown text of the message
+    #[live]
+    pub text: String,
+    #[live]
+    pub name: String,
+    /// src of the avatar, empty keeps the image of the template
+    #[live]
+    pub avatar_src: String,
+    /// waiting for the message, shows the loading instead of the text
+    #[live]
+    pub typing: bool,
+    /// the `text` markdown of the template, kept so that streaming does not look it up for each token
+    #[rust]
+    markdown: GMarkdownRef,
+}
+
+inherits_view_widget_node!(GBubble);
+
+impl Widget for GBubble {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        self.deref_widget.handle_event(cx, event, scope);
+    }
+}
+
+impl LiveHook for GBubble {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.markdown = self.gmarkdown(id!(text));
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GBubble {
+    fn render(&mut self, cx: &mut Cx) -> () {
+        self.sync_role();
+        let name = self.glabel(id!(name));
+        let _ = name.set_text(cx, self.name.to_string());
+        let _ = name.set_visible(cx, !self.name.is_empty());
+        name.set_default_font_color(cx, SECONDARY);
+        if !self.avatar_src.is_empty() {
+            if let Some(mut avatar) = self.gimage(id!(avatar)).borrow_mut() {
+                if let Err(e) = avatar.load(cx, &self.avatar_src) {
+                    error!("GBubble can not load avatar {}: {}", self.avatar_src, e);
+                }
+            }
+        }
+        self.markdown.set_text(cx, self.text.to_string());
+        self.sync_typing(cx);
+    }
+    /// the avatar is the last child for the user, the first one for others
+    fn sync_role(&mut self) -> () {
+        let user = self.role.is_user();
+        let children = &mut self.deref_widget.children;
+        if let Some(index) = children.iter().position(|(id, _)| *id == live_id!(avatar)) {
+            let avatar = children.remove(index);
+            if let Some(mut image) = avatar.1.as_gimage().borrow_mut() {
+                image.visible = self.role != BubbleRole::System;
+            }
+            if user {
+                children.push(avatar);
+            } else {
+                children.insert(0, avatar);
+            }
+        }
+        let x = match self.role {
+            BubbleRole::User => 1.0,
+            BubbleRole::Assistant => 0.0,
+            BubbleRole::System => 0.5,
+        };
+        self.deref_widget.style.basic.align.x = x;
+        if let Some(mut main) = self.gview(id!(main)).borrow_mut() {
+            main.style.basic.align.x = x;
+        }
+    }
+    fn sync_typing(&mut self, cx: &mut Cx) -> () {
+        if let Some(mut loading) = self.gloading(id!(loading)).borrow_mut() {
+            loading.visible = self.typing;
+            loading.set_loading(cx, self.typing);
+        }
+        if let Some(mut text) = self.markdown.borrow_mut() {
+            text.visible = !self.typing;
+        }
+        self.redraw(cx);
+    }
+    pub fn set_role(&mut self, cx: &mut Cx, role: BubbleRole) -> () {
+        self.role = role;
+        self.sync_role();
+        self.redraw(cx);
+    }
+    pub fn set_text(&mut self, cx: &mut Cx, text: String) -> () {
+        self.text = text;
+        self.markdown.set_text(cx, self.text.to_string());
+        self.set_typing(cx, false);
+    }
+    /// append a token of the streaming message, the typing state is finished.
+    /// Only the last blocks of the message are parsed again
+    pub fn append(&mut self, cx: &mut Cx, token: &str) -> () {
+        self.text.push_str(token);
+        self.markdown.append(cx, token);
+        self.set_typing(cx, false);
+    }
+    pub fn set_typing(&mut self, cx: &mut Cx, typing: bool) -> () {
+        if self.typing != typing {
+            self.typing = typing;
+            self.sync_typing(cx);
+        }
+    }
+}
+
+impl GBubbleRef {
+    pub fn set_role(&self, cx: &mut Cx, role: BubbleRole) -> () {
+        self.borrow_mut().map(|mut c| c.set_role(cx, role));
+    }
+    pub fn set_text(&self, cx: &mut Cx, text: String) -> () {
+        self.borrow_mut().map(|mut c| c.set_text(cx, text));
+    }
+    pub fn append(&self, cx: &mut Cx, token: &str) -> () {
+        self.borrow_mut().map(|mut c| c.append(cx, token));
+    }
+    pub fn set_typing(&self, cx: &mut Cx, typing: bool) -> () {
+        self.borrow_mut().map(|mut c| c.set_typing(cx, typing));
+    }
+    pub fn text(&self) -> String {
+        self.borrow().map_or(String::new(), |c| c.text.to_string())
+    }
+}
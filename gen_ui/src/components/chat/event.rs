@@ -0,0 +1,47 @@
+use makepad_widgets::*;
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum SenderEvent {
+    Send(SenderSend),
+    Stop(SenderStop),
+    Attach(SenderAttach),
+    AttachmentRemoved(SenderAttachmentRemoved),
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct SenderSend {
+    pub text: String,
+    /// the attachments added by `add_attachment`, cleared after sending
+    pub attachments: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SenderStop {
+    pub meta: FingerUpEvent,
+}
+
+/// the attach button is clicked, pick the files and add them by `add_attachment`
+#[derive(Debug, Clone)]
+pub struct SenderAttach {
+    pub meta: FingerUpEvent,
+}
+
+#[derive(Debug, Clone)]
+pub struct SenderAttachmentRemoved {
+    pub index: usize,
+    pub attachment: String,
+}
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum PromptsEvent {
+    Clicked(PromptsClicked),
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct PromptsClicked {
+    pub meta: FingerUpEvent,
+    pub index: usize,
+    pub prompt: String,
+}
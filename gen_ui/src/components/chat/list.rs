@@ -0,0 +1,157 @@
+use makepad_widgets::*;
+
+use crate::{
+    components::{chat::bubble::GBubbleWidgetRefExt, view::GView},
+    inherits_view_livehook, inherits_view_widget_node,
+    prop::BubbleRole,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GBubbleListBase = {{GBubbleList}} {
+        style: {
+            basic: {
+                height: Fill,
+                width: Fill,
+                flow: Down,
+                spacing: 16.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// the messages of the chat, each message is a `GBubble` created from the template of its role
+///
+/// ```rust,ignore
+/// let list = self.gbubble_list(id!(messages));
+/// list.push(cx, BubbleRole::User, "hello".to_string());
+/// let answer = list.push_typing(cx, BubbleRole::Assistant);
+/// // when the tokens arrive
+/// list.append(cx, answer, "Hi, ");
+/// ```
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GBubbleList {
+    #[deref]
+    pub deref_widget: GView,
+    /// keep the last message in view when messages are pushed or appended
+    #[live(true)]
+    pub stick_bottom: bool,
+    #[live]
+    user: Option<LivePtr>,
+    #[live]
+    assistant: Option<LivePtr>,
+    #[live]
+    system: Option<LivePtr>,
+    /// scroll to the bottom after the next draw (the height is unknown before)
+    #[rust]
+    scroll_bottom: bool,
+}
+
+inherits_view_widget_node!(GBubbleList);
+
+impl Widget for GBubbleList {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let step = self.deref_widget.draw_walk(cx, scope, walk);
+        if self.scroll_bottom {
+            self.scroll_bottom = false;
+            self.deref_widget.scroll_by(cx, dvec2(0.0, f64::MAX / 2.0));
+        }
+        step
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        self.deref_widget.handle_event(cx, event, scope);
+    }
+}
+
+impl LiveHook for GBubbleList {
+    inherits_view_livehook!();
+}
+
+impl GBubbleList {
+    /// push a message, returns the index of it
+    pub fn push(&mut self, cx: &mut Cx, role: BubbleRole, text: String) -> usize {
+        let bubble = self.create_bubble(cx, role);
+        bubble.set_text(cx, text);
+        self.push_bubble(cx, bubble)
+    }
+    /// push a typing message (such as the answer which is not arrived), returns the index of it
+    pub fn push_typing(&mut self, cx: &mut Cx, role: BubbleRole) -> usize {
+        let bubble = self.create_bubble(cx, role);
+        bubble.set_typing(cx, true);
+        self.push_bubble(cx, bubble)
+    }
+    /// append a token to the message, see `GBubble::append`
+    pub fn append(&mut self, cx: &mut Cx, index: usize, token: &str) -> () {
+        if let Some((_, bubble)) = self.deref_widget.children.get(index) {
+            bubble.as_gbubble().append(cx, token);
+            self.stick(cx);
+        }
+    }
+    pub fn set_typing(&mut self, cx: &mut Cx, index: usize, typing: bool) -> () {
+        if let Some((_, bubble)) = self.deref_widget.children.get(index) {
+            bubble.as_gbubble().set_typing(cx, typing);
+        }
+    }
+    pub fn bubble(&self, index: usize) -> Option<WidgetRef> {
+        self.deref_widget
+            .children
+            .get(index)
+            .map(|(_, bubble)| bubble.clone())
+    }
+    pub fn len(&self) -> usize {
+        self.deref_widget.children.len()
+    }
+    pub fn clear(&mut self, cx: &mut Cx) -> () {
+        self.deref_widget.children.clear();
+        self.redraw(cx);
+    }
+    fn create_bubble(&self, cx: &mut Cx, role: BubbleRole) -> WidgetRef {
+        let ptr = match role {
+            BubbleRole::User => self.user,
+            BubbleRole::Assistant => self.assistant,
+            BubbleRole::System => self.system,
+        };
+        let bubble = WidgetRef::new_from_ptr(cx, ptr);
+        bubble.as_gbubble().set_role(cx, role);
+        bubble
+    }
+    fn push_bubble(&mut self, cx: &mut Cx, bubble: WidgetRef) -> usize {
+        let index = self.deref_widget.children.len();
+        self.deref_widget
+            .children
+            .push((LiveId(index as u64 + 1), bubble));
+        self.stick(cx);
+        index
+    }
+    fn stick(&mut self, cx: &mut Cx) -> () {
+        self.scroll_bottom = self.stick_bottom;
+        self.redraw(cx);
+    }
+}
+
+impl GBubbleListRef {
+    pub fn push(&self, cx: &mut Cx, role: BubbleRole, text: String) -> usize {
+        self.borrow_mut().map_or(0, |mut c| c.push(cx, role, text))
+    }
+    pub fn push_typing(&self, cx: &mut Cx, role: BubbleRole) -> usize {
+        self.borrow_mut().map_or(0, |mut c| c.push_typing(cx, role))
+    }
+    pub fn append(&self, cx: &mut Cx, index: usize, token: &str) -> () {
+        self.borrow_mut().map(|mut c| c.append(cx, index, token));
+    }
+    pub fn set_typing(&self, cx: &mut Cx, index: usize, typing: bool) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.set_typing(cx, index, typing));
+    }
+    pub fn len(&self) -> usize {
+        self.borrow().map_or(0, |c| c.len())
+    }
+    pub fn clear(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.clear(cx));
+    }
+}
@@ -0,0 +1,15 @@
+pub mod bubble;
+mod event;
+pub mod list;
+pub mod prompts;
+mod register;
+pub mod sender;
+pub mod welcome;
+
+pub use bubble::*;
+pub use event::*;
+pub use list::*;
+pub use prompts::*;
+pub use register::register as chat_register;
+pub use sender::*;
+pub use welcome::*;
@@ -0,0 +1,122 @@
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        label::GLabelWidgetExt,
+        view::{GView, GViewWidgetRefExt},
+    },
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+};
+
+use super::event::{PromptsClicked, PromptsEvent};
+
+live_design! {
+    link genui_basic;
+
+    pub GPromptsBase = {{GPrompts}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: RightWrap,
+                spacing: 8.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// the suggestions of the prompt, each prompt is an `item` (`GView` with a `text` label)
+///
+/// ```rust,ignore
+/// <GPrompts>{
+///     prompts: ["What is GenUI?", "How to use GBubble?"]
+/// }
+/// ```
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GPrompts {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub prompts: Vec<String>,
+    /// template of the prompt
+    #[live]
+    item: Option<LivePtr>,
+    #[live(true)]
+    pub event_open: bool,
+}
+
+inherits_view_widget_node!(GPrompts);
+
+impl Widget for GPrompts {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if !self.event_open {
+            return;
+        }
+        let clicked =
+            self.deref_widget
+                .children
+                .iter()
+                .enumerate()
+                .find_map(|(index, (_, item))| {
+                    item.as_gview().clicked(&actions).map(|e| (index, e.meta))
+                });
+        if let Some((index, meta)) = clicked {
+            cx.widget_action(
+                self.widget_uid(),
+                &scope.path,
+                PromptsEvent::Clicked(PromptsClicked {
+                    meta,
+                    index,
+                    prompt: self.prompts[index].to_string(),
+                }),
+            );
+        }
+    }
+}
+
+impl LiveHook for GPrompts {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GPrompts {
+    /// rebuild the items from `prompts`
+    fn render(&mut self, cx: &mut Cx) -> () {
+        self.deref_widget.children.clear();
+        for (index, prompt) in self.prompts.iter().enumerate() {
+            let item = WidgetRef::new_from_ptr(cx, self.item);
+            let _ = item.glabel(id!(text)).set_text(cx, prompt.to_string());
+            self.deref_widget
+                .children
+                .push((LiveId(index as u64 + 1), item));
+        }
+        self.redraw(cx);
+    }
+    pub fn set_prompts(&mut self, cx: &mut Cx, prompts: Vec<String>) -> () {
+        self.prompts = prompts;
+        self.render(cx);
+    }
+    event_option! {
+        clicked: PromptsEvent::Clicked => PromptsClicked
+    }
+}
+
+impl GPromptsRef {
+    pub fn set_prompts(&self, cx: &mut Cx, prompts: Vec<String>) -> () {
+        self.borrow_mut().map(|mut c| c.set_prompts(cx, prompts));
+    }
+    event_option_ref! {
+        clicked => PromptsClicked
+    }
+}
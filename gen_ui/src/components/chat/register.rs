@@ -0,0 +1,9 @@
+use makepad_widgets::Cx;
+
+pub fn register(cx: &mut Cx) {
+    crate::components::chat::bubble::live_design(cx);
+    crate::components::chat::list::live_design(cx);
+    crate::components::chat::prompts::live_design(cx);
+    crate::components::chat::sender::live_design(cx);
+    crate::components::chat::welcome::live_design(cx);
+}
@@ -0,0 +1,192 @@
+use makepad_widgets::*;
+
+use crate::{
+    active_event,
+    components::{
+        GButtonWidgetExt, GTagWidgetRefExt,
+        area::GInputAreaWidgetExt,
+        view::{GView, GViewWidgetExt},
+    },
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+};
+
+use super::event::{SenderAttach, SenderAttachmentRemoved, SenderEvent, SenderSend, SenderStop};
+
+live_design! {
+    link genui_basic;
+
+    pub GSenderBase = {{GSender}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Down,
+                spacing: 6.0,
+            }
+        }
+    }
+}
+
+/// the input bar of the chat, the template contains:
+/// - `attachments`: the view of the attachment tags (created from `attachment`)
+/// - `attach`, `send` and `stop` buttons
+/// - `input`: multi-line `GInputArea`, `Enter` sends and `Shift + Enter` breaks the line
+///
+/// when `loading` (the answer is generating) the `stop` button replaces the `send` button
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GSender {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub loading: bool,
+    /// template of the attachment, a `GTag`
+    #[live]
+    attachment: Option<LivePtr>,
+    #[live(true)]
+    pub event_open: bool,
+    #[rust]
+    attachments: Vec<String>,
+}
+
+inherits_view_widget_node!(GSender);
+
+impl Widget for GSender {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if self.gbutton(id!(send)).clicked(&actions).is_some()
+            || self.ginput_area(id!(input)).returned(&actions).is_some()
+        {
+            self.submit(cx);
+        }
+        if let Some(e) = self.gbutton(id!(stop)).clicked(&actions) {
+            self.active_stop(cx, e.meta);
+        }
+        if let Some(e) = self.gbutton(id!(attach)).clicked(&actions) {
+            self.active_attach(cx, e.meta);
+        }
+        let closed = self.gview(id!(attachments)).borrow().and_then(|view| {
+            view.children
+                .iter()
+                .position(|(_, tag)| tag.as_gtag().close(&actions).is_some())
+        });
+        if let Some(index) = closed {
+            if let Some(attachment) = self.remove_attachment(cx, index) {
+                self.active_attachment_removed(cx, index, attachment);
+            }
+        }
+    }
+}
+
+impl LiveHook for GSender {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.sync_loading(cx);
+        self.sync_attachments(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GSender {
+    /// send the text and the attachments, nothing is sent while loading or empty
+    fn submit(&mut self, cx: &mut Cx) -> () {
+        let input = self.ginput_area(id!(input));
+        let text = input
+            .borrow()
+            .map_or(String::new(), |input| input.value.to_string());
+        if self.loading || (text.trim().is_empty() && self.attachments.is_empty()) {
+            return;
+        }
+        input.set_text(cx, "");
+        let attachments = std::mem::take(&mut self.attachments);
+        self.sync_attachments(cx);
+        self.active_send(cx, text, attachments);
+    }
+    active_event! {
+        active_send: SenderEvent::Send |text: String, attachments: Vec<String>| => SenderSend { text, attachments },
+        active_stop: SenderEvent::Stop |meta: FingerUpEvent| => SenderStop { meta },
+        active_attach: SenderEvent::Attach |meta: FingerUpEvent| => SenderAttach { meta },
+        active_attachment_removed: SenderEvent::AttachmentRemoved |index: usize, attachment: String| => SenderAttachmentRemoved { index, attachment }
+    }
+    pub fn set_loading(&mut self, cx: &mut Cx, loading: bool) -> () {
+        self.loading = loading;
+        self.sync_loading(cx);
+    }
+    fn sync_loading(&mut self, cx: &mut Cx) -> () {
+        let _ = self.gbutton(id!(send)).set_visible(cx, !self.loading);
+        let _ = self.gbutton(id!(stop)).set_visible(cx, self.loading);
+        self.redraw(cx);
+    }
+    pub fn add_attachment(&mut self, cx: &mut Cx, attachment: String) -> () {
+        self.attachments.push(attachment);
+        self.sync_attachments(cx);
+    }
+    pub fn remove_attachment(&mut self, cx: &mut Cx, index: usize) -> Option<String> {
+        if index >= self.attachments.len() {
+            return None;
+        }
+        let attachment = self.attachments.remove(index);
+        self.sync_attachments(cx);
+        Some(attachment)
+    }
+    pub fn attachments(&self) -> &[String] {
+        &self.attachments
+    }
+    /// rebuild the tags of the attachments
+    fn sync_attachments(&mut self, cx: &mut Cx) -> () {
+        if let Some(mut view) = self.gview(id!(attachments)).borrow_mut() {
+            view.children.clear();
+            for (index, attachment) in self.attachments.iter().enumerate() {
+                let tag = WidgetRef::new_from_ptr(cx, self.attachment);
+                tag.as_gtag().slot_text_mut(cx, |cx, label| {
+                    let _ = label.set_text(cx, attachment.to_string());
+                });
+                view.children.push((LiveId(index as u64 + 1), tag));
+            }
+            view.visible = !self.attachments.is_empty();
+        }
+        self.redraw(cx);
+    }
+    pub fn text(&self) -> String {
+        self.ginput_area(id!(input))
+            .borrow()
+            .map_or(String::new(), |input| input.value.to_string())
+    }
+    event_option! {
+        send: SenderEvent::Send => SenderSend,
+        stop: SenderEvent::Stop => SenderStop,
+        attach: SenderEvent::Attach => SenderAttach,
+        attachment_removed: SenderEvent::AttachmentRemoved => SenderAttachmentRemoved
+    }
+}
+
+impl GSenderRef {
+    pub fn set_loading(&self, cx: &mut Cx, loading: bool) -> () {
+        self.borrow_mut().map(|mut c| c.set_loading(cx, loading));
+    }
+    pub fn add_attachment(&self, cx: &mut Cx, attachment: String) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.add_attachment(cx, attachment));
+    }
+    pub fn remove_attachment(&self, cx: &mut Cx, index: usize) -> Option<String> {
+        self.borrow_mut()
+            .and_then(|mut c| c.remove_attachment(cx, index))
+    }
+    pub fn attachments(&self) -> Vec<String> {
+        self.borrow().map_or(vec![], |c| c.attachments.clone())
+    }
+    pub fn text(&self) -> String {
+        self.borrow().map_or(String::new(), |c| c.text())
+    }
+    event_option_ref! {
+        send => SenderSend,
+        stop => SenderStop,
+        attach => SenderAttach,
+        attachment_removed => SenderAttachmentRemoved
+    }
+}
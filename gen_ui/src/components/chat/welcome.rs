@@ -0,0 +1,96 @@
+use makepad_widgets::*;
+
+use crate::{
+    components::{image::GImageWidgetExt, label::GLabelWidgetExt, view::GView},
+    inherits_view_livehook, inherits_view_widget_node,
+    prop::manuel::SECONDARY,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GWelcomeBase = {{GWelcome}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Right,
+                spacing: 16.0,
+                align: {y: 0.5},
+            }
+        }
+    }
+}
+
+/// the welcome panel of the chat, the template contains `icon` (`GImage`),
+/// `title` and `description` labels, other children (such as `GPrompts`) can be added after them
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GWelcome {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub title: String,
+    #[live]
+    pub description: String,
+    /// src of the icon, empty keeps the image of the template
+    #[live]
+    pub icon_src: String,
+}
+
+inherits_view_widget_node!(GWelcome);
+
+impl Widget for GWelcome {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        self.deref_widget.handle_event(cx, event, scope);
+    }
+}
+
+impl LiveHook for GWelcome {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GWelcome {
+    fn render(&mut self, cx: &mut Cx) -> () {
+        let _ = self.glabel(id!(title)).set_text(cx, self.title.to_string());
+        let description = self.glabel(id!(description));
+        let _ = description.set_text(cx, self.description.to_string());
+        let _ = description.set_visible(cx, !self.description.is_empty());
+        description.set_default_font_color(cx, SECONDARY);
+        if !self.icon_src.is_empty() {
+            if let Some(mut icon) = self.gimage(id!(icon)).borrow_mut() {
+                if let Err(e) = icon.load(cx, &self.icon_src) {
+                    error!("GWelcome can not load icon {}: {}", self.icon_src, e);
+                }
+            }
+        }
+        self.redraw(cx);
+    }
+    pub fn set_title(&mut self, cx: &mut Cx, title: String) -> () {
+        self.title = title;
+        self.render(cx);
+    }
+    pub fn set_description(&mut self, cx: &mut Cx, description: String) -> () {
+        self.description = description;
+        self.render(cx);
+    }
+}
+
+impl GWelcomeRef {
+    pub fn set_title(&self, cx: &mut Cx, title: String) -> () {
+        self.borrow_mut().map(|mut c| c.set_title(cx, title));
+    }
+    pub fn set_description(&self, cx: &mut Cx, description: String) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.set_description(cx, description));
+    }
+}
@@ -1,6 +1,9 @@
 use super::event::{CheckboxGroupChanged, CheckboxGroupEvent};
 use crate::{
-    components::{checkbox::GCheckboxWidgetRefExt, view::GView},
+    components::{
+        checkbox::{CheckState, GCheckboxWidgetRefExt},
+        view::GView,
+    },
     inherits_view_livehook, inherits_view_widget_node,
 };
 use makepad_widgets::*;
@@ -49,6 +52,32 @@ impl Widget for GCheckboxGroup {
         };
         let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
 
+        // check all
+        let mut check_all = None;
+        for (_id, child) in self.children.iter() {
+            let _ = child.as_gcheckbox().borrow().map(|checkbox| {
+                if checkbox.check_all {
+                    if let Some(param) = checkbox.clicked(&actions) {
+                        check_all.replace(param);
+                    }
+                }
+            });
+        }
+        if let Some(param) = check_all {
+            self.check_all(cx, param.active);
+            let (index, value) = self.active_indexs();
+            cx.widget_action(
+                self.widget_uid(),
+                &scope.path,
+                CheckboxGroupEvent::Changed(CheckboxGroupChanged {
+                    meta: param.meta,
+                    value,
+                    index,
+                }),
+            );
+            return;
+        }
+
         let mut active_indexs = None;
         let mut active_values = None;
         let mut active_event = None;
@@ -71,6 +100,10 @@ impl Widget for GCheckboxGroup {
             }
         }
 
+        if !actions.is_empty() {
+            self.sync_check_all(cx);
+        }
+
         if active_event.is_some() {
             // let _ = self.toggle(cx, active_values.as_ref().unwrap().clone(), false);
             let _ = self.find_active();
@@ -111,7 +144,7 @@ impl GCheckboxGroup {
                         if child.value.is_empty() {
                             child.value = index.to_string();
                         }
-                        if child.active {
+                        if child.active && !child.check_all {
                             active.push(child.value.to_string());
                         }
                     } else {
@@ -136,11 +169,78 @@ impl GCheckboxGroup {
                     if child.value.is_empty() {
                         child.value = index.to_string();
                     }
-                    let active = self.active.contains(&child.value);
-                    child.toggle(cx, active, init);
+                    if !child.check_all {
+                        let active = self.active.contains(&child.value);
+                        child.toggle(cx, active, init);
+                    }
                 } else {
                     panic!("GCheckboxGroup only allows GCheckbox as child!")
                 }
             });
+        self.sync_check_all(cx);
+    }
+    /// check (or uncheck) all checkboxes of the group, disabled checkboxes are kept
+    pub fn check_all(&mut self, cx: &mut Cx, active: bool) -> () {
+        let mut values = self.active.clone();
+        for (_, child) in self.children.iter() {
+            if let Some(checkbox) = child.as_gcheckbox().borrow() {
+                if checkbox.check_all || checkbox.disabled {
+                    continue;
+                }
+                values.retain(|v| v != &checkbox.value);
+                if active {
+                    values.push(checkbox.value.to_string());
+                }
+            }
+        }
+        self.toggle(cx, values, false);
+    }
+    /// `Checked` if all checkboxes are checked, `Indeterminate` if only part of them
+    pub fn check_state(&self) -> CheckState {
+        let (checked, total) = self
+            .children
+            .iter()
+            .filter_map(|(_, child)| {
+                child
+                    .as_gcheckbox()
+                    .borrow()
+                    .and_then(|checkbox| (!checkbox.check_all).then(|| checkbox.active))
+            })
+            .fold((0, 0), |(checked, total), active| {
+                (checked + active as usize, total + 1)
+            });
+        CheckState::from_count(checked, total)
+    }
+    /// update the `check_all` checkbox by the other checkboxes
+    fn sync_check_all(&mut self, cx: &mut Cx) -> () {
+        let state = self.check_state();
+        for (_, child) in self.children.iter() {
+            if let Some(mut checkbox) = child.as_gcheckbox().borrow_mut() {
+                if checkbox.check_all {
+                    checkbox.set_check_state(cx, state);
+                }
+            }
+        }
+    }
+    fn active_indexs(&self) -> (Vec<i32>, Vec<String>) {
+        self.children
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (_, child))| {
+                child.as_gcheckbox().borrow().and_then(|checkbox| {
+                    (checkbox.active && !checkbox.check_all)
+                        .then(|| (index as i32, checkbox.value.to_string()))
+                })
+            })
+            .unzip()
+    }
+}
+
+impl GCheckboxGroupRef {
+    pub fn check_all(&self, cx: &mut Cx, active: bool) -> () {
+        self.borrow_mut().map(|mut c| c.check_all(cx, active));
+    }
+    pub fn check_state(&self) -> CheckState {
+        self.borrow().map_or(CheckState::Unchecked, |c| c.check_state())
     }
 }
@@ -136,6 +136,13 @@ pub struct GCheckbox {
     // specific value of the checkbox, can be used to identify the checkbox
     #[live]
     pub value: String,
+    /// partially checked, drawn as a dash with the active style, clicking makes it checked
+    #[live]
+    pub indeterminate: bool,
+    /// in `GCheckboxGroup`: check/uncheck all other checkboxes of the group, and show
+    /// indeterminate when only part of them are checked
+    #[live]
+    pub check_all: bool,
     #[rust]
     pub state: CheckboxState,
 }
@@ -305,7 +312,7 @@ impl Component for GCheckbox {
         if self.disabled {
             self.switch_state(CheckboxState::Disabled);
         } else {
-            if self.active {
+            if self.active || self.indeterminate {
                 self.switch_state(CheckboxState::Active);
             } else {
                 self.switch_state(CheckboxState::Basic);
@@ -315,6 +322,7 @@ impl Component for GCheckbox {
         let style = self.style.get(state);
         self.draw_container.merge(&style.container);
         self.draw_checkbox.merge(&style.checkbox);
+        self.draw_checkbox.indeterminate = self.indeterminate.to_f32();
         let _ = self.extra.render(cx)?;
         Ok(())
     }
@@ -339,14 +347,14 @@ impl Component for GCheckbox {
             }
             Hit::FingerHoverIn(e) => {
                 cx.set_cursor(self.style.get(self.state).container.cursor);
-                if !self.active {
+                if !self.active && !self.indeterminate {
                     self.switch_state_with_animation(cx, CheckboxState::Hover);
                     self.play_animation(cx, id!(hover.on));
                 }
                 self.active_hover_in(cx, e);
             }
             Hit::FingerHoverOut(e) => {
-                if !self.active {
+                if !self.active && !self.indeterminate {
                     self.switch_state_with_animation(cx, CheckboxState::Basic);
                     self.play_animation(cx, id!(hover.off));
                 }
@@ -355,12 +363,14 @@ impl Component for GCheckbox {
             Hit::FingerUp(e) => {
                 if e.is_over {
                     if e.has_hovers() {
-                        let (state_an, state) = if self.active {
+                        // indeterminate -> checked
+                        let (state_an, state) = if self.active && !self.indeterminate {
                             (id!(hover.off), CheckboxState::Basic)
                         } else {
                             (id!(hover.active), CheckboxState::Active)
                         };
-                        self.active = !self.active;
+                        self.active = self.indeterminate || !self.active;
+                        self.indeterminate = false;
                         self.switch_state_with_animation(cx, state);
                         self.play_animation(cx, state_an);
                     } else {
@@ -662,6 +672,7 @@ impl GCheckbox {
     }
     pub fn toggle(&mut self, cx: &mut Cx, active: bool, init: bool) -> () {
         self.active = active;
+        self.indeterminate = false;
         let (state, hover_id) = match (active, init) {
             (true, false) => (CheckboxState::Active, Some(id!(hover.active))),
             (true, true) => (CheckboxState::Active, None),
@@ -674,4 +685,39 @@ impl GCheckbox {
         }
         self.active_clicked(cx, None);
     }
+    pub fn check_state(&self) -> CheckState {
+        if self.indeterminate {
+            CheckState::Indeterminate
+        } else if self.active {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+    /// set check state without emitting `Clicked`, used by cascading (tree, check all)
+    pub fn set_check_state(&mut self, cx: &mut Cx, state: CheckState) -> () {
+        if self.check_state() == state {
+            return;
+        }
+        self.active = state == CheckState::Checked;
+        self.indeterminate = state == CheckState::Indeterminate;
+        let (state, hover_id) = if self.active || self.indeterminate {
+            (CheckboxState::Active, id!(hover.active))
+        } else {
+            (CheckboxState::Basic, id!(hover.off))
+        };
+        self.switch_state(state);
+        self.play_animation(cx, hover_id);
+        self.redraw(cx);
+    }
+}
+
+impl GCheckboxRef {
+    pub fn check_state(&self) -> CheckState {
+        self.borrow()
+            .map_or(CheckState::Unchecked, |c| c.check_state())
+    }
+    pub fn set_check_state(&self, cx: &mut Cx, state: CheckState) -> () {
+        self.borrow_mut().map(|mut c| c.set_check_state(cx, state));
+    }
 }
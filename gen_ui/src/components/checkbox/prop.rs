@@ -461,3 +461,39 @@ component_part! {
         Extra => extra => EXTRA
     }, CheckboxState
 }
+
+/// Check state of `GCheckbox`, `Indeterminate` means partially checked
+/// (some children are checked in a tree or a group)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl CheckState {
+    /// state of a parent from the count of its checked children
+    pub fn from_count(checked: usize, total: usize) -> Self {
+        if total == 0 || checked == 0 {
+            CheckState::Unchecked
+        } else if checked == total {
+            CheckState::Checked
+        } else {
+            CheckState::Indeterminate
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CheckState;
+
+    #[test]
+    fn check_state_from_count() {
+        assert_eq!(CheckState::from_count(0, 0), CheckState::Unchecked);
+        assert_eq!(CheckState::from_count(0, 3), CheckState::Unchecked);
+        assert_eq!(CheckState::from_count(1, 3), CheckState::Indeterminate);
+        assert_eq!(CheckState::from_count(3, 3), CheckState::Checked);
+    }
+}
@@ -0,0 +1,14 @@
+use makepad_widgets::*;
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum CodeEvent {
+    Copied(CodeCopied),
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct CodeCopied {
+    pub meta: FingerUpEvent,
+    /// the copied code
+    pub code: String,
+}
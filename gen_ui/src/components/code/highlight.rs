@@ -0,0 +1,417 @@
+use crate::themes::Theme;
+
+/// languages highlighted by `GCode`, other languages are shown as plain text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CodeLang {
+    Rust,
+    Toml,
+    Json,
+    Js,
+    Ts,
+    Python,
+    Shell,
+    #[default]
+    Plain,
+}
+
+impl CodeLang {
+    /// the name or extension of the language, case insensitive
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "rust" | "rs" => CodeLang::Rust,
+            "toml" => CodeLang::Toml,
+            "json" | "jsonc" => CodeLang::Json,
+            "js" | "javascript" | "jsx" | "mjs" => CodeLang::Js,
+            "ts" | "typescript" | "tsx" => CodeLang::Ts,
+            "py" | "python" => CodeLang::Python,
+            "sh" | "shell" | "bash" | "zsh" | "console" => CodeLang::Shell,
+            _ => CodeLang::Plain,
+        }
+    }
+    fn line_comment(&self) -> Option<&'static str> {
+        match self {
+            CodeLang::Rust | CodeLang::Js | CodeLang::Ts => Some("//"),
+            CodeLang::Toml | CodeLang::Python | CodeLang::Shell => Some("#"),
+            CodeLang::Json | CodeLang::Plain => None,
+        }
+    }
+    fn block_comment(&self) -> bool {
+        matches!(self, CodeLang::Rust | CodeLang::Js | CodeLang::Ts)
+    }
+    /// keywords split by whitespace
+    fn keywords(&self) -> &'static str {
+        match self {
+            CodeLang::Rust => {
+                "as async await break const continue crate dyn else enum extern fn for if impl in \
+                 let loop match mod move mut pub ref return self Self static struct super trait \
+                 type unsafe use where while"
+            }
+            CodeLang::Js => JS_KEYWORDS,
+            CodeLang::Ts => {
+                "abstract any as async await boolean break case catch class const continue \
+                 declare default delete do else enum export extends finally for from function \
+                 if implements import in instanceof interface keyof let namespace new number of \
+                 private protected public readonly return static string super switch this \
+                 throw try type typeof var void while yield"
+            }
+            CodeLang::Python => {
+                "and as assert async await break class continue def del elif else except \
+                 finally for from global if import in is lambda nonlocal not or pass raise \
+                 return self try while with yield"
+            }
+            CodeLang::Shell => {
+                "case do done elif else esac export fi for function if in local return then \
+                 until while"
+            }
+            CodeLang::Toml | CodeLang::Json | CodeLang::Plain => "",
+        }
+    }
+    fn is_keyword(&self, word: &str) -> bool {
+        self.keywords()
+            .split_whitespace()
+            .any(|keyword| keyword == word)
+    }
+}
+
+const JS_KEYWORDS: &str = "async await break case catch class const continue debugger default \
+                           delete do else export extends finally for from function if import \
+                           in instanceof let new of return static super switch this throw try \
+                           typeof var void while with yield";
+
+const CONSTANTS: &str = "true false null undefined None True False NaN";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Keyword,
+    String,
+    Number,
+    Comment,
+    Function,
+    Type,
+    Constant,
+    Punct,
+    Plain,
+}
+
+impl TokenKind {
+    /// the palette of the token, the level is set by `token_level` of `GCode`,
+    /// `None` uses the color of the code label
+    pub fn theme(&self) -> Option<Theme> {
+        match self {
+            TokenKind::Keyword => Some(Theme::Primary),
+            TokenKind::String => Some(Theme::Success),
+            TokenKind::Number | TokenKind::Type => Some(Theme::Warning),
+            TokenKind::Comment => Some(Theme::Dark),
+            TokenKind::Function => Some(Theme::Info),
+            TokenKind::Constant => Some(Theme::Error),
+            TokenKind::Punct | TokenKind::Plain => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeToken {
+    pub kind: TokenKind,
+    pub text: String,
+}
+
+impl CodeToken {
+    pub fn new<T>(kind: TokenKind, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            kind,
+            text: text.into(),
+        }
+    }
+}
+
+/// highlight the code and split it into lines, the tokens do not contain `\n`
+pub fn highlight(code: &str, lang: CodeLang) -> Vec<Vec<CodeToken>> {
+    let mut lexer = Lexer {
+        chars: code.chars().collect(),
+        i: 0,
+        lang,
+        line_start: true,
+        lines: vec![vec![]],
+    };
+    lexer.run();
+    lexer.lines
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    i: usize,
+    lang: CodeLang,
+    /// no token but whitespace before in the current line
+    line_start: bool,
+    lines: Vec<Vec<CodeToken>>,
+}
+
+impl Lexer {
+    fn run(&mut self) -> () {
+        while self.i < self.chars.len() {
+            let c = self.chars[self.i];
+            let start = self.i;
+            if c.is_whitespace() {
+                self.take_while(|c| c.is_whitespace());
+                self.push(TokenKind::Plain, start);
+                continue;
+            }
+            let kind = if self.lang == CodeLang::Plain {
+                self.take_while(|c| !c.is_whitespace());
+                TokenKind::Plain
+            } else if self
+                .lang
+                .line_comment()
+                .map_or(false, |mark| self.starts_with(mark))
+            {
+                self.take_while(|c| c != '\n');
+                TokenKind::Comment
+            } else if self.lang.block_comment() && self.starts_with("/*") {
+                self.take_until("*/");
+                TokenKind::Comment
+            } else if self.lang == CodeLang::Python
+                && (self.starts_with("\"\"\"") || self.starts_with("'''"))
+            {
+                let quote: String = self.chars[start..start + 3].iter().collect();
+                self.i += 3;
+                self.take_until(&quote);
+                TokenKind::String
+            } else if self.lang == CodeLang::Rust && c == '\'' {
+                self.rust_quote()
+            } else if matches!(c, '"' | '\'' | '`') {
+                self.string(c);
+                if self.lang == CodeLang::Json && self.next_char() == Some(':') {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::String
+                }
+            } else if self.lang == CodeLang::Toml && c == '[' && self.line_start {
+                self.take_while(|c| c != ']' && c != '\n');
+                self.i = (self.i + 1).min(self.chars.len());
+                TokenKind::Type
+            } else if self.lang == CodeLang::Shell && c == '$' {
+                self.i += 1;
+                match self.chars.get(self.i) {
+                    Some('{') => self.take_until("}"),
+                    Some(c) if !is_ident(*c) => self.i += 1,
+                    _ => self.take_while(is_ident),
+                }
+                TokenKind::Constant
+            } else if c.is_ascii_digit() {
+                self.take_while(|c| is_ident(c) || c == '.');
+                TokenKind::Number
+            } else if is_ident(c) || (c == '$' && matches!(self.lang, CodeLang::Js | CodeLang::Ts))
+            {
+                // keys of toml can contain `-`
+                let toml = self.lang == CodeLang::Toml;
+                self.i += 1;
+                self.take_while(|c| is_ident(c) || (toml && c == '-'));
+                self.word(start)
+            } else {
+                self.i += 1;
+                TokenKind::Punct
+            };
+            self.push(kind, start);
+            self.line_start = false;
+        }
+    }
+    fn word(&self, start: usize) -> TokenKind {
+        let word: String = self.chars[start..self.i].iter().collect();
+        let word = word.as_str();
+        if self.lang.is_keyword(word) {
+            TokenKind::Keyword
+        } else if CONSTANTS
+            .split_whitespace()
+            .any(|constant| constant == word)
+        {
+            TokenKind::Constant
+        } else if self.lang == CodeLang::Toml {
+            // keys of the table
+            if self.line_start && matches!(self.next_char(), Some('=' | '.')) {
+                TokenKind::Keyword
+            } else {
+                TokenKind::Plain
+            }
+        } else if self.lang == CodeLang::Shell && self.line_start {
+            // the command
+            TokenKind::Function
+        } else if matches!(self.next_char(), Some('(')) || self.chars.get(self.i) == Some(&'!') {
+            TokenKind::Function
+        } else if word.starts_with(|c: char| c.is_uppercase()) && self.lang != CodeLang::Shell {
+            TokenKind::Type
+        } else {
+            TokenKind::Plain
+        }
+    }
+    /// char literal or lifetime
+    fn rust_quote(&mut self) -> TokenKind {
+        let start = self.i;
+        self.i += 1;
+        match (self.chars.get(self.i), self.chars.get(self.i + 1)) {
+            (Some('\\'), _) => {
+                self.i += 1;
+                self.take_until("'");
+                TokenKind::String
+            }
+            (Some(_), Some('\'')) => {
+                self.i += 2;
+                TokenKind::String
+            }
+            _ => {
+                self.take_while(is_ident);
+                if self.i == start + 1 {
+                    TokenKind::Punct
+                } else {
+                    TokenKind::Keyword
+                }
+            }
+        }
+    }
+    /// the string with the escapes, the quotes of some languages end at the end of the line
+    fn string(&mut self, quote: char) -> () {
+        let multiline = quote == '`' || matches!(self.lang, CodeLang::Rust | CodeLang::Shell);
+        self.i += 1;
+        while let Some(&c) = self.chars.get(self.i) {
+            if c == '\n' && !multiline {
+                return;
+            }
+            self.i += 1;
+            if c == '\\' {
+                self.i += 1;
+            } else if c == quote {
+                break;
+            }
+        }
+        self.i = self.i.min(self.chars.len());
+    }
+    /// move to the end of the mark (or the end of the code)
+    fn take_until(&mut self, mark: &str) -> () {
+        while self.i < self.chars.len() && !self.starts_with(mark) {
+            self.i += 1;
+        }
+        self.i = (self.i + mark.chars().count()).min(self.chars.len());
+    }
+    fn take_while<F>(&mut self, f: F) -> ()
+    where
+        F: Fn(char) -> bool,
+    {
+        while self.i < self.chars.len() && f(self.chars[self.i]) {
+            self.i += 1;
+        }
+    }
+    fn starts_with(&self, mark: &str) -> bool {
+        mark.chars()
+            .enumerate()
+            .all(|(offset, c)| self.chars.get(self.i + offset) == Some(&c))
+    }
+    /// the next char which is not a space or tab
+    fn next_char(&self) -> Option<char> {
+        self.chars[self.i..]
+            .iter()
+            .copied()
+            .find(|c| !matches!(c, ' ' | '\t'))
+    }
+    /// push the chars from `start` to the current index, split by lines
+    fn push(&mut self, kind: TokenKind, start: usize) -> () {
+        let text: String = self.chars[start..self.i].iter().collect();
+        for (index, part) in text.split('\n').enumerate() {
+            if index > 0 {
+                self.lines.push(vec![]);
+                self.line_start = true;
+            }
+            if part.is_empty() {
+                continue;
+            }
+            let line = self.lines.last_mut().unwrap();
+            match line.last_mut() {
+                Some(last) if last.kind == kind && kind == TokenKind::Plain => {
+                    last.text.push_str(part)
+                }
+                _ => line.push(CodeToken::new(kind, part)),
+            }
+        }
+    }
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CodeLang, CodeToken, TokenKind, highlight};
+
+    fn kinds(code: &str, lang: CodeLang) -> Vec<(TokenKind, String)> {
+        highlight(code, lang)
+            .into_iter()
+            .flatten()
+            .filter(|token| !token.text.trim().is_empty())
+            .map(|token| (token.kind, token.text))
+            .collect()
+    }
+
+    #[test]
+    fn highlight_rust_test() {
+        let lines = highlight(
+            "fn main() {\n    // hi\n    let s: &'a str = \"x\";\n}",
+            CodeLang::Rust,
+        );
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0][..3],
+            [
+                CodeToken::new(TokenKind::Keyword, "fn"),
+                CodeToken::new(TokenKind::Plain, " "),
+                CodeToken::new(TokenKind::Function, "main"),
+            ]
+        );
+        assert_eq!(lines[1][1], CodeToken::new(TokenKind::Comment, "// hi"));
+        assert!(lines[2].contains(&CodeToken::new(TokenKind::Keyword, "'a")));
+        assert!(lines[2].contains(&CodeToken::new(TokenKind::String, "\"x\"")));
+    }
+
+    #[test]
+    fn highlight_langs_test() {
+        assert_eq!(
+            kinds(
+                "[package]\nname = \"gen_ui\" # name\nversion = 1",
+                CodeLang::Toml
+            ),
+            vec![
+                (TokenKind::Type, "[package]".to_string()),
+                (TokenKind::Keyword, "name".to_string()),
+                (TokenKind::Punct, "=".to_string()),
+                (TokenKind::String, "\"gen_ui\"".to_string()),
+                (TokenKind::Comment, "# name".to_string()),
+                (TokenKind::Keyword, "version".to_string()),
+                (TokenKind::Punct, "=".to_string()),
+                (TokenKind::Number, "1".to_string()),
+            ]
+        );
+        assert_eq!(
+            kinds("{\"a\": true}", CodeLang::Json),
+            vec![
+                (TokenKind::Punct, "{".to_string()),
+                (TokenKind::Keyword, "\"a\"".to_string()),
+                (TokenKind::Punct, ":".to_string()),
+                (TokenKind::Constant, "true".to_string()),
+                (TokenKind::Punct, "}".to_string()),
+            ]
+        );
+        assert_eq!(
+            kinds("cargo run $HOME", CodeLang::Shell),
+            vec![
+                (TokenKind::Function, "cargo".to_string()),
+                (TokenKind::Plain, " run ".to_string()),
+                (TokenKind::Constant, "$HOME".to_string()),
+            ]
+        );
+        // multiline strings are split by lines
+        let lines = highlight("s = \"\"\"a\nb\"\"\"", CodeLang::Python);
+        assert_eq!(lines[1], vec![CodeToken::new(TokenKind::String, "b\"\"\"")]);
+        assert_eq!(CodeLang::from_name("TSX"), CodeLang::Ts);
+    }
+}
@@ -0,0 +1,208 @@
+mod event;
+mod highlight;
+
+pub use event::*;
+pub use highlight::*;
+
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        GButtonWidgetExt, GCollapseWidgetExt,
+        label::{GLabelWidgetExt, RichSpan},
+        view::GView,
+    },
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+    prop::manuel::{PLACEHOLDER, SECONDARY},
+    themes::ColorFontConf,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GCodeBase = {{GCode}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Down,
+            }
+        }
+    }
+}
+
+/// syntax-highlighted code block, the template contains:
+/// - `header`: `lang` label and `copy` button
+/// - `body`: `numbers` and `code` labels, `code` scrolls horizontally
+/// - `more`: a `GCollapse` holds the lines after `fold_lines` (`more_numbers`, `more_code`),
+///   `more_text` is the label of its header
+///
+/// `lang`, `more_text` and the line numbers use the secondary and placeholder font colors of the theme
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GCode {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub code: String,
+    /// name or extension of the language, see `CodeLang::from_name`
+    #[live]
+    pub lang: String,
+    #[live(true)]
+    pub line_numbers: bool,
+    /// palette level of the token colors
+    #[live(300)]
+    pub token_level: u32,
+    /// lines after it are folded into `more`, 0 never folds
+    #[live(20)]
+    pub fold_lines: usize,
+    #[live(true)]
+    pub event_open: bool,
+    /// count of the folded lines
+    #[rust]
+    folded: usize,
+}
+
+inherits_view_widget_node!(GCode);
+
+impl Widget for GCode {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if let Some(e) = self.deref_widget.gbutton(id!(copy)).clicked(&actions) {
+            cx.copy_to_clipboard(&self.code);
+            if self.event_open {
+                cx.widget_action(
+                    self.widget_uid(),
+                    &scope.path,
+                    CodeEvent::Copied(CodeCopied {
+                        meta: e.meta,
+                        code: self.code.to_string(),
+                    }),
+                );
+            }
+        }
+        if let Some(e) = self.deref_widget.gcollapse(id!(more)).changed(&actions) {
+            self.set_more_text(cx, e.active);
+        }
+    }
+}
+
+impl LiveHook for GCode {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GCode {
+    pub fn set_code(&mut self, cx: &mut Cx, code: String) -> () {
+        self.code = code;
+        self.render(cx);
+    }
+    pub fn set_lang(&mut self, cx: &mut Cx, lang: String) -> () {
+        self.lang = lang;
+        self.render(cx);
+    }
+    fn render(&mut self, cx: &mut Cx) -> () {
+        let lang = CodeLang::from_name(&self.lang);
+        let lines = highlight(self.code.trim_end_matches('\n'), lang);
+        let fold = if self.fold_lines > 0 && lines.len() > self.fold_lines {
+            self.fold_lines
+        } else {
+            lines.len()
+        };
+        let (head, tail) = lines.split_at(fold);
+        self.folded = tail.len();
+        // numbers of both parts have the same width, so the code is aligned
+        let width = lines.len().to_string().len();
+        self.set_lines(cx, id!(numbers), id!(code), head, 1, width);
+        self.set_lines(cx, id!(more_numbers), id!(more_code), tail, fold + 1, width);
+
+        let lang = if self.lang.is_empty() {
+            "text".to_string()
+        } else {
+            self.lang.to_string()
+        };
+        self.deref_widget
+            .glabel(id!(lang))
+            .set_spans(cx, vec![font_span(lang, SECONDARY)]);
+        let mut opened = false;
+        if let Some(mut more) = self.deref_widget.gcollapse(id!(more)).borrow_mut() {
+            more.visible = self.folded > 0;
+            opened = more.active;
+        }
+        self.set_more_text(cx, opened);
+        self.redraw(cx);
+    }
+    fn set_lines(
+        &mut self,
+        cx: &mut Cx,
+        numbers: &[LiveId],
+        code: &[LiveId],
+        lines: &[Vec<CodeToken>],
+        start: usize,
+        width: usize,
+    ) -> () {
+        let text = (start..start + lines.len())
+            .map(|number| format!("{:>width$}", number, width = width))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let numbers = self.deref_widget.glabel(numbers);
+        numbers.set_spans(cx, vec![font_span(text, PLACEHOLDER)]);
+        let _ = numbers.set_visible(cx, self.line_numbers);
+
+        let mut spans = vec![];
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                spans.push(RichSpan::new("\n"));
+            }
+            for token in line.iter() {
+                let span = RichSpan::new(token.text.as_str());
+                spans.push(match token.kind.theme() {
+                    Some(theme) => span.color(theme.color(self.token_level).into()),
+                    None => span,
+                });
+            }
+        }
+        self.deref_widget.glabel(code).set_spans(cx, spans);
+    }
+    fn set_more_text(&mut self, cx: &mut Cx, opened: bool) -> () {
+        let text = if opened {
+            "Show less".to_string()
+        } else {
+            format!("Show {} more lines", self.folded)
+        };
+        self.deref_widget
+            .glabel(id!(more_text))
+            .set_spans(cx, vec![font_span(text, SECONDARY)]);
+    }
+    event_option! {
+        copied: CodeEvent::Copied => CodeCopied
+    }
+}
+
+/// span colored by the font color `key` of the theme
+fn font_span(text: String, key: &str) -> RichSpan {
+    RichSpan::new(text).color(ColorFontConf::from_key(key).into())
+}
+
+impl GCodeRef {
+    pub fn set_code(&self, cx: &mut Cx, code: String) -> () {
+        self.borrow_mut().map(|mut c| c.set_code(cx, code));
+    }
+    pub fn set_lang(&self, cx: &mut Cx, lang: String) -> () {
+        self.borrow_mut().map(|mut c| c.set_lang(cx, lang));
+    }
+    pub fn code(&self) -> String {
+        self.borrow().map_or(String::new(), |c| c.code.to_string())
+    }
+    event_option_ref! {
+        copied => CodeCopied
+    }
+}
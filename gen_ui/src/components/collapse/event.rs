@@ -23,3 +23,18 @@ pub struct CollapseChanged {
     pub meta: Option<FingerUpEvent>,
     pub active: bool,
 }
+
+#[derive(Debug, Clone, DefaultNone)]
+pub enum CollapseGroupEvent {
+    Changed(CollapseGroupChanged),
+    None,
+}
+
+#[derive(Clone, Debug)]
+pub struct CollapseGroupChanged {
+    pub meta: Option<FingerUpEvent>,
+    /// The indexs of the opened collapses.
+    pub index: Vec<i32>,
+    /// The values of the opened collapses, in the order they are opened.
+    pub value: Vec<String>,
+}
@@ -0,0 +1,171 @@
+use super::event::{CollapseGroupChanged, CollapseGroupEvent};
+use crate::{
+    components::{collapse::GCollapseWidgetRefExt, view::GView},
+    inherits_view_livehook, inherits_view_widget_node,
+};
+use makepad_widgets::*;
+
+live_design! {
+    link genui_basic;
+
+    pub GCollapseGroupBase = {{GCollapseGroup}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Down,
+                spacing: 0.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GCollapseGroup {
+    #[deref]
+    pub deref_widget: GView,
+    // values of the opened collapses, in the order they are opened
+    #[live]
+    pub active: Vec<String>,
+    /// only one collapse can be opened, opening another one closes it
+    #[live]
+    pub accordion: bool,
+    /// the max count of opened collapses, the earliest opened one is closed (0: no limit)
+    #[live]
+    pub max_open: usize,
+}
+
+inherits_view_widget_node!(GCollapseGroup);
+
+impl Widget for GCollapseGroup {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        if self.animator_handle_event(cx, event).must_redraw() {
+            self.redraw(cx);
+        };
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+
+        let mut changed = None;
+        for (_id, child) in self.children.iter() {
+            let _ = child.as_gcollapse().borrow().map(|collapse| {
+                if let Some(param) = collapse.changed(&actions) {
+                    changed.replace((collapse.value.to_string(), param));
+                }
+            });
+            if changed.is_some() {
+                break;
+            }
+        }
+
+        if let Some((value, param)) = changed {
+            let mut active = self.active.clone();
+            active.retain(|v| v != &value);
+            if param.active {
+                active.push(value);
+            }
+            self.toggle(cx, active, false);
+            let (index, value) = self.active_indexs();
+            cx.widget_action(
+                self.widget_uid(),
+                &scope.path,
+                CollapseGroupEvent::Changed(CollapseGroupChanged {
+                    meta: param.meta,
+                    index,
+                    value,
+                }),
+            );
+        }
+    }
+}
+
+impl LiveHook for GCollapseGroup {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        if self.active.is_empty() {
+            self.find_active();
+        }
+        self.set_active(cx, self.active.clone());
+    }
+    inherits_view_livehook!();
+}
+
+impl GCollapseGroup {
+    pub fn find_active(&mut self) -> () {
+        self.active =
+            self.children
+                .iter()
+                .enumerate()
+                .fold(Vec::new(), |mut active, (index, (_, child))| {
+                    if let Some(mut child) = child.as_gcollapse().borrow_mut() {
+                        // use the index as the value if the value of the collapse is empty
+                        if child.value.is_empty() {
+                            child.value = index.to_string();
+                        }
+                        if child.active {
+                            active.push(child.value.to_string());
+                        }
+                    } else {
+                        panic!("GCollapseGroup only allows GCollapse as child!");
+                    }
+                    active
+                });
+    }
+    /// open the collapses of `active` and close the others without animation
+    pub fn set_active(&mut self, cx: &mut Cx, active: Vec<String>) -> () {
+        self.toggle(cx, active, true);
+    }
+    /// the latest opened ones are kept when `accordion` or `max_open` is set
+    pub fn toggle(&mut self, cx: &mut Cx, mut active: Vec<String>, init: bool) -> () {
+        let max_open = if self.accordion { 1 } else { self.max_open };
+        if max_open > 0 && active.len() > max_open {
+            active.drain(..active.len() - max_open);
+        }
+        self.active = active;
+
+        self.children
+            .iter()
+            .enumerate()
+            .for_each(|(index, (_id, child))| {
+                if let Some(mut child) = child.as_gcollapse().borrow_mut() {
+                    if child.value.is_empty() {
+                        child.value = index.to_string();
+                    }
+                    let active = self.active.contains(&child.value);
+                    child.toggle(cx, active, init);
+                } else {
+                    panic!("GCollapseGroup only allows GCollapse as child!")
+                }
+            });
+    }
+    fn active_indexs(&self) -> (Vec<i32>, Vec<String>) {
+        self.active
+            .iter()
+            .filter_map(|value| {
+                self.children
+                    .iter()
+                    .position(|(_, child)| {
+                        child
+                            .as_gcollapse()
+                            .borrow()
+                            .map_or(false, |collapse| collapse.value.eq(value))
+                    })
+                    .map(|index| (index as i32, value.to_string()))
+            })
+            .unzip()
+    }
+}
+
+impl GCollapseGroupRef {
+    pub fn set_active(&self, cx: &mut Cx, active: Vec<String>) -> () {
+        self.borrow_mut().map(|mut c| c.set_active(cx, active));
+    }
+    pub fn active(&self) -> Vec<String> {
+        self.borrow().map_or(vec![], |c| c.active.clone())
+    }
+}
@@ -1,8 +1,12 @@
 mod event;
+pub mod group;
 mod prop;
+mod register;
 
 pub use event::*;
+pub use group::*;
 pub use prop::*;
+pub use register::register as collapse_register;
 
 use makepad_widgets::*;
 
@@ -70,6 +74,9 @@ pub struct GCollapse {
     pub draw_collapse: DrawView,
     #[live]
     pub active: bool,
+    /// the value in a `GCollapseGroup`, the index in the group is used if empty
+    #[live]
+    pub value: String,
     #[live]
     pub fold: f64,
     #[live(true)]
@@ -410,6 +417,28 @@ impl GCollapse {
             });
         }
     }
+    /// open or close the collapse without `CollapseEvent::Changed`, `init` skips the animation
+    pub fn toggle(&mut self, cx: &mut Cx, active: bool, init: bool) -> () {
+        if self.active == active && !init {
+            return;
+        }
+        self.active = active;
+        self.fold = active.to_f64();
+        let (state, animation) = if active {
+            (CollapseState::Active, id!(active.on))
+        } else {
+            (CollapseState::Basic, id!(active.off))
+        };
+        if init {
+            self.switch_state(state);
+            self.animator_cut(cx, animation);
+        } else {
+            self.switch_state_with_animation(cx, state);
+            self.animator_play(cx, animation);
+            self.animation_counter = true;
+        }
+        self.redraw(cx);
+    }
     event_option! {
         hover_in: CollapseEvent::HoverIn => CollapseHoverIn,
         hover_out: CollapseEvent::HoverOut => CollapseHoverOut,
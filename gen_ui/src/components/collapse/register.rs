@@ -0,0 +1,6 @@
+use makepad_widgets::Cx;
+
+pub fn register(cx: &mut Cx) {
+    crate::components::collapse::group::live_design(cx);
+    crate::components::collapse::live_design(cx);
+}
@@ -0,0 +1,188 @@
+/// names of the months, used as the labels above the grid
+pub const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// days since 1970-01-01 of a `YYYY-MM-DD` date
+pub fn parse_date(date: &str) -> Option<i64> {
+    let mut parts = date.trim().splitn(3, '-');
+    let year = parts.next()?.parse::<i64>().ok()?;
+    let month = parts.next()?.parse::<i64>().ok()?;
+    let day = parts.next()?.parse::<i64>().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // days from civil, see http://howardhinnant.github.io/date_algorithms.html
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146097 + doe - 719468)
+}
+
+/// `(year, month, day)` of the days since 1970-01-01
+pub fn civil(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// 0 is Sunday
+pub fn weekday(days: i64) -> usize {
+    // 1970-01-01 is Thursday
+    (days + 4).rem_euclid(7) as usize
+}
+
+/// upper bounds of the levels 1..=3, the counts over the last one are level 4
+///
+/// the bounds are the quartiles of the non-zero counts, so the colors are spread evenly
+pub fn quantiles(counts: &[u32]) -> [u32; 3] {
+    let mut counts = counts
+        .iter()
+        .copied()
+        .filter(|count| *count > 0)
+        .collect::<Vec<u32>>();
+    if counts.is_empty() {
+        return [0; 3];
+    }
+    counts.sort_unstable();
+    let at = |q: f64| counts[((counts.len() - 1) as f64 * q) as usize];
+    [at(0.25), at(0.5), at(0.75)]
+}
+
+/// 0 is no contribution, 1..=4 from less to more
+pub fn level(count: u32, bounds: &[u32; 3]) -> usize {
+    if count == 0 {
+        0
+    } else {
+        1 + bounds.iter().filter(|bound| count > **bound).count()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContributionCell {
+    pub days: i64,
+    pub count: u32,
+    pub level: usize,
+    /// column of the grid
+    pub week: usize,
+    /// row of the grid, 0 is Sunday
+    pub day: usize,
+}
+
+/// cells of `weeks` columns ending at `end` (the last column may be partial)
+#[derive(Debug, Clone, Default)]
+pub struct ContributionGrid {
+    pub cells: Vec<ContributionCell>,
+    /// `(week, month)` of the columns where a month starts, month is 0-based
+    pub months: Vec<(usize, usize)>,
+    pub bounds: [u32; 3],
+}
+
+impl ContributionGrid {
+    /// `end` is the days of the last date, the latest date of the data if `None`
+    pub fn new(data: &[(String, u32)], end: Option<i64>, weeks: usize) -> Self {
+        let data = data
+            .iter()
+            .filter_map(|(date, count)| parse_date(date).map(|days| (days, *count)))
+            .collect::<Vec<(i64, u32)>>();
+        let Some(end) = end.or_else(|| data.iter().map(|(days, _)| *days).max()) else {
+            return Self::default();
+        };
+        if weeks == 0 {
+            return Self::default();
+        }
+        // the first column starts at Sunday
+        let start = end - weekday(end) as i64 - (weeks as i64 - 1) * 7;
+        let mut counts = vec![0_u32; (end - start + 1) as usize];
+        for (days, count) in data {
+            if (start..=end).contains(&days) {
+                counts[(days - start) as usize] += count;
+            }
+        }
+        let bounds = quantiles(&counts);
+        let mut months = vec![];
+        let cells = counts
+            .iter()
+            .enumerate()
+            .map(|(index, count)| {
+                let days = start + index as i64;
+                let week = index / 7;
+                // label the column where a month starts
+                let (_, month, day) = civil(days);
+                if day == 1 {
+                    months.push((week, month as usize - 1));
+                }
+                ContributionCell {
+                    days,
+                    count: *count,
+                    level: level(*count, &bounds),
+                    week,
+                    day: index % 7,
+                }
+            })
+            .collect();
+        Self {
+            cells,
+            months,
+            bounds,
+        }
+    }
+    pub fn total(&self) -> u32 {
+        self.cells.iter().map(|cell| cell.count).sum()
+    }
+    pub fn cell(&self, week: usize, day: usize) -> Option<&ContributionCell> {
+        self.cells.get(week * 7 + day)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn date() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        let days = parse_date("2024-02-29").unwrap();
+        assert_eq!(format_date(days), "2024-02-29");
+        assert_eq!(format_date(days + 1), "2024-03-01");
+        // 2025-01-01 is Wednesday
+        assert_eq!(weekday(parse_date("2025-01-01").unwrap()), 3);
+        assert_eq!(parse_date("2025-13-01"), None);
+    }
+
+    #[test]
+    fn grid() {
+        let data = vec![
+            ("2025-01-01".to_string(), 1),
+            ("2025-01-02".to_string(), 4),
+            ("2025-01-02".to_string(), 4),
+            ("2025-01-04".to_string(), 20),
+        ];
+        let grid = ContributionGrid::new(&data, None, 2);
+        // ends at Saturday 2025-01-04, starts at Sunday 2024-12-22
+        assert_eq!(grid.cells.len(), 14);
+        assert_eq!(format_date(grid.cells[0].days), "2024-12-22");
+        assert_eq!(grid.total(), 29);
+        let jan_2 = grid.cell(1, 4).unwrap();
+        assert_eq!(jan_2.count, 8);
+        assert_eq!(grid.cell(1, 3).unwrap().level, 1);
+        assert_eq!(grid.cell(1, 6).unwrap().level, 4);
+        assert_eq!(grid.cell(0, 0).unwrap().level, 0);
+        assert_eq!(grid.months, vec![(1, 0)]);
+    }
+}
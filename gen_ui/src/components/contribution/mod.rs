@@ -0,0 +1,368 @@
+pub mod grid;
+
+pub use grid::{ContributionCell, ContributionGrid, format_date, parse_date};
+
+use makepad_widgets::*;
+
+use crate::{
+    components::contribution::grid::MONTHS,
+    components::label::GLabel,
+    prop::manuel::SECONDARY,
+    shader::{draw_contribution::DrawContribution, draw_view::DrawView},
+    themes::Theme,
+    visible,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GContributionBase = {{GContribution}} {}
+}
+
+/// GitHub-style contribution heatmap, a column is a week and a row is a day (Sunday first)
+///
+/// the cells are colored by the quartiles of the non-zero counts with the palette of `theme`,
+/// hover a cell to show its count
+///
+/// ```rust,ignore
+/// self.gcontribution(id!(contribution)).set_data(cx, vec![("2025-01-01".to_string(), 3)]);
+/// ```
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GContribution {
+    #[live]
+    pub theme: Theme,
+    /// the last date (`YYYY-MM-DD`) of the grid, the latest date of the data if empty
+    #[live]
+    pub end: String,
+    #[live(53)]
+    pub weeks: usize,
+    #[live(12.0)]
+    pub cell_size: f64,
+    #[live(3.0)]
+    pub cell_spacing: f64,
+    /// show the total and the legend under the grid
+    #[live(true)]
+    pub legend: bool,
+    #[live(true)]
+    pub visible: bool,
+    #[live]
+    pub draw_cell: DrawContribution,
+    #[live]
+    pub draw_tooltip: DrawView,
+    #[live]
+    pub tooltip_text: GLabel,
+    /// template of the month, weekday and legend labels
+    #[live]
+    pub label: Option<LivePtr>,
+    #[rust]
+    labels: Vec<GLabel>,
+    #[rust]
+    data: Vec<(String, u32)>,
+    #[rust]
+    grid: ContributionGrid,
+    /// index of the hovered cell
+    #[rust]
+    hover: Option<usize>,
+    #[rust]
+    area: Area,
+}
+
+impl WidgetNode for GContribution {
+    fn uid_to_widget(&self, _uid: WidgetUid) -> WidgetRef {
+        WidgetRef::empty()
+    }
+
+    fn find_widgets(&self, _path: &[LiveId], _cached: WidgetCache, _results: &mut WidgetSet) {
+        ()
+    }
+
+    fn walk(&mut self, _cx: &mut Cx) -> Walk {
+        let size = self.size();
+        Walk {
+            width: Size::Fixed(size.x),
+            height: Size::Fixed(size.y),
+            ..Default::default()
+        }
+    }
+
+    fn area(&self) -> Area {
+        self.area
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.area.redraw(cx);
+    }
+
+    visible!();
+}
+
+impl Widget for GContribution {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        if !self.visible {
+            return DrawStep::done();
+        }
+        cx.begin_turtle(walk, Layout::default());
+        let origin = cx.turtle().pos();
+        let grid_pos = origin + dvec2(WEEKDAY_WIDTH, MONTH_HEIGHT);
+        let step = self.cell_size + self.cell_spacing;
+        let texts = self.texts();
+        if self.labels.len() != texts.len() {
+            self.labels = texts
+                .iter()
+                .map(|_| {
+                    let mut label = GLabel::new_from_ptr(cx, self.label);
+                    label.set_default_font_color(cx, SECONDARY);
+                    label
+                })
+                .collect();
+        }
+        for ((text, pos, align), label) in texts.into_iter().zip(self.labels.iter_mut()) {
+            draw_label(cx, scope, label, text, grid_pos + pos, align);
+        }
+
+        for (index, cell) in self.grid.cells.iter().enumerate() {
+            let pos = grid_pos + dvec2(cell.week as f64 * step, cell.day as f64 * step);
+            self.draw_cell.color = self.level_color(cell.level);
+            self.draw_cell.hover = (self.hover == Some(index)) as u8 as f32;
+            self.draw_cell.draw_abs(
+                cx,
+                Rect {
+                    pos,
+                    size: dvec2(self.cell_size, self.cell_size),
+                },
+            );
+        }
+        if self.legend {
+            // less [levels] more, on the right under the grid
+            let y = grid_pos.y + 7.0 * step + LEGEND_GAP;
+            let x = grid_pos.x + self.weeks as f64 * step - 5.0 * step - LEGEND_TEXT_WIDTH;
+            for level in 0..5 {
+                self.draw_cell.color = self.level_color(level);
+                self.draw_cell.hover = 0.0;
+                self.draw_cell.draw_abs(
+                    cx,
+                    Rect {
+                        pos: dvec2(x + level as f64 * step, y),
+                        size: dvec2(self.cell_size, self.cell_size),
+                    },
+                );
+            }
+        }
+        cx.end_turtle_with_area(&mut self.area);
+
+        if let Some(cell) = self.hover.and_then(|index| self.grid.cells.get(index)) {
+            let pos = grid_pos + dvec2(cell.week as f64 * step, cell.day as f64 * step);
+            let text = format!("{} contributions on {}", cell.count, format_date(cell.days));
+            self.draw_tooltip(cx, scope, text, pos);
+        }
+        DrawStep::done()
+    }
+
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, _scope: &mut Scope) {
+        if !self.visible {
+            return;
+        }
+        match event.hits(cx, self.area) {
+            Hit::FingerHoverIn(e) | Hit::FingerHoverOver(e) => {
+                let rect = self.area.rect(cx);
+                let hover = self.cell_at(e.abs - rect.pos);
+                if hover != self.hover {
+                    self.hover = hover;
+                    self.redraw(cx);
+                }
+            }
+            Hit::FingerHoverOut(_) => {
+                if self.hover.take().is_some() {
+                    self.redraw(cx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl LiveHook for GContribution {
+    fn after_new_before_apply(&mut self, _cx: &mut Cx) {
+        // the tooltip is a dark panel of the theme by default
+        self.draw_tooltip.background_color = Theme::Dark.color(800).into();
+    }
+    fn after_apply(&mut self, cx: &mut Cx, _apply: &mut Apply, _index: usize, _nodes: &[LiveNode]) {
+        self.render(cx);
+    }
+}
+
+impl GContribution {
+    fn render(&mut self, cx: &mut Cx) -> () {
+        let end = parse_date(&self.end);
+        self.grid = ContributionGrid::new(&self.data, end, self.weeks);
+        self.hover = None;
+        self.redraw(cx);
+    }
+    /// `(date, count)`, the date is `YYYY-MM-DD`, counts of the same date are added up
+    pub fn set_data(&mut self, cx: &mut Cx, data: Vec<(String, u32)>) -> () {
+        self.data = data;
+        self.render(cx);
+    }
+    pub fn total(&self) -> u32 {
+        self.grid.total()
+    }
+    fn size(&self) -> DVec2 {
+        let step = self.cell_size + self.cell_spacing;
+        let legend = if self.legend {
+            LEGEND_GAP + self.cell_size
+        } else {
+            0.0
+        };
+        dvec2(
+            WEEKDAY_WIDTH + self.weeks as f64 * step,
+            MONTH_HEIGHT + 7.0 * step + legend,
+        )
+    }
+    /// level 0 is the empty cell
+    fn level_color(&self, level: usize) -> Vec4 {
+        match level {
+            0 => Theme::Dark.color(700).into(),
+            level => self.theme.color(LEVELS[(level - 1).min(3)]).into(),
+        }
+    }
+    /// the cell under the position (relative to the widget)
+    fn cell_at(&self, pos: DVec2) -> Option<usize> {
+        let step = self.cell_size + self.cell_spacing;
+        let pos = pos - dvec2(WEEKDAY_WIDTH, MONTH_HEIGHT);
+        if pos.x < 0.0 || pos.y < 0.0 {
+            return None;
+        }
+        let (week, day) = ((pos.x / step) as usize, (pos.y / step) as usize);
+        // in the spacing
+        if pos.x - week as f64 * step > self.cell_size
+            || pos.y - day as f64 * step > self.cell_size
+            || day >= 7
+        {
+            return None;
+        }
+        let index = week * 7 + day;
+        (index < self.grid.cells.len()).then_some(index)
+    }
+    /// text, position (relative to the grid) and align of the labels
+    fn texts(&self) -> Vec<(String, DVec2, Align)> {
+        let step = self.cell_size + self.cell_spacing;
+        let left = Align { x: 0.0, y: 0.5 };
+        let mut texts = self
+            .grid
+            .months
+            .iter()
+            .map(|(week, month)| {
+                (
+                    MONTHS[*month].to_string(),
+                    dvec2(*week as f64 * step, -MONTH_HEIGHT),
+                    left,
+                )
+            })
+            .collect::<Vec<_>>();
+        for (day, text) in [(1, "Mon"), (3, "Wed"), (5, "Fri")] {
+            texts.push((
+                text.to_string(),
+                dvec2(
+                    -WEEKDAY_WIDTH,
+                    day as f64 * step - (MONTH_HEIGHT - self.cell_size) * 0.5,
+                ),
+                left,
+            ));
+        }
+        if self.legend {
+            let y = 7.0 * step + LEGEND_GAP - (MONTH_HEIGHT - self.cell_size) * 0.5;
+            let x = self.weeks as f64 * step - 5.0 * step - LEGEND_TEXT_WIDTH;
+            texts.push((
+                format!("{} contributions", self.grid.total()),
+                dvec2(0.0, y),
+                left,
+            ));
+            texts.push((
+                "Less".to_string(),
+                dvec2(x - self.cell_spacing, y),
+                Align { x: 1.0, y: 0.5 },
+            ));
+            texts.push(("More".to_string(), dvec2(x + 5.0 * step, y), left));
+        }
+        texts
+    }
+    fn draw_tooltip(&mut self, cx: &mut Cx2d, scope: &mut Scope, text: String, pos: DVec2) -> () {
+        let _ = self.tooltip_text.set_text(cx, text);
+        // above the cell, centered
+        let abs_pos = dvec2(
+            pos.x + self.cell_size * 0.5 - TOOLTIP_WIDTH * 0.5,
+            pos.y - TOOLTIP_HEIGHT - TOOLTIP_GAP,
+        );
+        self.draw_tooltip.begin(
+            cx,
+            Walk {
+                abs_pos: Some(abs_pos),
+                margin: Margin::default(),
+                width: Size::Fixed(TOOLTIP_WIDTH),
+                height: Size::Fixed(TOOLTIP_HEIGHT),
+            },
+            Layout {
+                align: Align { x: 0.5, y: 0.5 },
+                ..Default::default()
+            },
+        );
+        let text_walk = self.tooltip_text.walk(cx);
+        let _ = self.tooltip_text.draw_walk(cx, scope, text_walk);
+        self.draw_tooltip.end(cx);
+    }
+}
+
+fn draw_label(
+    cx: &mut Cx2d,
+    scope: &mut Scope,
+    label: &mut GLabel,
+    text: String,
+    pos: DVec2,
+    align: Align,
+) -> () {
+    let _ = label.set_text(cx, text);
+    // the position of the right aligned label is its end
+    let x = if align.x == 1.0 {
+        pos.x - LEGEND_TEXT_WIDTH
+    } else {
+        pos.x
+    };
+    cx.begin_turtle(
+        Walk {
+            abs_pos: Some(dvec2(x, pos.y)),
+            margin: Margin::default(),
+            width: if align.x == 1.0 {
+                Size::Fixed(LEGEND_TEXT_WIDTH)
+            } else {
+                Size::Fit
+            },
+            height: Size::Fixed(MONTH_HEIGHT),
+        },
+        Layout {
+            align,
+            ..Default::default()
+        },
+    );
+    let label_walk = label.walk(cx);
+    let _ = label.draw_walk(cx, scope, label_walk);
+    cx.end_turtle();
+}
+
+impl GContributionRef {
+    pub fn set_data(&self, cx: &mut Cx, data: Vec<(String, u32)>) -> () {
+        self.borrow_mut().map(|mut c| c.set_data(cx, data));
+    }
+    pub fn total(&self) -> u32 {
+        self.borrow().map_or(0, |c| c.total())
+    }
+}
+
+/// palette levels of the levels 1..=4, brighter means more
+const LEVELS: [u32; 4] = [800, 600, 400, 200];
+const WEEKDAY_WIDTH: f64 = 32.0;
+const MONTH_HEIGHT: f64 = 16.0;
+const LEGEND_GAP: f64 = 8.0;
+const LEGEND_TEXT_WIDTH: f64 = 32.0;
+const TOOLTIP_WIDTH: f64 = 200.0;
+const TOOLTIP_HEIGHT: f64 = 24.0;
+const TOOLTIP_GAP: f64 = 6.0;
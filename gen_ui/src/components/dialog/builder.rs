@@ -0,0 +1,100 @@
+use crate::prop::CloseMode;
+
+use super::DialogResult;
+
+/// value of the `OK` button of confirm/prompt dialogs
+pub const DIALOG_OK: &str = "ok";
+/// value of the `Cancel` button of confirm/prompt dialogs
+pub const DIALOG_CANCEL: &str = "cancel";
+
+/// id of a dialog opened by `CxDialogExt`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DialogId(pub u64);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DialogKind {
+    #[default]
+    Normal,
+    Confirm,
+    Prompt,
+}
+
+/// build a dialog from the `dialog` template of `GDialogManager`
+/// ```ignore
+/// let builder = DialogBuilder::new("Save changes?")
+///     .content("Your changes will be lost if you don't save them.")
+///     .button("discard", "Discard")
+///     .button("save", "Save");
+/// let id = cx.open_dialog_builder(builder);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DialogBuilder {
+    pub title: String,
+    pub content: String,
+    /// footer buttons: `(value, text)`
+    pub buttons: Vec<(String, String)>,
+    /// placeholder of the input, the input is hidden if `None`
+    pub input: Option<String>,
+    pub close_mode: CloseMode,
+    pub kind: DialogKind,
+}
+
+impl DialogBuilder {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = content.into();
+        self
+    }
+    pub fn button(mut self, value: impl Into<String>, text: impl Into<String>) -> Self {
+        self.buttons.push((value.into(), text.into()));
+        self
+    }
+    pub fn input(mut self, placeholder: impl Into<String>) -> Self {
+        self.input = Some(placeholder.into());
+        self
+    }
+    /// `CloseMode::Virtual`: Esc and clicking the backdrop do not close the dialog
+    pub fn close_mode(mut self, close_mode: CloseMode) -> Self {
+        self.close_mode = close_mode;
+        self
+    }
+    /// `Cancel` and `OK` buttons, the result is `DialogResult::Confirm`
+    pub fn confirm(title: impl Into<String>, content: impl Into<String>) -> Self {
+        let mut builder = Self::new(title)
+            .content(content)
+            .button(DIALOG_CANCEL, "Cancel")
+            .button(DIALOG_OK, "OK");
+        builder.kind = DialogKind::Confirm;
+        builder
+    }
+    /// confirm dialog with an input, the result is `DialogResult::Prompt`
+    pub fn prompt(
+        title: impl Into<String>,
+        content: impl Into<String>,
+        placeholder: impl Into<String>,
+    ) -> Self {
+        let mut builder = Self::confirm(title, content).input(placeholder);
+        builder.kind = DialogKind::Prompt;
+        builder
+    }
+    /// result of the clicked footer button
+    pub(crate) fn button_result(&self, value: &str, input: String) -> DialogResult {
+        match self.kind {
+            DialogKind::Normal => DialogResult::Button(value.to_string()),
+            DialogKind::Confirm => DialogResult::Confirm(value == DIALOG_OK),
+            DialogKind::Prompt => DialogResult::Prompt((value == DIALOG_OK).then_some(input)),
+        }
+    }
+    pub(crate) fn dismissed_result(&self) -> DialogResult {
+        match self.kind {
+            DialogKind::Normal => DialogResult::Dismissed,
+            DialogKind::Confirm => DialogResult::Confirm(false),
+            DialogKind::Prompt => DialogResult::Prompt(None),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use makepad_widgets::{ActionDefaultRef, Actions, DefaultNone};
+
+use super::DialogId;
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum DialogEvent {
+    Closed(DialogClosed),
+    None,
+}
+
+#[derive(Clone, Debug)]
+pub struct DialogClosed {
+    pub id: DialogId,
+    pub result: DialogResult,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogResult {
+    /// a footer button of a builder dialog is clicked, the value of the button
+    Button(String),
+    /// confirm dialog: `true` if `OK` is clicked
+    Confirm(bool),
+    /// prompt dialog: the input value if `OK` is clicked (or enter is pressed), `None` if cancelled
+    Prompt(Option<String>),
+    /// closed by Esc, the backdrop or `close_dialog`
+    Dismissed,
+}
+
+/// find the `DialogEvent::Closed` of the dialog in the actions
+pub fn dialog_closed(actions: &Actions, id: DialogId) -> Option<DialogResult> {
+    actions
+        .iter()
+        .find_map(|action| match action.downcast_ref::<DialogEvent>() {
+            Some(DialogEvent::Closed(e)) if e.id == id => Some(e.result.clone()),
+            _ => None,
+        })
+}
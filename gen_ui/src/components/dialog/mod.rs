@@ -0,0 +1,367 @@
+mod builder;
+mod event;
+
+pub use builder::*;
+pub use event::*;
+
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        GButtonWidgetRefExt, GLabelWidgetExt, GLabelWidgetRefExt, GViewWidgetExt,
+        area::GInputAreaWidgetExt,
+    },
+    prop::CloseMode,
+    shader::draw_view::DrawView,
+    visible,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GDialogManagerBase = {{GDialogManager}}{}
+}
+
+/// ## Draws the dialogs opened by `CxDialogExt`
+/// put it once at the end of the root view (not in a page), the opened dialogs are drawn on top
+/// of everything as a z-ordered stack (the last opened is on the top), each one with a modal backdrop.
+/// only the top dialog receives events:
+/// - the backdrop sweep locks the finger events while a dialog is open, widgets below get nothing
+/// - key focus is kept inside the top dialog
+/// - Esc and clicking the backdrop close the top dialog if its close mode is `CloseMode::Out`
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GDialogManager {
+    /// template of builder dialogs, named children: `title`, `content`, `input`, `footer`
+    #[live]
+    pub dialog: Option<LivePtr>,
+    /// template of the footer buttons of builder dialogs
+    #[live]
+    pub button: Option<LivePtr>,
+    #[live]
+    pub draw_backdrop: DrawView,
+    #[live]
+    draw_list: DrawList2d,
+    #[live(true)]
+    pub visible: bool,
+}
+
+/// opened dialogs, shared by `CxDialogExt` and `GDialogManager`
+#[derive(Default)]
+pub struct DialogGlobal {
+    /// the last one is on the top
+    pub stack: Vec<DialogEntry>,
+    /// templates of `GDialogManager`
+    templates: Option<(LivePtr, Option<LivePtr>)>,
+    /// the top dialog should grab the key focus after drawn
+    focus_pending: bool,
+    /// the backdrop area sweep locked while a dialog is open
+    lock: Option<Area>,
+    next_id: u64,
+}
+
+pub struct DialogEntry {
+    pub id: DialogId,
+    pub widget: WidgetRef,
+    pub close_mode: CloseMode,
+    builder: Option<DialogBuilder>,
+    /// footer buttons of builder dialogs: `(value, button)`
+    buttons: Vec<(String, WidgetRef)>,
+}
+
+impl DialogEntry {
+    fn input_value(&self) -> String {
+        self.widget
+            .ginput_area(id!(input))
+            .borrow()
+            .map_or(String::new(), |input| input.value.to_string())
+    }
+    /// result of a clicked footer button, pressing enter in the input confirms a prompt dialog
+    fn clicked_result(&self, actions: &Actions) -> Option<DialogResult> {
+        let builder = self.builder.as_ref()?;
+        if builder.kind == DialogKind::Prompt
+            && self
+                .widget
+                .ginput_area(id!(input))
+                .returned(actions)
+                .is_some()
+        {
+            return Some(builder.button_result(DIALOG_OK, self.input_value()));
+        }
+        self.buttons
+            .iter()
+            .find(|(_, button)| button.as_gbutton().clicked(actions).is_some())
+            .map(|(value, _)| builder.button_result(value, self.input_value()))
+    }
+    fn dismissed_result(&self) -> DialogResult {
+        self.builder
+            .as_ref()
+            .map_or(DialogResult::Dismissed, |builder| {
+                builder.dismissed_result()
+            })
+    }
+}
+
+/// open/close dialogs from anywhere, the result is emitted as `DialogEvent::Closed`
+/// (use `dialog_closed` to find it in the actions)
+pub trait CxDialogExt {
+    /// open a dialog from a template
+    fn open_dialog(&mut self, ptr: LivePtr, close_mode: CloseMode) -> DialogId;
+    /// open a dialog from the `dialog` template of `GDialogManager`,
+    /// `None` if there is no `GDialogManager`
+    fn open_dialog_builder(&mut self, builder: DialogBuilder) -> Option<DialogId>;
+    fn confirm_dialog(&mut self, title: &str, content: &str) -> Option<DialogId> {
+        self.open_dialog_builder(DialogBuilder::confirm(title, content))
+    }
+    fn prompt_dialog(&mut self, title: &str, content: &str, placeholder: &str) -> Option<DialogId> {
+        self.open_dialog_builder(DialogBuilder::prompt(title, content, placeholder))
+    }
+    /// close the dialog, the result is `DialogResult::Dismissed` (or the cancel result)
+    fn close_dialog(&mut self, id: DialogId);
+    /// the widget of an opened dialog
+    fn dialog_widget(&mut self, id: DialogId) -> Option<WidgetRef>;
+}
+
+impl CxDialogExt for Cx {
+    fn open_dialog(&mut self, ptr: LivePtr, close_mode: CloseMode) -> DialogId {
+        let widget = WidgetRef::new_from_ptr(self, Some(ptr));
+        push_dialog(self, widget, close_mode, None, vec![])
+    }
+    fn open_dialog_builder(&mut self, builder: DialogBuilder) -> Option<DialogId> {
+        let (dialog, button) = self.global::<DialogGlobal>().templates?;
+        let widget = WidgetRef::new_from_ptr(self, Some(dialog));
+        if let Some(mut title) = widget.glabel(id!(title)).borrow_mut() {
+            let _ = title.set_text(self, builder.title.to_string());
+        }
+        if let Some(mut content) = widget.glabel(id!(content)).borrow_mut() {
+            let _ = content.set_text(self, builder.content.to_string());
+        }
+        if let Some(mut input) = widget.ginput_area(id!(input)).borrow_mut() {
+            input.visible = builder.input.is_some();
+            input.set_empty_text(self, builder.input.clone().unwrap_or_default());
+        }
+        let mut buttons = vec![];
+        if let Some(mut footer) = widget.gview(id!(footer)).borrow_mut() {
+            for (value, text) in builder.buttons.iter() {
+                let button = WidgetRef::new_from_ptr(self, button);
+                if let Some(button) = button.as_gbutton().borrow() {
+                    if let Some(mut label) = button.slot.as_glabel().borrow_mut() {
+                        let _ = label.set_text(self, text.to_string());
+                    }
+                }
+                footer
+                    .children
+                    .push((LiveId::from_str(value), button.clone()));
+                buttons.push((value.to_string(), button));
+            }
+        }
+        let close_mode = builder.close_mode;
+        Some(push_dialog(
+            self,
+            widget,
+            close_mode,
+            Some(builder),
+            buttons,
+        ))
+    }
+    fn close_dialog(&mut self, id: DialogId) {
+        close_dialog_with(self, id, None);
+    }
+    fn dialog_widget(&mut self, id: DialogId) -> Option<WidgetRef> {
+        self.global::<DialogGlobal>()
+            .stack
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.widget.clone())
+    }
+}
+
+fn push_dialog(
+    cx: &mut Cx,
+    widget: WidgetRef,
+    close_mode: CloseMode,
+    builder: Option<DialogBuilder>,
+    buttons: Vec<(String, WidgetRef)>,
+) -> DialogId {
+    let global = cx.global::<DialogGlobal>();
+    global.next_id += 1;
+    let id = DialogId(global.next_id);
+    global.stack.push(DialogEntry {
+        id,
+        widget,
+        close_mode,
+        builder,
+        buttons,
+    });
+    global.focus_pending = true;
+    cx.redraw_all();
+    id
+}
+
+/// remove the dialog from the stack and emit `DialogEvent::Closed`, `None` result means dismissed
+fn close_dialog_with(cx: &mut Cx, id: DialogId, result: Option<DialogResult>) {
+    let global = cx.global::<DialogGlobal>();
+    let Some(index) = global.stack.iter().position(|entry| entry.id == id) else {
+        return;
+    };
+    let entry = global.stack.remove(index);
+    // the next dialog takes the key focus back
+    global.focus_pending = !global.stack.is_empty();
+    if !global.focus_pending {
+        if let Some(lock) = global.lock.take() {
+            cx.sweep_unlock(lock);
+        }
+        cx.revert_key_focus();
+    }
+    let result = result.unwrap_or_else(|| entry.dismissed_result());
+    cx.action(DialogEvent::Closed(DialogClosed { id, result }));
+    cx.redraw_all();
+}
+
+impl WidgetNode for GDialogManager {
+    fn uid_to_widget(&self, _uid: WidgetUid) -> WidgetRef {
+        WidgetRef::empty()
+    }
+
+    fn find_widgets(&self, _path: &[LiveId], _cached: WidgetCache, _results: &mut WidgetSet) {
+        ()
+    }
+
+    fn walk(&mut self, _cx: &mut Cx) -> Walk {
+        Walk::fixed(0.0, 0.0)
+    }
+
+    fn area(&self) -> Area {
+        self.draw_backdrop.area()
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.draw_list.redraw(cx);
+    }
+
+    visible!();
+}
+
+impl LiveHook for GDialogManager {
+    fn after_apply(&mut self, cx: &mut Cx, _apply: &mut Apply, _index: usize, _nodes: &[LiveNode]) {
+        if let Some(dialog) = self.dialog {
+            cx.global::<DialogGlobal>().templates = Some((dialog, self.button));
+        }
+    }
+}
+
+impl Widget for GDialogManager {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, _walk: Walk) -> DrawStep {
+        let dialogs: Vec<WidgetRef> = cx
+            .global::<DialogGlobal>()
+            .stack
+            .iter()
+            .map(|entry| entry.widget.clone())
+            .collect();
+        if !self.visible || dialogs.is_empty() {
+            return DrawStep::done();
+        }
+
+        self.draw_list.begin_overlay_reuse(cx);
+        cx.begin_pass_sized_turtle(Layout::flow_down());
+        let size = cx.current_pass_size();
+        for dialog in dialogs.iter() {
+            // every dialog has its own backdrop, so the dialogs below are dimmed too
+            self.draw_backdrop.begin(
+                cx,
+                Walk {
+                    abs_pos: Some(DVec2::default()),
+                    width: Size::Fixed(size.x),
+                    height: Size::Fixed(size.y),
+                    ..Default::default()
+                },
+                Layout {
+                    align: Align { x: 0.5, y: 0.5 },
+                    ..Default::default()
+                },
+            );
+            let walk = dialog.walk(cx);
+            let _ = dialog.draw_walk(cx, scope, walk);
+            self.draw_backdrop.end(cx);
+        }
+        cx.end_pass_sized_turtle();
+        self.draw_list.end(cx);
+
+        // lock the finger events to the backdrop (the area changes when redrawn)
+        let area = self.draw_backdrop.area();
+        cx.sweep_lock(area);
+        cx.global::<DialogGlobal>().lock = Some(area);
+
+        if std::mem::take(&mut cx.global::<DialogGlobal>().focus_pending) {
+            if let Some(top) = dialogs.last() {
+                cx.set_key_focus(top.area());
+            }
+        }
+        DrawStep::done()
+    }
+
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible {
+            return;
+        }
+        let Some((id, dialog, close_mode)) = cx
+            .global::<DialogGlobal>()
+            .stack
+            .last()
+            .map(|entry| (entry.id, entry.widget.clone(), entry.close_mode))
+        else {
+            return;
+        };
+
+        // only the top dialog gets the finger events through the lock
+        let lock = cx.global::<DialogGlobal>().lock;
+        if let Some(lock) = lock {
+            cx.sweep_unlock(lock);
+        }
+        dialog.handle_event(cx, event, scope);
+        if let Some(lock) = lock {
+            cx.sweep_lock(lock);
+        }
+
+        match event {
+            Event::Actions(actions) => {
+                let result = cx
+                    .global::<DialogGlobal>()
+                    .stack
+                    .last()
+                    .and_then(|entry| entry.clicked_result(actions));
+                if result.is_some() {
+                    close_dialog_with(cx, id, result);
+                    return;
+                }
+            }
+            Event::KeyDown(e) => {
+                if e.key_code == KeyCode::Escape && close_mode == CloseMode::Out {
+                    close_dialog_with(cx, id, None);
+                    return;
+                }
+            }
+            Event::KeyFocus(e) => {
+                // focus trap: the focus can not leave the top dialog
+                let rect = dialog.area().rect(cx);
+                if !rect.contains(e.focus.rect(cx).pos) {
+                    cx.set_key_focus(dialog.area());
+                }
+            }
+            _ => {}
+        }
+
+        // the backdrop takes the rest of the finger events, widgets below the dialog are not reachable
+        let area = self.draw_backdrop.area();
+        match event.hits_with_sweep_area(cx, area, area) {
+            Hit::FingerUp(e) => {
+                if e.is_over
+                    && close_mode == CloseMode::Out
+                    && !dialog.area().rect(cx).contains(e.abs)
+                {
+                    close_dialog_with(cx, id, None);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -19,6 +19,8 @@ pub enum DropDownToggleEvent {
     Click(FingerUpEvent),
     Hover(FingerHoverEvent),
     Press(FingerDownEvent),
+    /// secondary click of `TriggerMode::ContextMenu`
+    ContextMenu(FingerDownEvent),
     // KetFocusLost(KeyFocusEvent),
     #[default]
     Other,
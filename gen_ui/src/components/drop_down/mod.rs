@@ -7,7 +7,12 @@ use std::{cell::RefCell, rc::Rc};
 use makepad_widgets::*;
 
 use crate::{
-    components::{popup::GPopupContainer, traits::PopupComponent, view::GView},
+    components::{
+        menu::{GMenuRef, GMenuWidgetRefExt},
+        popup::{GPopupContainer, Placement, flip_popup, place_popup, popup_viewport, shift_popup},
+        traits::PopupComponent,
+        view::GView,
+    },
     prop::{CloseMode, PopupMode, Position, TriggerMode},
     visible,
 };
@@ -57,6 +62,21 @@ pub struct GDropDown {
     pub proportion: f32,
     #[rust(true)]
     pub redraw_flag: bool,
+    /// where the context menu is opened (`TriggerMode::ContextMenu`)
+    #[rust]
+    pub pointer: DVec2,
+    /// flip, shift and constrain the popup to keep it inside the window (Popover/ToolTip)
+    #[live(true)]
+    pub auto_placement: bool,
+    /// the space kept between the popup and the window edge
+    #[live(8.0)]
+    pub viewport_padding: f32,
+    /// size of the popup in the last draw
+    #[rust]
+    popup_size: DVec2,
+    /// the side decided once per open, the popup does not flip while it is open
+    #[rust]
+    placed: Option<Position>,
 }
 
 #[derive(Default, Clone)]
@@ -81,72 +101,43 @@ impl Widget for GDropDown {
             let popup_walk = popup_menu.walk();
             popup_menu.begin(cx, popup_walk);
             match self.mode {
+                PopupMode::Popover | PopupMode::ToolTip if self.trigger_mode.is_context_menu() => {
+                    popup_menu.draw_popup(cx, scope, None, 0.0, &mut self.redraw_flag);
+                    let area = self.area().rect(cx);
+                    let container = popup_menu.area().rect(cx);
+                    let shift = self.context_menu_shift(cx, area, container.size);
+                    popup_menu.end(cx, scope, self.area(), shift);
+                }
                 PopupMode::Popover | PopupMode::ToolTip => {
                     let area = self.area().rect(cx);
-                    let angle_offset = self.position.angle_offset(area.size);
+                    let viewport = popup_viewport(cx, self.viewport_padding as f64);
+                    // decide the side before drawing if the popup has been measured (last open)
+                    if self.placed.is_none() && self.popup_size != DVec2::default() {
+                        self.placed = Some(self.flip(area, self.popup_size, viewport));
+                    }
+                    let drawn = self.placed.unwrap_or(self.position);
+                    let placement = self.placement(drawn, area, self.popup_size, viewport);
+                    popup_menu.set_max_size(self.auto_placement.then_some(placement.max_size));
                     popup_menu.draw_popup(
                         cx,
                         scope,
-                        Some(self.position),
-                        angle_offset,
+                        Some(placement.position),
+                        placement.arrow_offset,
                         &mut self.redraw_flag,
                     );
                     let container = popup_menu.area().rect(cx);
-                    let mut shift = match self.position {
-                        Position::Bottom => DVec2 {
-                            x: -container.size.x / 2.0 + area.size.x / 2.0,
-                            y: area.size.y + self.offset as f64,
-                        },
-                        Position::BottomLeft => DVec2 {
-                            x: 0.0,
-                            y: area.size.y + self.offset as f64,
-                        },
-                        Position::BottomRight => DVec2 {
-                            x: area.size.x - container.size.x,
-                            y: area.size.y + self.offset as f64,
-                        },
-                        Position::Top => DVec2 {
-                            x: -container.size.x / 2.0 + area.size.x / 2.0,
-                            y: -self.offset as f64 - container.size.y,
-                        },
-                        Position::TopLeft => DVec2 {
-                            x: 0.0,
-                            y: -self.offset as f64 - container.size.y,
-                        },
-                        Position::TopRight => DVec2 {
-                            x: area.size.x - container.size.x,
-                            y: -self.offset as f64 - container.size.y,
-                        },
-                        Position::Left => DVec2 {
-                            x: -self.offset as f64 - container.size.x,
-                            y: area.size.y / 2.0 - container.size.y / 2.0,
-                        },
-                        Position::LeftTop => DVec2 {
-                            x: -self.offset as f64 - container.size.x,
-                            y: 0.0,
-                        },
-                        Position::LeftBottom => DVec2 {
-                            x: -self.offset as f64 - container.size.x,
-                            y: 0.0 - container.size.y + area.size.y,
-                        },
-                        Position::Right => DVec2 {
-                            x: area.size.x + self.offset as f64,
-                            y: area.size.y / 2.0 - container.size.y / 2.0,
-                        },
-                        Position::RightTop => DVec2 {
-                            x: area.size.x + self.offset as f64,
-                            y: 0.0,
-                        },
-                        Position::RightBottom => DVec2 {
-                            x: area.size.x + self.offset as f64,
-                            y: 0.0 - container.size.y + area.size.y,
-                        },
-                    };
-
-                    shift.x += self.offset_x as f64;
-                    shift.y += self.offset_y as f64;
-
-                    popup_menu.end(cx, scope, self.area(), shift);
+                    if self.placed.is_none() {
+                        // first open, decide the side with the measured size
+                        self.placed = Some(self.flip(area, container.size, viewport));
+                    }
+                    let position = self.placed.unwrap_or(self.position);
+                    let placement = self.placement(position, area, container.size, viewport);
+                    if container.size != self.popup_size || position != drawn {
+                        // draw again with the right side and arrow
+                        self.popup_size = container.size;
+                        popup_menu.redraw(cx);
+                    }
+                    popup_menu.end(cx, scope, self.area(), placement.shift);
                 }
 
                 PopupMode::Dialog => {
@@ -175,10 +166,52 @@ impl Widget for GDropDown {
             let mut map = global.map.borrow_mut();
             let popup_menu = map.get_mut(&self.popup.unwrap()).unwrap();
             popup_menu.handle_event_with(cx, event, scope, self.area());
+            if self.trigger_mode.is_context_menu() {
+                match event {
+                    Event::KeyDown(e) => {
+                        if e.key_code == KeyCode::Escape {
+                            self.close_inner(cx, DropDownToggleEvent::Other, false);
+                        } else {
+                            for menu in popup_menus(popup_menu) {
+                                if let Some(mut menu) = menu.borrow_mut() {
+                                    let _ = menu.handle_key_down(cx, e);
+                                }
+                            }
+                        }
+                        return;
+                    }
+                    Event::Actions(actions) => {
+                        // selecting an item closes the context menu
+                        if popup_menus(popup_menu)
+                            .iter()
+                            .any(|menu| menu.selected(actions).is_some())
+                        {
+                            self.close_inner(cx, DropDownToggleEvent::Other, false);
+                            return;
+                        }
+                    }
+                    Event::MouseDown(e) => {
+                        // secondary click in the trigger area again moves the context menu to the pointer
+                        let is_in = menus_contain_pos(cx, popup_menu, e.abs);
+                        if !is_in
+                            && e.button == MouseButton::SECONDARY
+                            && self.area().rect(cx).contains(e.abs)
+                        {
+                            self.pointer = e.abs;
+                            reset_menus(cx, popup_menu);
+                            self.redraw(cx);
+                            return;
+                        }
+                    }
+                    _ => {}
+                }
+            }
             if let Event::MouseDown(e) = event {
                 match self.mode {
                     PopupMode::Popover | PopupMode::ToolTip => {
-                        let is_in = popup_menu.menu_contains_pos(cx, e.abs);
+                        // opened flyouts of the menus are drawn outside the popup
+                        let is_in = popup_menu.menu_contains_pos(cx, e.abs)
+                            || menus_contain_pos(cx, popup_menu, e.abs);
                         self.close_inner(cx, DropDownToggleEvent::Other, is_in);
                     }
 
@@ -206,6 +239,17 @@ impl Widget for GDropDown {
                 cx.set_key_focus(self.area());
                 if self.trigger_mode.is_press() {
                     self.open_inner(cx, DropDownToggleEvent::Press(e));
+                } else if self.trigger_mode.is_context_menu()
+                    && matches!(e.mouse_button(), Some(MouseButton::SECONDARY))
+                {
+                    self.pointer = e.abs;
+                    if let Some(popup) = self.popup.as_ref() {
+                        let global = cx.global::<PopupMenuGlobal>().clone();
+                        if let Some(popup_menu) = global.map.borrow_mut().get_mut(popup) {
+                            reset_menus(cx, popup_menu);
+                        }
+                    }
+                    self.open_inner(cx, DropDownToggleEvent::ContextMenu(e));
                 }
             }
             Hit::FingerHoverIn(e) => {
@@ -223,7 +267,7 @@ impl Widget for GDropDown {
             Hit::FingerUp(e) => {
                 if e.is_over && self.trigger_mode.is_click() {
                     self.open_inner(cx, DropDownToggleEvent::Click(e));
-                } else {
+                } else if !self.trigger_mode.is_context_menu() {
                     // focus lost
                     self.close_inner(cx, DropDownToggleEvent::Other, false);
                 }
@@ -283,6 +327,7 @@ impl GDropDown {
             return;
         }
         self.opened = true;
+        self.placed = None;
         self.redraw(cx);
         cx.sweep_lock(self.area());
         self.active_toggled(cx, e_kind);
@@ -312,6 +357,43 @@ impl GDropDown {
         }
         self.redraw_flag = true;
     }
+    /// the side of the popup for this open, see `placed`
+    fn flip(&self, area: Rect, size: DVec2, viewport: Rect) -> Position {
+        if self.auto_placement {
+            flip_popup(self.position, area, size, viewport, self.offset as f64)
+        } else {
+            self.position
+        }
+    }
+    fn placement(&self, position: Position, area: Rect, size: DVec2, viewport: Rect) -> Placement {
+        shift_popup(
+            position,
+            area,
+            size,
+            viewport,
+            self.offset as f64,
+            dvec2(self.offset_x as f64, self.offset_y as f64),
+            self.auto_placement,
+        )
+    }
+    /// the context menu is placed at the pointer (as a trigger without size), flipped above it
+    /// and shifted to keep it inside the window
+    fn context_menu_shift(&self, cx: &Cx2d, area: Rect, size: DVec2) -> DVec2 {
+        let pointer = Rect {
+            pos: self.pointer,
+            size: DVec2::default(),
+        };
+        let placement = place_popup(
+            Position::BottomLeft,
+            pointer,
+            size,
+            popup_viewport(cx, self.viewport_padding as f64),
+            0.0,
+            dvec2(self.offset_x as f64, self.offset_y as f64),
+            true,
+        );
+        pointer.pos + placement.shift - area.pos
+    }
     fn active_toggled(&mut self, cx: &mut Cx, e_kind: DropDownToggleEvent) {
         cx.widget_action(
             self.widget_uid(),
@@ -323,3 +405,32 @@ impl GDropDown {
         );
     }
 }
+
+/// the `GMenu`s placed in the popup
+fn popup_menus(popup_menu: &GPopupContainer) -> Vec<GMenuRef> {
+    popup_menu
+        .popup
+        .children
+        .iter()
+        .map(|(_, child)| child.as_gmenu())
+        .filter(|menu| menu.borrow().is_some())
+        .collect()
+}
+
+/// is the position on one of the menus (flyouts included)
+fn menus_contain_pos(cx: &Cx, popup_menu: &GPopupContainer, pos: DVec2) -> bool {
+    popup_menus(popup_menu).iter().any(|menu| {
+        menu.borrow()
+            .map_or(false, |menu| menu.contains_pos(cx, pos))
+    })
+}
+
+/// clear the keyboard focus and close the flyouts before the context menu is opened again
+fn reset_menus(cx: &mut Cx, popup_menu: &GPopupContainer) {
+    for menu in popup_menus(popup_menu) {
+        if let Some(mut menu) = menu.borrow_mut() {
+            menu.set_focus(cx, None);
+            menu.close_flyouts(cx, "");
+        }
+    }
+}
@@ -1,4 +1,11 @@
-use std::{cell::RefCell, fs::File, io::Read, path::PathBuf};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use makepad_widgets::{
     image_cache::{
@@ -8,6 +15,13 @@ use makepad_widgets::{
     *,
 };
 
+use super::{
+    disk_cache::ImageDiskCache,
+    event::ImageLoadError,
+    format::{decode_image, DecodedImage},
+};
+use crate::prop::ImgFormat;
+
 // #[cfg(target_arch = "wasm32")]
 // use wasm_bindgen::prelude::*;
 
@@ -45,50 +59,19 @@ pub trait ImageAsync: ImageCacheImpl {
                     .as_mut()
                     .unwrap()
                     .execute_rev(path.as_ref().to_path_buf(), move |image_path| {
-                        if let Ok(data) = fpath_u8(image_path.as_path()) {
-                            if image_path.extension().map(|s| s == "jpg").unwrap_or(false) {
-                                match ImageBuffer::from_jpg(&data) {
-                                    Ok(data) => {
-                                        Cx::post_action(AsyncImageLoad {
-                                            image_path,
-                                            result: RefCell::new(Some(Ok(data))),
-                                        });
-                                    }
-                                    Err(err) => {
-                                        Cx::post_action(AsyncImageLoad {
-                                            image_path,
-                                            result: RefCell::new(Some(Err(err))),
-                                        });
-                                    }
-                                }
-                            } else if image_path.extension().map(|s| s == "png").unwrap_or(false) {
-                                match ImageBuffer::from_png(&data) {
-                                    Ok(data) => {
-                                        Cx::post_action(AsyncImageLoad {
-                                            image_path,
-                                            result: RefCell::new(Some(Ok(data))),
-                                        });
-                                    }
-                                    Err(err) => {
-                                        Cx::post_action(AsyncImageLoad {
-                                            image_path,
-                                            result: RefCell::new(Some(Err(err))),
-                                        });
-                                    }
-                                }
-                            } else {
+                        match fpath_u8(image_path.as_path()) {
+                            Ok(data) => {
+                                // detect format by content, do not trust the extension
+                                let _ = post_data_load(image_path, &data);
+                            }
+                            Err(_) => {
                                 Cx::post_action(AsyncImageLoad {
-                                    image_path,
-                                    result: RefCell::new(Some(Err(ImageError::UnsupportedFormat))),
+                                    image_path: image_path.clone(),
+                                    result: RefCell::new(Some(Err(ImageError::PathNotFound(
+                                        image_path,
+                                    )))),
                                 });
                             }
-                        } else {
-                            Cx::post_action(AsyncImageLoad {
-                                image_path: image_path.clone(),
-                                result: RefCell::new(Some(Err(ImageError::PathNotFound(
-                                    image_path,
-                                )))),
-                            });
                         }
                     });
                 Ok(AsyncLoadResult::Loading(w, h))
@@ -121,6 +104,7 @@ pub trait ImageAsync: ImageCacheImpl {
         url: &str,
         height: usize,
         width: usize,
+        disk_cache: Option<ImageDiskCache>,
     ) -> Result<AsyncLoadResult, Box<dyn std::error::Error>> {
         // 创建一个基于 URL 的虚拟路径作为缓存键
         let url = url.to_string();
@@ -133,14 +117,30 @@ pub trait ImageAsync: ImageCacheImpl {
                     path.as_path().to_path_buf(),
                     ImageCacheEntry::Loading(width, height),
                 );
-                let request = HttpRequest::new(url.to_string(), HttpMethod::GET);
-                // let request_id = live_id!(ImageDownload);
-                let request_id = LiveId::from_str(&url);
-                cx.http_request(request_id, request);
                 if cx.get_global::<ImageCache>().thread_pool.is_none() {
                     cx.get_global::<ImageCache>().thread_pool =
                         Some(TagThreadPool::new(cx, cx.cpu_cores().max(3) - 2));
                 }
+                match disk_cache.filter(|disk_cache| disk_cache.contains(&url)) {
+                    Some(disk_cache) => {
+                        // hit disk cache, decode in thread pool and do not request again
+                        cx.get_global::<ImageCache>()
+                            .thread_pool
+                            .as_mut()
+                            .unwrap()
+                            .execute_rev(path, move |image_path| {
+                                let loaded = match disk_cache.get(&url) {
+                                    Some(data) => post_data_load(image_path, &data),
+                                    None => post_image_load(image_path, Err(ImageError::EmptyData)),
+                                };
+                                if !loaded {
+                                    // broken cache file, remove it then request again next time
+                                    let _ = disk_cache.remove(&url);
+                                }
+                            });
+                    }
+                    None => self.request_url(cx, &url),
+                }
                 // cx.get_global::<ImageCache>()
                 //     .thread_pool
                 //     .as_mut()
@@ -160,6 +160,42 @@ pub trait ImageAsync: ImageCacheImpl {
             }
         }
     }
+    /// ## send http request to get image
+    /// the request id is the `LiveId` of the url
+    fn request_url(&mut self, cx: &mut Cx, url: &str) -> () {
+        let request = HttpRequest::new(url.to_string(), HttpMethod::GET);
+        cx.http_request(LiveId::from_str(url), request);
+    }
+}
+
+/// # Image Load Errors
+/// `AsyncImageLoad` result can only be taken once, the image which takes an error result will
+/// record it here, so that every image waiting for the same path can know it has failed.
+/// Every image handles the action in the same event, so only the errors of the current event
+/// are kept.
+#[derive(Default)]
+pub struct ImageLoadErrors {
+    /// id of the event which posts the errors
+    event_id: u64,
+    map: HashMap<PathBuf, Arc<ImageLoadError>>,
+}
+
+impl ImageLoadErrors {
+    /// record the error of the path, the errors of the former events are dropped
+    pub fn insert(&mut self, event_id: u64, path: PathBuf, error: ImageLoadError) -> () {
+        if self.event_id != event_id {
+            self.event_id = event_id;
+            self.map.clear();
+        }
+        self.map.insert(path, Arc::new(error));
+    }
+    /// error of the path posted in the event
+    pub fn get(&self, event_id: u64, path: &Path) -> Option<Arc<ImageLoadError>> {
+        if self.event_id != event_id {
+            return None;
+        }
+        self.map.get(path).cloned()
+    }
 }
 
 pub trait TryFromCxImage {
@@ -178,14 +214,66 @@ impl TryFromCxImage for Cx {
 }
 
 pub fn parse_image_buffer(buf: Vec<u8>) -> Result<ImageBuffer, ImageError> {
-    match imghdr::from_bytes(&buf) {
-        Some(ty) => match ty {
-            imghdr::Type::Png => ImageBuffer::from_png(&buf),
-            imghdr::Type::Jpeg => ImageBuffer::from_jpg(&buf),
-            _ => Err(ImageError::UnsupportedFormat),
-        },
-        None => Err(ImageError::UnsupportedFormat),
+    decode_image(&buf).map(|decoded| decoded.buffer)
+}
+
+/// # Animated Image Load
+/// posted before `AsyncImageLoad` when the decoded image is animated (gif/apng/webp),
+/// `delays` is the display time (seconds) of each frame
+#[derive(Debug)]
+pub struct AnimatedImageLoad {
+    pub image_path: PathBuf,
+    pub delays: Vec<f64>,
+}
+
+/// # Image Frame Delays
+/// frame delays of animated images, keyed by image path (or url)
+#[derive(Default)]
+pub struct ImageFrameDelays {
+    pub map: HashMap<PathBuf, Arc<Vec<f64>>>,
+}
+
+/// post decoded result (call in thread pool), return true if the image is decoded
+pub fn post_image_load(image_path: PathBuf, result: Result<DecodedImage, ImageError>) -> bool {
+    let (result, is_ok) = match result {
+        Ok(DecodedImage { buffer, delays }) => {
+            if let Some(delays) = delays {
+                Cx::post_action(AnimatedImageLoad {
+                    image_path: image_path.clone(),
+                    delays,
+                });
+            }
+            (Ok(buffer), true)
+        }
+        Err(e) => (Err(e), false),
+    };
+    Cx::post_action(AsyncImageLoad {
+        image_path,
+        result: RefCell::new(Some(result)),
+    });
+    is_ok
+}
+
+/// # Svg Image Load
+/// posted instead of `AsyncImageLoad` when the loaded data is svg, which can not be decoded into a
+/// texture, `GImage` draws it by `GSvg`
+#[derive(Debug)]
+pub struct SvgImageLoad {
+    pub image_path: PathBuf,
+    pub data: Arc<Vec<u8>>,
+}
+
+/// post the loaded data (call in thread pool), svg is posted as `SvgImageLoad` and the others are
+/// decoded, return true if the data is an image
+pub fn post_data_load(image_path: PathBuf, data: &[u8]) -> bool {
+    if ImgFormat::sniff(data) == Some(ImgFormat::Svg) {
+        Cx::post_action(SvgImageLoad {
+            image_path,
+            data: Arc::new(data.to_vec()),
+        });
+        return true;
     }
+    post_image_load(image_path, decode_image(data))
 }
 
 /// load from path as u8
@@ -0,0 +1,217 @@
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
+
+use super::async_impl::fpath_u8;
+
+/// default max size of the disk cache: 64MB
+pub const DEFAULT_DISK_CACHE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// id of the temp file written by `ImageDiskCache::put`
+static TMP_ID: AtomicU64 = AtomicU64::new(0);
+
+/// # Image Disk Cache
+/// Persist images which are loaded from url (`Src::Url`) across runs, the default dir is
+/// `genui/image_cache` in the cache dir of the user (the temp dir if the system has none).
+/// Each image is stored as a file named by the hash of its url, when the total size of the
+/// cache dir is bigger than `max_size`, the least recently used files will be removed.
+///
+/// The cache is stored as a global, you can replace it before any image is loaded:
+/// ```rust,ignore
+/// cx.set_global(ImageDiskCache::new("/path/to/cache", 16 * 1024 * 1024));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImageDiskCache {
+    pub dir: PathBuf,
+    /// max size of the cache dir (bytes)
+    pub max_size: u64,
+}
+
+impl Default for ImageDiskCache {
+    fn default() -> Self {
+        Self::new(
+            dirs::cache_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join("genui")
+                .join("image_cache"),
+            DEFAULT_DISK_CACHE_SIZE,
+        )
+    }
+}
+
+impl ImageDiskCache {
+    pub fn new<P>(dir: P, max_size: u64) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+            max_size,
+        }
+    }
+    /// ## get the cache file path of the url
+    pub fn path(&self, url: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}", fnv1a(url.as_bytes())))
+    }
+    /// ## read cached image data of the url
+    /// the modified time of the file will be refreshed, so that it will be evicted later
+    pub fn get(&self, url: &str) -> Option<Vec<u8>> {
+        let path = self.path(url);
+        let data = fpath_u8(path.as_path()).ok()?;
+        if let Ok(file) = File::options().write(true).open(path.as_path()) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(data)
+    }
+    /// ## is the url cached
+    pub fn contains(&self, url: &str) -> bool {
+        self.path(url).is_file()
+    }
+    /// ## write image data of the url to the cache, then evict if over size
+    pub fn put(&self, url: &str, data: &[u8]) -> std::io::Result<()> {
+        if data.len() as u64 > self.max_size {
+            return Ok(());
+        }
+        fs::create_dir_all(self.dir.as_path())?;
+        // write a temp file in the same dir then rename it, so a reader never gets a partial image
+        let path = self.path(url);
+        let tmp = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TMP_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let write = || -> std::io::Result<()> {
+            let mut file = File::create(tmp.as_path())?;
+            file.write_all(data)?;
+            file.sync_all()?;
+            fs::rename(tmp.as_path(), path.as_path())
+        };
+        if let Err(e) = write() {
+            let _ = fs::remove_file(tmp.as_path());
+            return Err(e);
+        }
+        self.evict()
+    }
+    /// ## remove cached image of the url
+    pub fn remove(&self, url: &str) -> std::io::Result<()> {
+        let path = self.path(url);
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+    /// ## remove least recently used files until the cache size is not bigger than `max_size`
+    pub fn evict(&self) -> std::io::Result<()> {
+        let mut files = vec![];
+        for entry in fs::read_dir(self.dir.as_path())? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            // temp files (with extension) are being written by `put`
+            if !metadata.is_file() || entry.path().extension().is_some() {
+                continue;
+            }
+            files.push((
+                metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                metadata.len(),
+                entry.path(),
+            ));
+        }
+        for path in over_size(files, self.max_size) {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+    /// ## remove all cached images
+    pub fn clear(&self) -> std::io::Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(self.dir.as_path())?;
+        }
+        Ok(())
+    }
+}
+
+/// least recently used files (modified time, size, path) to remove, so the total size is not
+/// bigger than `max_size`
+fn over_size(mut files: Vec<(SystemTime, u64, PathBuf)>, max_size: u64) -> Vec<PathBuf> {
+    let mut total = files.iter().map(|(_, len, _)| len).sum::<u64>();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut removed = vec![];
+    for (_, len, path) in files {
+        if total <= max_size {
+            break;
+        }
+        total -= len;
+        removed.push(path);
+    }
+    removed
+}
+
+/// stable hash (FNV-1a 64) for url, do not use `DefaultHasher` which may change between versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325_u64;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{ImageDiskCache, over_size};
+
+    fn cache(name: &str, max_size: u64) -> ImageDiskCache {
+        let cache = ImageDiskCache::new(
+            std::env::temp_dir().join("genui_test").join(name),
+            max_size,
+        );
+        let _ = cache.clear();
+        cache
+    }
+
+    #[test]
+    fn put_and_get() {
+        let cache = cache("put_and_get", 1024);
+        cache.put("https://example.com/a.png", &[1, 2, 3]).unwrap();
+        assert_eq!(cache.get("https://example.com/a.png"), Some(vec![1, 2, 3]));
+        assert_eq!(cache.get("https://example.com/b.png"), None);
+        // the temp file is renamed to the cache file
+        cache.put("https://example.com/a.png", &[4, 5]).unwrap();
+        assert_eq!(cache.get("https://example.com/a.png"), Some(vec![4, 5]));
+        assert_eq!(std::fs::read_dir(&cache.dir).unwrap().count(), 1);
+        let _ = cache.clear();
+    }
+
+    #[test]
+    fn evict_over_size() {
+        let at = |secs: u64| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        let files = vec![
+            (at(3), 4, PathBuf::from("c")),
+            (at(1), 4, PathBuf::from("a")),
+            (at(2), 4, PathBuf::from("b")),
+        ];
+        assert_eq!(over_size(files.clone(), 8), vec![PathBuf::from("a")]);
+        assert_eq!(
+            over_size(files.clone(), 4),
+            vec![PathBuf::from("a"), PathBuf::from("b")]
+        );
+        assert!(over_size(files, 12).is_empty());
+    }
+
+    #[test]
+    fn skip_bigger_than_max_size() {
+        let cache = cache("skip_bigger_than_max_size", 8);
+        cache.put("https://example.com/d.png", &[0; 16]).unwrap();
+        assert!(!cache.contains("https://example.com/d.png"));
+        let _ = cache.clear();
+    }
+}
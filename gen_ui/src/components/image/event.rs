@@ -0,0 +1,42 @@
+use std::{fmt::Display, sync::Arc};
+
+use makepad_widgets::{ActionDefaultRef, DefaultNone, image_cache::ImageError};
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum ImageEvent {
+    Loaded(ImageLoaded),
+    LoadFailed(ImageLoadFailed),
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageLoaded {
+    pub src: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageLoadFailed {
+    pub src: String,
+    /// how many times the image has been requested (only url image will retry)
+    pub attempts: usize,
+    pub error: Arc<ImageLoadError>,
+}
+
+#[derive(Debug)]
+pub enum ImageLoadError {
+    /// the file can not be read or the data can not be decoded
+    Image(ImageError),
+    /// the request of the url is timeout, failed or responded without the image
+    Network(String),
+}
+
+impl Display for ImageLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageLoadError::Image(e) => write!(f, "{:?}", e),
+            ImageLoadError::Network(reason) => write!(f, "network error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ImageLoadError {}
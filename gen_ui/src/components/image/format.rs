@@ -0,0 +1,148 @@
+use std::io::Cursor;
+
+use image::{
+    codecs::{gif::GifDecoder, png::PngDecoder, webp::WebPDecoder},
+    AnimationDecoder, Frame, ImageFormat as CodecFormat, RgbaImage,
+};
+use makepad_widgets::image_cache::{ImageBuffer, ImageError};
+use makepad_widgets::TextureAnimation;
+
+use crate::prop::ImgFormat;
+
+/// # Decoded Image
+/// `delays` is the display time (seconds) of each frame, only animated images have it.
+pub struct DecodedImage {
+    pub buffer: ImageBuffer,
+    pub delays: Option<Vec<f64>>,
+}
+
+/// decode image data by its content, animated gif/apng/webp are decoded into a sprite sheet
+/// (frames are arranged in a grid, see `TextureAnimation`)
+pub fn decode_image(data: &[u8]) -> Result<DecodedImage, ImageError> {
+    let format = ImgFormat::sniff(data).ok_or(ImageError::UnsupportedFormat)?;
+    if let Some(frames) = animation_frames(format, data)? {
+        return from_frames(frames);
+    }
+    let buffer = match format {
+        ImgFormat::Png | ImgFormat::Apng => ImageBuffer::from_png(data)?,
+        ImgFormat::Jpeg => ImageBuffer::from_jpg(data)?,
+        ImgFormat::WebP => from_rgba(&decode_with(data, CodecFormat::WebP)?),
+        ImgFormat::Bmp => from_rgba(&decode_with(data, CodecFormat::Bmp)?),
+        // gif is always decoded as frames
+        ImgFormat::Gif => return Err(ImageError::UnsupportedFormat),
+        // svg is drawn by `GSvg`, `GImage` routes it before decoding
+        ImgFormat::Svg => return Err(ImageError::UnsupportedFormat),
+    };
+    Ok(DecodedImage {
+        buffer,
+        delays: None,
+    })
+}
+
+/// decode every frame with its display time (seconds), a still image is one frame without delay
+pub(crate) fn decode_frames(data: &[u8]) -> Result<Vec<(ImageBuffer, f64)>, ImageError> {
+    let format = ImgFormat::sniff(data).ok_or(ImageError::UnsupportedFormat)?;
+    match animation_frames(format, data)? {
+        Some(frames) if !frames.is_empty() => Ok(frames
+            .iter()
+            .map(|frame| (from_rgba(frame.buffer()), delay(frame)))
+            .collect()),
+        _ => decode_image(data).map(|image| vec![(image.buffer, 0.0)]),
+    }
+}
+
+/// frames of the animated formats (apng, gif, animated webp), `None` for still images
+fn animation_frames(format: ImgFormat, data: &[u8]) -> Result<Option<Vec<Frame>>, ImageError> {
+    let frames = match format {
+        ImgFormat::Apng => PngDecoder::new(Cursor::new(data))
+            .and_then(|decoder| decoder.apng())
+            .and_then(|decoder| decoder.into_frames().collect_frames()),
+        ImgFormat::Gif => GifDecoder::new(Cursor::new(data))
+            .and_then(|decoder| decoder.into_frames().collect_frames()),
+        ImgFormat::WebP => {
+            let decoder =
+                WebPDecoder::new(Cursor::new(data)).map_err(|_| ImageError::UnsupportedFormat)?;
+            if !decoder.has_animation() {
+                return Ok(None);
+            }
+            decoder.into_frames().collect_frames()
+        }
+        _ => return Ok(None),
+    };
+    frames.map(Some).map_err(|_| ImageError::UnsupportedFormat)
+}
+
+/// display time (seconds) of the frame
+fn delay(frame: &Frame) -> f64 {
+    let (numer, denom) = frame.delay().numer_denom_ms();
+    let delay = numer as f64 / denom.max(1) as f64 / 1000.0;
+    // browsers treat too small delay as 100ms
+    if delay < 0.02 { 0.1 } else { delay }
+}
+
+fn decode_with(data: &[u8], format: CodecFormat) -> Result<RgbaImage, ImageError> {
+    image::load_from_memory_with_format(data, format)
+        .map(|image| image.to_rgba8())
+        .map_err(|_| ImageError::UnsupportedFormat)
+}
+
+/// pack rgba pixel as makepad texture pixel (argb)
+fn pack(pixel: &[u8]) -> u32 {
+    (pixel[3] as u32) << 24 | (pixel[0] as u32) << 16 | (pixel[1] as u32) << 8 | pixel[2] as u32
+}
+
+fn from_rgba(image: &RgbaImage) -> ImageBuffer {
+    ImageBuffer {
+        width: image.width() as usize,
+        height: image.height() as usize,
+        data: image.pixels().map(|pixel| pack(&pixel.0)).collect(),
+        animation: None,
+    }
+}
+
+fn from_frames(frames: Vec<Frame>) -> Result<DecodedImage, ImageError> {
+    let first = frames.first().ok_or(ImageError::EmptyData)?;
+    if frames.len() == 1 {
+        return Ok(DecodedImage {
+            buffer: from_rgba(first.buffer()),
+            delays: None,
+        });
+    }
+    let (width, height) = (
+        first.buffer().width() as usize,
+        first.buffer().height() as usize,
+    );
+    let num_frames = frames.len();
+    // keep the sprite sheet close to a square, avoid hitting the max texture size
+    let columns = (num_frames as f64).sqrt().ceil() as usize;
+    let rows = num_frames.div_ceil(columns);
+    let sheet_width = width * columns;
+    let mut data = vec![0_u32; sheet_width * height * rows];
+    let mut delays = Vec::with_capacity(num_frames);
+
+    for (index, frame) in frames.iter().enumerate() {
+        delays.push(delay(frame));
+
+        let (x0, y0) = ((index % columns) * width, (index / columns) * height);
+        for (x, y, pixel) in frame.buffer().enumerate_pixels() {
+            let (x, y) = (x as usize, y as usize);
+            if x < width && y < height {
+                data[(y0 + y) * sheet_width + x0 + x] = pack(&pixel.0);
+            }
+        }
+    }
+
+    Ok(DecodedImage {
+        buffer: ImageBuffer {
+            width: sheet_width,
+            height: height * rows,
+            data,
+            animation: Some(TextureAnimation {
+                width,
+                height,
+                num_frames,
+            }),
+        },
+        delays: Some(delays),
+    })
+}
@@ -1,27 +1,44 @@
 mod async_impl;
+mod disk_cache;
+mod event;
+pub(crate) mod format;
 mod prop;
+mod register;
+pub mod viewer;
 
 use makepad_widgets::image_cache::{
     AsyncImageLoad, AsyncLoadResult, ImageCache, ImageCacheImpl, ImageError, ImageFit,
 };
+pub use disk_cache::*;
+pub use event::*;
+pub use format::*;
 pub use prop::*;
+pub use register::register as image_register;
+pub use viewer::*;
 
-use crate::components::image::async_impl::{parse_image_buffer, ImageAsync};
+use crate::components::image::async_impl::{
+    fpath_u8, post_data_load, AnimatedImageLoad, ImageAsync, ImageFrameDelays, ImageLoadErrors,
+    SvgImageLoad,
+};
 use crate::components::lifecycle::LifeCycle;
 use crate::components::traits::{BasicStyle, Component, Style};
+use crate::components::traits::PopupComponent;
+use crate::components::{GPopupContainer, GSvg, PopupMenuGlobal};
 use crate::error::Error;
 use crate::prop::manuel::{BASIC, LOADING};
-use crate::prop::{ApplyStateMap, Src, SrcType};
+use crate::prop::{ApplyStateMap, ImgFormat, Src, SrcType};
 use crate::shader::draw_image::DrawImg;
 use crate::themes::conf::Conf;
 use crate::{
-    animation_open_then_redraw, lifecycle, play_animation, pure_after_apply, set_index,
-    set_scope_path, sync, visible, ComponentAnInit,
+    active_event, animation_open_then_redraw, event_option, event_option_ref, lifecycle,
+    play_animation, pure_after_apply, set_index, set_scope_path, sync, visible, ComponentAnInit,
 };
 use makepad_widgets::*;
-use std::cell::RefCell;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::Arc;
 
 live_design! {
     link genui_basic;
@@ -72,11 +89,59 @@ pub struct GImage {
     async_image_size: Option<(usize, usize)>,
     #[rust]
     texture: Option<Texture>,
+    // --- svg -----------------------
+    /// svg (detected by the content of any src) is delegated to `GSvg`
+    #[live]
+    pub svg: GSvg,
+    #[rust]
+    is_svg: bool,
+    // --- url -----------------------
+    /// image shown while the url image is loading
+    #[live]
+    pub placeholder: Src,
+    /// image shown when the image failed to load
+    #[live]
+    pub error: Src,
+    /// cache url image on disk, see [`ImageDiskCache`]
+    #[live(true)]
+    pub disk_cache: bool,
+    /// retry times when the url image failed to load
+    #[live(2)]
+    pub retry: usize,
+    /// timeout (seconds) of each url request
+    #[live(15.0)]
+    pub timeout: f64,
+    #[rust]
+    attempts: usize,
+    #[rust]
+    timeout_timer: Timer,
+    #[rust]
+    show_placeholder: bool,
+    // --- preview -------------------
+    /// click to open the image in a full-window viewer (`GImageViewer`)
+    #[live]
+    pub preview: bool,
+    /// viewer popup container, which contains a `GImageViewer`
+    #[live]
+    pub viewer: Option<LivePtr>,
+    /// images can be stepped through in the viewer, if empty only `src` is shown
+    #[rust]
+    pub gallery: Vec<Src>,
+    #[rust]
+    previewing: bool,
     // --- animation -----------------
     #[live(ImageAnimation::BounceFps(25.0))]
     pub animation: ImageAnimation,
     #[live(true)]
     pub animation_open: bool,
+    /// play or pause the animated image (gif/apng/webp)
+    #[live(true)]
+    pub playing: bool,
+    /// frame delays of animated image decoded by genui, frames are played by `frame_timer`
+    #[rust]
+    frame_delays: Option<Arc<Vec<f64>>>,
+    #[rust]
+    frame_timer: Timer,
     // --- init ----------------------
     #[rust]
     pub lifecycle: LifeCycle,
@@ -111,12 +176,20 @@ impl WidgetNode for GImage {
     }
 
     fn area(&self) -> Area {
-        self.draw_img.area
+        if self.is_svg {
+            self.svg.area()
+        } else {
+            self.draw_img.area
+        }
     }
 
     fn redraw(&mut self, cx: &mut Cx) {
         // let _ = self.render(cx);
-        self.draw_img.redraw(cx);
+        if self.is_svg {
+            self.svg.redraw(cx);
+        } else {
+            self.draw_img.redraw(cx);
+        }
     }
     fn state(&self) -> String {
         self.state.to_string()
@@ -163,20 +236,41 @@ impl LiveHook for GImage {
 }
 
 impl Widget for GImage {
-    fn handle_event(&mut self, cx: &mut Cx, event: &Event, _scope: &mut Scope) {
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
         if !self.visible {
             return;
         }
         cx.global::<ComponentAnInit>().image = true;
+        if self.previewing {
+            self.handle_preview_event(cx, event, scope);
+        }
         let area = self.area();
         let hit = event.hits(cx, area);
+        if self.preview && !self.previewing {
+            match &hit {
+                Hit::FingerHoverIn(_) => cx.set_cursor(MouseCursor::Hand),
+                Hit::FingerHoverOut(_) => cx.set_cursor(MouseCursor::Default),
+                Hit::FingerUp(e) if e.is_over => self.open_preview(cx),
+                _ => {}
+            }
+        }
+        if self.is_svg {
+            return;
+        }
         self.handle_widget_event(cx, event, hit, area);
     }
 
-    fn draw_walk(&mut self, cx: &mut Cx2d, _scope: &mut Scope, mut walk: Walk) -> DrawStep {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, mut walk: Walk) -> DrawStep {
         if !self.visible {
             return DrawStep::done();
         }
+        self.set_scope_path(&scope.path);
+        if self.is_svg {
+            let walk = self.walk(cx);
+            let _ = self.svg.draw_walk(cx, scope, walk);
+            self.draw_preview(cx, scope);
+            return DrawStep::done();
+        }
         // alright we get a walk. depending on our aspect ratio
         // we change either nothing, or width or height
         let rect = cx.peek_walk_turtle(walk);
@@ -184,7 +278,14 @@ impl Widget for GImage {
         let style = self.style.get(self.state);
         let (width, height) = if let Some((w, h)) = &self.async_image_size {
             // still loading
-
+            match self.texture.as_ref() {
+                Some(placeholder) if self.show_placeholder => {
+                    self.draw_img.draw_vars.set_texture(0, placeholder);
+                }
+                _ => {
+                    self.draw_img.draw_vars.empty_texture(0);
+                }
+            }
             (*w as f64, *h as f64)
         } else if let Some(image_texture) = &self.texture {
             self.draw_img.draw_vars.set_texture(0, image_texture);
@@ -194,7 +295,10 @@ impl Widget for GImage {
                 .unwrap_or((style.min_width as usize, style.min_height as usize));
             if let Some(animation) = image_texture.animation(cx) {
                 let (w, h) = (animation.width as f64, animation.height as f64);
-                self.next_frame = cx.new_next_frame();
+                // frames with delays are driven by `frame_timer`
+                if self.frame_delays.is_none() && self.playing {
+                    self.next_frame = cx.new_next_frame();
+                }
                 // we have an animation. lets compute the scale and zoom for a certain frame
                 let scale_x = w as f32 / width as f32;
                 let scale_y = h as f32 / height as f32;
@@ -242,6 +346,7 @@ impl Widget for GImage {
         }
 
         self.draw_img.draw_walk(cx, walk);
+        self.draw_preview(cx, scope);
 
         DrawStep::done()
     }
@@ -329,6 +434,28 @@ impl GImageRef {
         }
     }
 
+    /// See [`GImage::open_preview()`].
+    pub fn open_preview(&self, cx: &mut Cx) -> () {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.open_preview(cx);
+        }
+    }
+
+    /// Set the gallery which can be stepped through in the viewer.
+    pub fn set_gallery(&self, gallery: Vec<Src>) -> () {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.gallery = gallery;
+        }
+    }
+
+    /// Reload the image from `src`, the failed url image will be requested again.
+    pub fn reload(&self, cx: &mut Cx) -> () {
+        if let Some(mut inner) = self.borrow_mut() {
+            let _ = inner.render(cx);
+            inner.redraw(cx);
+        }
+    }
+
     pub fn set_uniform(&self, cx: &Cx, uniform: &[LiveId], value: &[f32]) {
         if let Some(mut inner) = self.borrow_mut() {
             inner.draw_img.set_uniform(cx, uniform, value);
@@ -352,6 +479,30 @@ impl GImageRef {
             false
         }
     }
+
+    /// See [`GImage::play()`].
+    pub fn play(&self, cx: &mut Cx) -> () {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.play(cx);
+        }
+    }
+
+    /// See [`GImage::pause()`].
+    pub fn pause(&self, cx: &mut Cx) -> () {
+        if let Some(mut inner) = self.borrow_mut() {
+            inner.pause(cx);
+        }
+    }
+
+    /// See [`GImage::is_playing()`].
+    pub fn is_playing(&self) -> bool {
+        self.borrow().map_or(false, |inner| inner.is_playing())
+    }
+
+    event_option_ref! {
+        loaded => ImageLoaded,
+        load_failed => ImageLoadFailed
+    }
 }
 
 impl Component for GImage {
@@ -370,11 +521,13 @@ impl Component for GImage {
         // self.draw_img.merge(&style.into());
         // -----------------------------------------------------------
         self.lazy_create_image_cache(cx);
+        self.is_svg = false;
+        self.stop_frames(cx);
         match self.src.clone() {
             Src::None => {}
             Src::Live(live_dependency) => {
                 if !live_dependency.as_str().is_empty() {
-                    let _ = self.load_image_dep_by_path(cx, live_dependency.as_str(), 0);
+                    let _ = self.load_dep(cx, live_dependency);
                 }
             }
             _ => {
@@ -392,58 +545,121 @@ impl Component for GImage {
         // lets check if we have a post action
         if let Event::Actions(actions) = &event {
             for action in actions {
+                if let Some(AnimatedImageLoad { image_path, delays }) = &action.downcast_ref() {
+                    cx.global::<ImageFrameDelays>()
+                        .map
+                        .insert(image_path.to_path_buf(), Arc::new(delays.clone()));
+                }
+                if let Some(SvgImageLoad { image_path, data }) = &action.downcast_ref() {
+                    // svg is not kept in the image cache, it is cached by `GSvg`
+                    cx.get_global::<ImageCache>().map.remove(image_path);
+                    if self.async_image_size.is_some()
+                        && self.async_image_path.as_deref() == Some(image_path.as_path())
+                    {
+                        let key = image_path.to_string_lossy().to_string();
+                        self.load_svg(cx, &key, Rc::new(data.to_vec()));
+                        self.handle_loaded(cx);
+                    }
+                }
                 if let Some(AsyncImageLoad { image_path, result }) = &action.downcast_ref() {
                     if let Some(result) = result.borrow_mut().take() {
-                        // we have a result for the image_cache to load up
-                        self.process_async_image_load(cx, image_path, result);
+                        match result {
+                            Err(e) => {
+                                // record the error, every image waiting for this path will fail
+                                cx.get_global::<ImageCache>().map.remove(image_path);
+                                let event_id = cx.event_id();
+                                cx.global::<ImageLoadErrors>().insert(
+                                    event_id,
+                                    image_path.to_path_buf(),
+                                    ImageLoadError::Image(e),
+                                );
+                            }
+                            result => {
+                                // we have a result for the image_cache to load up
+                                let _ = self.process_async_image_load(cx, image_path, result);
+                            }
+                        }
                     }
                     if self.async_image_size.is_some()
                         && self.async_image_path.clone() == Some(image_path.to_path_buf())
                     {
-                        // see if we can load from cache
-                        self.load_image_from_cache(cx, image_path, 0);
-                        self.async_image_size = None;
-                        self.animator_play(cx, id!(loading.off));
-                        self.redraw(cx);
+                        let event_id = cx.event_id();
+                        let error = cx.global::<ImageLoadErrors>().get(event_id, image_path);
+                        if let Some(error) = error {
+                            self.handle_load_failed(cx, error);
+                        } else {
+                            // see if we can load from cache
+                            self.load_image_from_cache(cx, image_path, 0);
+                            self.start_frames_by_path(cx, image_path);
+                            self.handle_loaded(cx);
+                        }
                     }
                 }
             }
         } else if let Event::NetworkResponses(response_events) = &event {
             if self.src.is_url() {
                 for response_event in response_events {
+                    let image_live_id = LiveId::from_str(&self.src.to_string());
+                    if response_event.request_id != image_live_id
+                        || self.async_image_size.is_none()
+                    {
+                        continue;
+                    }
                     match &response_event.response {
                         NetworkResponse::HttpResponse(response) => {
-                            let image_live_id = LiveId::from_str(&self.src.to_string());
-                            if response_event.request_id == image_live_id {
-                                if response.status_code == 200 {
+                            match (response.status_code, response.body.as_ref()) {
+                                (200, Some(buf)) => {
+                                    cx.stop_timer(self.timeout_timer);
                                     // 这是图片的下载请求，请求方式为GET，我们需要转为buf
-                                    if let Some(buf) = &response.body {
-                                        let buf = buf.clone();
-                                        let path_str = self.src.to_string();
-                                        let path = PathBuf::from(&path_str);
-                                        cx.get_global::<ImageCache>()
-                                            .thread_pool
-                                            .as_mut()
-                                            .unwrap()
-                                            .execute_rev(path, move |image_path| {
-                                                let result = parse_image_buffer(buf);
-                                                Cx::post_action(AsyncImageLoad {
-                                                    image_path,
-                                                    result: RefCell::new(Some(result)),
-                                                });
-                                            });
-                                    }
+                                    let buf = buf.clone();
+                                    let url = self.src.to_string();
+                                    let path = PathBuf::from(&url);
+                                    let disk_cache = self
+                                        .disk_cache
+                                        .then(|| cx.global::<ImageDiskCache>().clone());
+                                    cx.get_global::<ImageCache>()
+                                        .thread_pool
+                                        .as_mut()
+                                        .unwrap()
+                                        .execute_rev(path, move |image_path| {
+                                            let decoded = post_data_load(image_path, &buf);
+                                            if let (true, Some(disk_cache)) =
+                                                (decoded, disk_cache)
+                                            {
+                                                let _ = disk_cache.put(&url, &buf);
+                                            }
+                                        });
+                                }
+                                (200, None) => {
+                                    self.handle_url_failed(cx, "empty response body".to_string());
+                                }
+                                (status_code, _) => {
+                                    self.handle_url_failed(
+                                        cx,
+                                        format!("status code {}", status_code),
+                                    );
                                 }
                             }
                         }
+                        NetworkResponse::HttpRequestError(e) => {
+                            self.handle_url_failed(cx, e.message.to_string());
+                        }
                         _ => {}
                     }
                 }
             }
         }
 
+        if self.timeout_timer.is_event(event).is_some() {
+            self.handle_url_failed(cx, "timeout".to_string());
+        }
+
+        if self.frame_timer.is_event(event).is_some() {
+            self.next_delay_frame(cx);
+        }
+
         let style = self.style.get(self.state);
-        if let Some(nf) = self.next_frame.is_event(event) {
+        if let Some(nf) = self.next_frame.is_event(event).filter(|_| self.playing) {
             // compute the next frame and patch things up
             if let Some(image_texture) = &self.texture {
                 let (texture_width, texture_height) = image_texture
@@ -565,18 +781,20 @@ impl Component for GImage {
 impl GImage {
     pub fn load(&mut self, cx: &mut Cx, src: &str) -> Result<(), Box<dyn std::error::Error>> {
         let src_type = SrcType::from_str(src)?;
+        self.is_svg = false;
         let _ = match src_type {
             SrcType::Path(path_buf) => self.load_from_local_break(cx, path_buf.as_path()),
             SrcType::Url(url) => {
                 // use reqwest::get do not jam the main thread
-                self.animator_play(cx, id!(loading.on));
+                self.attempts = 1;
+                let placeholder = self.placeholder.clone();
+                self.show_placeholder = self.load_fallback(cx, &placeholder).is_ok();
+                if !self.show_placeholder {
+                    self.animator_play(cx, id!(loading.on));
+                }
                 self.load_from_url_break(cx, url)
             }
-            SrcType::Base64 { data, ty } => match ty {
-                imghdr::Type::Png => self.load_png_from_data(cx, &data, 0).map_err(|e| e.into()),
-                imghdr::Type::Jpeg => self.load_jpg_from_data(cx, &data, 0).map_err(|e| e.into()),
-                _ => Err(ImageError::UnsupportedFormat.into()),
-            },
+            SrcType::Base64 { data, .. } => self.load_from_data(cx, &data).map_err(|e| e.into()),
         }?;
         Ok(())
     }
@@ -587,7 +805,10 @@ impl GImage {
         url: String,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let (height, width) = self.get_size_when_load();
-        let result = self.load_from_url(cx, &url, height, width)?;
+        let disk_cache = self
+            .disk_cache
+            .then(|| cx.global::<ImageDiskCache>().clone());
+        let result = self.load_from_url(cx, &url, height, width, disk_cache)?;
         self.handle_async_result(cx, result, PathBuf::from(url));
         Ok(())
     }
@@ -613,17 +834,273 @@ impl GImage {
             AsyncLoadResult::Loading(w, h) => {
                 self.async_image_size = Some((w, h));
                 self.async_image_path = Some(path.as_ref().to_path_buf());
-                self.animator_play(cx, id!(loading.on));
+                if !self.show_placeholder {
+                    self.animator_play(cx, id!(loading.on));
+                }
                 self.redraw(cx);
             }
             AsyncLoadResult::Loaded => {
                 self.load_image_from_cache(cx, path.as_ref(), 0);
-                self.async_image_size = None;
-                self.animator_play(cx, id!(loading.off));
-                self.redraw(cx);
+                self.start_frames_by_path(cx, path.as_ref());
+                self.handle_loaded(cx);
+            }
+        }
+    }
+    fn handle_loaded(&mut self, cx: &mut Cx) -> () {
+        cx.stop_timer(self.timeout_timer);
+        self.async_image_size = None;
+        self.show_placeholder = false;
+        self.animator_play(cx, id!(loading.off));
+        self.active_loaded(cx, self.src.to_string());
+        self.redraw(cx);
+    }
+    /// url request failed (bad status, request error or timeout), retry or give up
+    /// retry the url, or fail with the `reason` of the last attempt
+    fn handle_url_failed(&mut self, cx: &mut Cx, reason: String) -> () {
+        cx.stop_timer(self.timeout_timer);
+        if self.async_image_size.is_none() {
+            return;
+        }
+        let url = self.src.to_string();
+        if self.attempts <= self.retry {
+            self.attempts += 1;
+            self.request_url(cx, &url);
+        } else {
+            let path = PathBuf::from(&url);
+            cx.get_global::<ImageCache>().map.remove(&path);
+            self.handle_load_failed(cx, Arc::new(ImageLoadError::Network(reason)));
+        }
+    }
+    fn handle_load_failed(&mut self, cx: &mut Cx, error: Arc<ImageLoadError>) -> () {
+        cx.stop_timer(self.timeout_timer);
+        self.async_image_size = None;
+        self.async_image_path = None;
+        self.show_placeholder = false;
+        self.animator_play(cx, id!(loading.off));
+        let error_src = self.error.clone();
+        if self.load_fallback(cx, &error_src).is_err() {
+            self.texture = None;
+        }
+        self.active_load_failed(cx, self.src.to_string(), self.attempts, error);
+        self.redraw(cx);
+    }
+    /// load placeholder or error image, only `Live`, `File` and `Base64` are supported,
+    /// these sources are loaded synchronously
+    fn load_fallback(&mut self, cx: &mut Cx, src: &Src) -> Result<(), Box<dyn std::error::Error>> {
+        match src {
+            Src::Live(dep) if !dep.as_str().is_empty() => {
+                self.load_dep(cx, dep.clone()).map_err(|e| e.into())
+            }
+            Src::Base64(_) | Src::File(_) if !src.is_empty() => {
+                match SrcType::from_str(&src.to_string())? {
+                    SrcType::Path(path) => {
+                        let data = fpath_u8(path.as_path())?;
+                        self.load_from_data(cx, &data).map_err(|e| e.into())
+                    }
+                    SrcType::Base64 { data, .. } => {
+                        self.load_from_data(cx, &data).map_err(|e| e.into())
+                    }
+                    SrcType::Url(_) => Err(ImageError::UnsupportedFormat.into()),
+                }
             }
+            _ => Err(ImageError::UnsupportedFormat.into()),
+        }
+    }
+    /// load image from encoded data, the format is detected from the content
+    pub fn load_from_data(&mut self, cx: &mut Cx, data: &[u8]) -> Result<(), ImageError> {
+        if ImgFormat::sniff(data) == Some(ImgFormat::Svg) {
+            // the same data shares the same svg dependency
+            let mut hasher = DefaultHasher::new();
+            data.hash(&mut hasher);
+            let key = format!("data/{:x}", hasher.finish());
+            self.load_svg(cx, &key, Rc::new(data.to_vec()));
+            return Ok(());
+        }
+        let DecodedImage { buffer, delays } = decode_image(data)?;
+        self.is_svg = false;
+        self.stop_frames(cx);
+        self.texture = Some(buffer.into_new_texture(cx));
+        self.start_frames(cx, delays.map(Arc::new));
+        Ok(())
+    }
+    /// load a live dependency, svg is drawn by `GSvg`
+    fn load_dep(&mut self, cx: &mut Cx, dep: LiveDependency) -> Result<(), ImageError> {
+        let is_svg = cx.get_dependency(dep.as_str()).map_or_else(
+            |_| dep.as_str().to_lowercase().ends_with(".svg"),
+            |data| ImgFormat::sniff(&data) == Some(ImgFormat::Svg),
+        );
+        self.is_svg = is_svg;
+        if is_svg {
+            self.svg.src = dep;
+            let _ = self.svg.render(cx);
+            Ok(())
+        } else {
+            self.load_image_dep_by_path(cx, dep.as_str(), 0)
         }
     }
+    /// draw the svg data by `GSvg`, `key` names the data (such as the path or url)
+    fn load_svg(&mut self, cx: &mut Cx, key: &str, data: Rc<Vec<u8>>) -> () {
+        self.stop_frames(cx);
+        self.texture = None;
+        self.is_svg = true;
+        self.svg.load_data(cx, key, data);
+        self.redraw(cx);
+    }
+    fn start_frames_by_path(&mut self, cx: &mut Cx, path: &Path) -> () {
+        let delays = cx.global::<ImageFrameDelays>().map.get(path).cloned();
+        self.start_frames(cx, delays);
+    }
+    fn start_frames(&mut self, cx: &mut Cx, delays: Option<Arc<Vec<f64>>>) -> () {
+        cx.stop_timer(self.frame_timer);
+        self.frame_delays = delays;
+        self.animation_frame = 0.0;
+        self.draw_img.image_pan = vec2(0.0, 0.0);
+        if self.playing {
+            self.play(cx);
+        }
+    }
+    fn stop_frames(&mut self, cx: &mut Cx) -> () {
+        cx.stop_timer(self.frame_timer);
+        self.frame_delays = None;
+    }
+    /// ## play animated image
+    /// - gif/apng/webp decoded by genui: play frames by their own delays, `Stop/Frame/Factor`
+    ///   keep a fixed frame, `Once/OnceFps` stop at the last frame
+    /// - other animated texture: play by `animation`
+    pub fn play(&mut self, cx: &mut Cx) -> () {
+        self.playing = true;
+        match self.frame_delays.as_ref() {
+            Some(delays) => {
+                cx.stop_timer(self.frame_timer);
+                let delay = delays
+                    .get(self.animation_frame as usize)
+                    .copied()
+                    .unwrap_or(0.1);
+                self.frame_timer = cx.start_timeout(delay);
+            }
+            None => {
+                self.last_time = None;
+                self.next_frame = cx.new_next_frame();
+            }
+        }
+    }
+    /// ## pause animated image at current frame
+    pub fn pause(&mut self, cx: &mut Cx) -> () {
+        self.playing = false;
+        cx.stop_timer(self.frame_timer);
+    }
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+    fn next_delay_frame(&mut self, cx: &mut Cx) -> () {
+        let (Some(delays), Some(texture)) = (self.frame_delays.clone(), self.texture.as_ref())
+        else {
+            return;
+        };
+        let (Some(animation), Some((texture_width, texture_height))) = (
+            texture.animation(cx).clone(),
+            texture.get_format(cx).vec_width_height(),
+        ) else {
+            return;
+        };
+        let num_frames = delays.len().max(1);
+        let last_frame = num_frames - 1;
+        let current = self.animation_frame as usize;
+        let (frame, go_on) = match self.animation {
+            ImageAnimation::Stop => (current, false),
+            ImageAnimation::Frame(frame) => ((frame as usize).min(last_frame), false),
+            ImageAnimation::Factor(pos) => {
+                (((pos * last_frame as f64) as usize).min(last_frame), false)
+            }
+            ImageAnimation::Once | ImageAnimation::OnceFps(_) => {
+                let frame = (current + 1).min(last_frame);
+                (frame, frame < last_frame)
+            }
+            _ => ((current + 1) % num_frames, true),
+        };
+        self.animation_frame = frame as f64;
+
+        let horizontal_frames = (texture_width / animation.width).max(1);
+        let xpos = ((frame % horizontal_frames) * animation.width) as f32 / texture_width as f32;
+        let ypos = ((frame / horizontal_frames) * animation.height) as f32 / texture_height as f32;
+        self.draw_img.image_pan = vec2(xpos, ypos);
+        self.draw_img.update_instance_area_value(cx, id!(image_pan));
+
+        if go_on && self.playing {
+            self.frame_timer = cx.start_timeout(delays[frame]);
+        }
+    }
+    active_event! {
+        active_loaded: ImageEvent::Loaded |src: String| => ImageLoaded { src },
+        active_load_failed: ImageEvent::LoadFailed |src: String, attempts: usize, error: Arc<ImageLoadError>| => ImageLoadFailed { src, attempts, error }
+    }
+    /// ## open the viewer popup
+    /// the gallery (or `src` only if gallery is empty) is shown from the current `src`
+    pub fn open_preview(&mut self, cx: &mut Cx) -> () {
+        let Some(viewer) = self.viewer else {
+            return;
+        };
+        let gallery = if self.gallery.is_empty() {
+            vec![self.src.clone()]
+        } else {
+            self.gallery.clone()
+        };
+        let src = self.src.to_string();
+        let index = gallery
+            .iter()
+            .position(|item| item.to_string() == src)
+            .unwrap_or(0);
+        let global = cx.global::<PopupMenuGlobal>().clone();
+        let mut map = global.map.borrow_mut();
+        map.retain(|k, _| cx.live_registry.borrow().generation_valid(*k));
+        let container = map.get_or_insert(cx, viewer, |cx| {
+            GPopupContainer::new_from_ptr(cx, Some(viewer))
+        });
+        image_viewer(container).open(cx, gallery, index);
+        self.previewing = true;
+        cx.sweep_lock(self.area());
+        self.redraw(cx);
+    }
+    fn close_preview(&mut self, cx: &mut Cx) -> () {
+        self.previewing = false;
+        cx.sweep_unlock(self.area());
+        self.redraw(cx);
+    }
+    fn handle_preview_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) -> () {
+        let Some(viewer) = self.viewer else {
+            return;
+        };
+        let global = cx.global::<PopupMenuGlobal>().clone();
+        let mut map = global.map.borrow_mut();
+        let Some(container) = map.get_mut(&viewer) else {
+            return;
+        };
+        container.handle_event_with(cx, event, scope, self.area());
+        if !image_viewer(container).is_opened() {
+            self.close_preview(cx);
+        }
+    }
+    fn draw_preview(&mut self, cx: &mut Cx2d, scope: &mut Scope) -> () {
+        if !self.previewing {
+            return;
+        }
+        let Some(viewer) = self.viewer else {
+            return;
+        };
+        let global = cx.global::<PopupMenuGlobal>().clone();
+        let mut map = global.map.borrow_mut();
+        let Some(container) = map.get_mut(&viewer) else {
+            return;
+        };
+        let walk = container.walk();
+        container.begin(cx, walk);
+        container.draw_popup(cx, scope, None, 0.0, &mut false);
+        container.end(cx, scope, Area::Empty, DVec2::default());
+    }
+    event_option! {
+        loaded: ImageEvent::Loaded => ImageLoaded,
+        load_failed: ImageEvent::LoadFailed => ImageLoadFailed
+    }
 
     pub fn get_size_when_load(&self) -> (usize, usize) {
         let style = self.style.get(self.state);
@@ -653,4 +1130,24 @@ impl GImage {
     }
 }
 
-impl ImageAsync for GImage {}
+impl ImageAsync for GImage {
+    /// send the request and wait for the response until `timeout`,
+    /// a disk cache hit does not request so it is never timed out
+    fn request_url(&mut self, cx: &mut Cx, url: &str) -> () {
+        let request = HttpRequest::new(url.to_string(), HttpMethod::GET);
+        cx.http_request(LiveId::from_str(url), request);
+        cx.stop_timer(self.timeout_timer);
+        self.timeout_timer = cx.start_timeout(self.timeout);
+    }
+}
+
+/// find the `GImageViewer` in the viewer popup container
+fn image_viewer(container: &GPopupContainer) -> GImageViewerRef {
+    container
+        .popup
+        .children
+        .iter()
+        .map(|(_, child)| child.as_gimage_viewer())
+        .find(|viewer| viewer.borrow().is_some())
+        .unwrap_or_default()
+}
@@ -0,0 +1,6 @@
+use makepad_widgets::Cx;
+
+pub fn register(cx: &mut Cx) {
+    crate::components::image::live_design(cx);
+    crate::components::image::viewer::live_design(cx);
+}
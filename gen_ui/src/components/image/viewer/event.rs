@@ -0,0 +1,20 @@
+use makepad_widgets::{ActionDefaultRef, DefaultNone};
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum ImageViewerEvent {
+    Changed(ImageViewerChanged),
+    Closed(ImageViewerClosed),
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageViewerChanged {
+    /// index of the current image in gallery
+    pub index: usize,
+    pub src: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageViewerClosed {
+    pub index: usize,
+}
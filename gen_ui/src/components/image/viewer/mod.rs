@@ -0,0 +1,409 @@
+mod event;
+
+pub use event::*;
+
+use makepad_widgets::*;
+
+use crate::{
+    components::{image::GImage, traits::Component, view::GView, GButtonWidgetExt},
+    event_option, event_option_ref,
+    prop::Src,
+    shader::draw_view::DrawView,
+    visible,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GImageViewerBase = {{GImageViewer}} {}
+}
+
+/// # Image Viewer
+/// Preview images in a full-window popup, it is opened by `GImage` when `preview: true`.
+///
+/// ## Display
+/// ```md
+/// ---------------------------------------------
+/// |                                           |
+/// |                 image                     |
+/// |                                           |
+/// |   < > + - ⟲ ⟳ reset x  (toolbar)          |
+/// ---------------------------------------------
+/// ```
+/// - mouse: wheel to zoom, drag to pan, double click to zoom in/reset
+/// - touch: pinch to zoom, drag to pan, double tap to zoom in/reset
+/// - keyboard: `←` `→` step gallery, `+` `-` zoom, `R` rotate, `0` reset, `Esc` close
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GImageViewer {
+    #[live]
+    pub image: GImage,
+    /// toolbar buttons are found by id:
+    /// `prev`, `next`, `zoom_in`, `zoom_out`, `rotate_left`, `rotate_right`, `reset`, `close`
+    #[live]
+    pub toolbar: GView,
+    #[live]
+    pub draw_viewer: DrawView,
+    #[live(true)]
+    pub visible: bool,
+    #[live(true)]
+    pub event_open: bool,
+    #[live(0.2)]
+    pub min_zoom: f64,
+    #[live(8.0)]
+    pub max_zoom: f64,
+    /// zoom step of wheel and toolbar
+    #[live(0.25)]
+    pub zoom_step: f64,
+    #[rust]
+    pub gallery: Vec<Src>,
+    #[rust]
+    pub current: usize,
+    #[rust]
+    pub opened: bool,
+    #[rust(1.0)]
+    zoom: f64,
+    #[rust]
+    pan: DVec2,
+    /// clockwise rotation in 90° steps
+    #[rust]
+    rotation: usize,
+    /// (finger start, pan start)
+    #[rust]
+    drag: Option<(DVec2, DVec2)>,
+    #[rust]
+    touches: Vec<(DigitId, DVec2)>,
+    /// (distance start, zoom start)
+    #[rust]
+    pinch: Option<(f64, f64)>,
+    #[rust]
+    pub scope_path: Option<HeapLiveIdPath>,
+}
+
+impl LiveHook for GImageViewer {}
+
+impl WidgetNode for GImageViewer {
+    fn uid_to_widget(&self, uid: WidgetUid) -> WidgetRef {
+        self.toolbar.uid_to_widget(uid)
+    }
+
+    fn find_widgets(&self, path: &[LiveId], cached: WidgetCache, results: &mut WidgetSet) {
+        self.toolbar.find_widgets(path, cached, results);
+    }
+
+    fn walk(&mut self, _cx: &mut Cx) -> Walk {
+        Walk::fill()
+    }
+
+    fn area(&self) -> Area {
+        self.draw_viewer.area()
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.draw_viewer.redraw(cx);
+    }
+
+    visible!();
+}
+
+impl Widget for GImageViewer {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        if !self.visible {
+            return DrawStep::done();
+        }
+        let rect = cx.peek_walk_turtle(walk);
+        self.draw_viewer.begin(
+            cx,
+            walk,
+            Layout {
+                flow: Flow::Down,
+                align: Align { x: 0.5, y: 1.0 },
+                padding: Padding {
+                    bottom: 24.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        let image_walk = self.image_walk(cx, rect);
+        self.image.draw_img.rotation = self.rotation as f32;
+        let _ = self.image.draw_walk(cx, scope, image_walk);
+        let toolbar_walk = self.toolbar.walk(cx);
+        let _ = self.toolbar.draw_walk(cx, scope, toolbar_walk);
+        self.draw_viewer.end(cx);
+        if self.scope_path.is_none() {
+            self.scope_path.replace(scope.path.clone());
+        }
+        DrawStep::done()
+    }
+
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible || !self.opened {
+            return;
+        }
+        self.image.handle_event(cx, event, scope);
+        self.toolbar.handle_event(cx, event, scope);
+        self.match_event(cx, event);
+
+        let area = self.area();
+        match event.hits(cx, area) {
+            Hit::FingerScroll(e) => {
+                if e.scroll.y != 0.0 {
+                    let factor = if e.scroll.y < 0.0 {
+                        1.0 + self.zoom_step
+                    } else {
+                        1.0 / (1.0 + self.zoom_step)
+                    };
+                    self.zoom_at(cx, self.zoom * factor, Some(e.abs));
+                }
+            }
+            Hit::FingerDown(e) => {
+                cx.set_key_focus(area);
+                self.touches.retain(|(digit_id, _)| *digit_id != e.digit_id);
+                self.touches.push((e.digit_id, e.abs));
+                if self.touches.len() >= 2 {
+                    self.drag = None;
+                    self.pinch = Some((self.touches_distance(), self.zoom));
+                } else if e.tap_count == 2 {
+                    // double click/tap, zoom in at the finger or reset
+                    if self.zoom > 1.0 {
+                        self.reset(cx);
+                    } else {
+                        self.zoom_at(cx, 2.0, Some(e.abs));
+                    }
+                } else {
+                    self.drag = Some((e.abs, self.pan));
+                }
+            }
+            Hit::FingerMove(e) => {
+                if let Some(touch) = self
+                    .touches
+                    .iter_mut()
+                    .find(|(digit_id, _)| *digit_id == e.digit_id)
+                {
+                    touch.1 = e.abs;
+                }
+                if let Some((start_distance, start_zoom)) = self.pinch {
+                    if self.touches.len() >= 2 && start_distance > 0.0 {
+                        let center = (self.touches[0].1 + self.touches[1].1) * 0.5;
+                        let zoom = start_zoom * self.touches_distance() / start_distance;
+                        self.zoom_at(cx, zoom, Some(center));
+                    }
+                } else if let Some((start, start_pan)) = self.drag {
+                    self.pan = start_pan + (e.abs - start);
+                    self.redraw(cx);
+                }
+            }
+            Hit::FingerUp(e) => {
+                self.touches.retain(|(digit_id, _)| *digit_id != e.digit_id);
+                self.pinch = None;
+                // one finger left after pinch, keep dragging with it
+                self.drag = self.touches.first().map(|(_, abs)| (*abs, self.pan));
+            }
+            Hit::KeyDown(e) => match e.key_code {
+                KeyCode::ArrowLeft => self.prev(cx),
+                KeyCode::ArrowRight => self.next(cx),
+                KeyCode::Equals | KeyCode::NumpadAdd => self.zoom_in(cx),
+                KeyCode::Minus | KeyCode::NumpadSubtract => self.zoom_out(cx),
+                KeyCode::KeyR => self.rotate(cx, !e.modifiers.shift),
+                KeyCode::Key0 => self.reset(cx),
+                KeyCode::Escape => self.close(cx),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+impl MatchEvent for GImageViewer {
+    fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        if self.toolbar.gbutton(id!(prev)).clicked(actions).is_some() {
+            self.prev(cx);
+        }
+        if self.toolbar.gbutton(id!(next)).clicked(actions).is_some() {
+            self.next(cx);
+        }
+        if self.toolbar.gbutton(id!(zoom_in)).clicked(actions).is_some() {
+            self.zoom_in(cx);
+        }
+        if self.toolbar.gbutton(id!(zoom_out)).clicked(actions).is_some() {
+            self.zoom_out(cx);
+        }
+        if self.toolbar.gbutton(id!(rotate_left)).clicked(actions).is_some() {
+            self.rotate(cx, false);
+        }
+        if self.toolbar.gbutton(id!(rotate_right)).clicked(actions).is_some() {
+            self.rotate(cx, true);
+        }
+        if self.toolbar.gbutton(id!(reset)).clicked(actions).is_some() {
+            self.reset(cx);
+        }
+        if self.toolbar.gbutton(id!(close)).clicked(actions).is_some() {
+            self.close(cx);
+        }
+    }
+}
+
+impl GImageViewer {
+    /// ## open the viewer with gallery, `index` is the image shown first
+    pub fn open(&mut self, cx: &mut Cx, gallery: Vec<Src>, index: usize) -> () {
+        self.gallery = gallery;
+        self.opened = true;
+        self.set_current(cx, index);
+        cx.set_key_focus(self.area());
+    }
+    pub fn close(&mut self, cx: &mut Cx) -> () {
+        if !self.opened {
+            return;
+        }
+        self.opened = false;
+        self.touches.clear();
+        self.drag = None;
+        self.pinch = None;
+        self.active_closed(cx);
+        self.redraw(cx);
+    }
+    /// ## show the image of gallery at `index` and reset the transform
+    pub fn set_current(&mut self, cx: &mut Cx, index: usize) -> () {
+        let Some(src) = self.gallery.get(index).cloned() else {
+            return;
+        };
+        self.current = index;
+        self.image.src = src;
+        let _ = self.image.render(cx);
+        // prev and next are useless with only one image
+        let multiple = self.gallery.len() > 1;
+        for id in [id!(prev), id!(next)] {
+            self.toolbar.gbutton(id).set_visible(cx, multiple);
+        }
+        self.reset(cx);
+        self.active_changed(cx);
+    }
+    /// step to previous image, wrap to the last one
+    pub fn prev(&mut self, cx: &mut Cx) -> () {
+        let len = self.gallery.len();
+        if len > 1 {
+            self.set_current(cx, (self.current + len - 1) % len);
+        }
+    }
+    /// step to next image, wrap to the first one
+    pub fn next(&mut self, cx: &mut Cx) -> () {
+        let len = self.gallery.len();
+        if len > 1 {
+            self.set_current(cx, (self.current + 1) % len);
+        }
+    }
+    pub fn zoom_in(&mut self, cx: &mut Cx) -> () {
+        self.zoom_at(cx, self.zoom * (1.0 + self.zoom_step), None);
+    }
+    pub fn zoom_out(&mut self, cx: &mut Cx) -> () {
+        self.zoom_at(cx, self.zoom / (1.0 + self.zoom_step), None);
+    }
+    /// ## zoom and keep the point under `abs` (or the viewer center) still
+    pub fn zoom_at(&mut self, cx: &mut Cx, zoom: f64, abs: Option<DVec2>) -> () {
+        let zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+        let rect = self.area().rect(cx);
+        let center = rect.pos + rect.size * 0.5;
+        let point = abs.map_or(DVec2::default(), |abs| abs - center);
+        self.pan = point - (point - self.pan) * (zoom / self.zoom);
+        self.zoom = zoom;
+        self.redraw(cx);
+    }
+    /// rotate 90°, `clockwise: false` for anticlockwise
+    pub fn rotate(&mut self, cx: &mut Cx, clockwise: bool) -> () {
+        self.rotation = if clockwise {
+            (self.rotation + 1) % 4
+        } else {
+            (self.rotation + 3) % 4
+        };
+        self.redraw(cx);
+    }
+    /// reset zoom, pan and rotation
+    pub fn reset(&mut self, cx: &mut Cx) -> () {
+        self.zoom = 1.0;
+        self.pan = DVec2::default();
+        self.rotation = 0;
+        self.redraw(cx);
+    }
+    /// image is contained in the viewer (never scale up) at zoom 1.0
+    fn image_walk(&mut self, cx: &mut Cx2d, rect: Rect) -> Walk {
+        let dpi = cx.current_dpi_factor();
+        let (width, height) = self
+            .image
+            .size_in_pixels(cx)
+            .map_or((rect.size.x * 0.5, rect.size.y * 0.5), |(w, h)| {
+                (w as f64 / dpi, h as f64 / dpi)
+            });
+        let size = if self.rotation % 2 == 1 {
+            dvec2(height, width)
+        } else {
+            dvec2(width, height)
+        };
+        let fit = (rect.size.x / size.x.max(1.0))
+            .min(rect.size.y / size.y.max(1.0))
+            .min(1.0);
+        let size = size * fit * self.zoom;
+        Walk {
+            abs_pos: Some(rect.pos + (rect.size - size) * 0.5 + self.pan),
+            width: Size::Fixed(size.x),
+            height: Size::Fixed(size.y),
+            ..Default::default()
+        }
+    }
+    fn touches_distance(&self) -> f64 {
+        match self.touches.as_slice() {
+            [(_, a), (_, b), ..] => (*a - *b).length(),
+            _ => 0.0,
+        }
+    }
+    fn active_changed(&mut self, cx: &mut Cx) -> () {
+        if self.event_open {
+            self.scope_path.as_ref().map(|path| {
+                cx.widget_action(
+                    self.widget_uid(),
+                    path,
+                    ImageViewerEvent::Changed(ImageViewerChanged {
+                        index: self.current,
+                        src: self.image.src.to_string(),
+                    }),
+                );
+            });
+        }
+    }
+    fn active_closed(&mut self, cx: &mut Cx) -> () {
+        if self.event_open {
+            self.scope_path.as_ref().map(|path| {
+                cx.widget_action(
+                    self.widget_uid(),
+                    path,
+                    ImageViewerEvent::Closed(ImageViewerClosed {
+                        index: self.current,
+                    }),
+                );
+            });
+        }
+    }
+    event_option! {
+        changed: ImageViewerEvent::Changed => ImageViewerChanged,
+        closed: ImageViewerEvent::Closed => ImageViewerClosed
+    }
+}
+
+impl GImageViewerRef {
+    pub fn open(&self, cx: &mut Cx, gallery: Vec<Src>, index: usize) -> () {
+        if let Some(mut c_ref) = self.borrow_mut() {
+            c_ref.open(cx, gallery, index);
+        }
+    }
+    pub fn close(&self, cx: &mut Cx) -> () {
+        if let Some(mut c_ref) = self.borrow_mut() {
+            c_ref.close(cx);
+        }
+    }
+    pub fn is_opened(&self) -> bool {
+        self.borrow().map_or(false, |c_ref| c_ref.opened)
+    }
+    event_option_ref! {
+        changed => ImageViewerChanged,
+        closed => ImageViewerClosed
+    }
+}
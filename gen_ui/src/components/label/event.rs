@@ -0,0 +1,16 @@
+use makepad_widgets::*;
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum LabelEvent {
+    SpanClicked(LabelSpanClicked),
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabelSpanClicked {
+    pub meta: FingerUpEvent,
+    /// index of the span in the spans of the label
+    pub index: usize,
+    pub text: String,
+    pub href: Option<String>,
+}
@@ -1,25 +1,33 @@
 use makepad_widgets::{shader::draw_text::TextStyle, *};
 
 use crate::{
+    active_event,
     components::{
         lifecycle::LifeCycle,
         traits::{BasicStyle, Style},
     },
     error::Error,
-    getter, getter_setter_ref, lifecycle,
+    event_option, event_option_ref, getter, getter_setter_ref, lifecycle,
     prop::{
         ApplyStateMap,
-        manuel::{BASIC, DISABLED},
+        manuel::{BASIC, DISABLED, PRIMARY},
         traits::ToColor,
     },
-    pure_after_apply, set_index, set_scope_path, setter, sync,
-    themes::{Theme, conf::Conf},
+    pure_after_apply, set_index, set_scope_path, setter,
+    shader::draw_view::DrawView,
+    sync,
+    themes::{ColorFontConf, Theme, conf::Conf},
+    utils::find_ignore_case,
     visible,
 };
 
+mod event;
 mod prop;
+mod rich;
 
+pub use event::*;
 pub use prop::*;
+pub use rich::*;
 
 use super::traits::Component;
 
@@ -31,9 +39,19 @@ live_design! {
         font_bold: <THEME_FONT_BOLD>{}
         font_italic: <THEME_FONT_ITALIC>{}
         font_bold_italic: <THEME_FONT_BOLD_ITALIC>{}
+        draw_span: {
+            border_radius: vec4(2.0, 2.0, 2.0, 2.0),
+        }
     }
 }
 
+/// appended to the last line when the text is truncated by `max_lines`
+const ELLIPSIS: &str = "...";
+/// palette level of the theme colors of the highlight, links and code
+const ACCENT_LEVEL: u32 = 400;
+/// palette level and alpha of the theme color of the code background
+const CODE_BACKGROUND: (u32, f32) = (500, 0.2);
+
 #[derive(Live, LiveRegisterWidget, WidgetRef, WidgetSet)]
 pub struct GLabel {
     #[live]
@@ -49,6 +67,36 @@ pub struct GLabel {
     pub area: Area,
     #[live]
     pub text: ArcStringMut,
+    /// highlight the first match (ignore case) of the keyword in text, such as search results
+    #[live]
+    pub highlight: String,
+    /// theme of the highlighted keyword
+    #[live(Theme::Warning)]
+    pub highlight_theme: Theme,
+    /// parse `text` as markup, see `parse_rich`
+    #[live]
+    pub rich: bool,
+    /// max lines of the text, the overflow is truncated with an ellipsis (0: no limit)
+    #[live]
+    pub max_lines: usize,
+    /// theme of the link spans
+    #[live(Theme::Info)]
+    pub link_theme: Theme,
+    /// theme of the code spans
+    #[live(Theme::Error)]
+    pub code_theme: Theme,
+    /// background of the code spans, transparent (default) uses the color of the label theme
+    #[live]
+    pub code_background_color: Vec4,
+    /// spans of the rich text, parsed from `text` when `rich` or set by `set_spans`
+    #[rust]
+    pub spans: Vec<RichSpan>,
+    /// the drawn pieces of the spans, used to find the clicked span
+    #[rust]
+    span_rects: Vec<(usize, Rect)>,
+    /// layout of the last drawn spans, measured again only when the spans, the width or the font change
+    #[rust]
+    rich_layout: Option<RichLayout>,
     #[rust]
     index: usize,
     #[rust]
@@ -65,6 +113,9 @@ pub struct GLabel {
     // --- draw ------------------
     #[live]
     pub draw_text: DrawText,
+    /// code background, link underline and strikethrough of the spans
+    #[live]
+    pub draw_span: DrawView,
     #[rust]
     pub scope_path: Option<HeapLiveIdPath>,
     // --- init -----------------
@@ -76,6 +127,9 @@ pub struct GLabel {
     pub state: LabelState,
     #[rust]
     is_set_disabled_color: bool,
+    /// emit `LabelEvent::SpanClicked` when a span is clicked, the label ignores the finger if not set
+    #[live(true)]
+    pub event_open: bool,
 }
 
 impl WidgetNode for GLabel {
@@ -133,13 +187,28 @@ impl Widget for GLabel {
         // let _ = self.text.as_ref().is_empty().then(|| {
         //     let _ = self.set_text(cx, " ");
         // });
-        self.draw_text
-            .draw_walk(cx, walk, Align::default(), self.text.as_ref());
+        match self.draw_spans() {
+            Some(spans) => self.draw_rich(cx, spans),
+            None => {
+                self.draw_text
+                    .draw_walk(cx, walk, Align::default(), self.text.as_ref());
+            }
+        }
         cx.end_turtle_with_area(&mut self.area);
         self.set_scope_path(&scope.path);
         DrawStep::done()
     }
 
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, _scope: &mut Scope) {
+        // only rich labels handle events, plain labels leave them to the parent (such as `GButton`)
+        if !self.visible || self.disabled || !self.event_open || self.spans.is_empty() {
+            return;
+        }
+        let area = self.area;
+        let hit = event.hits(cx, area);
+        self.handle_widget_event(cx, event, hit, area);
+    }
+
     fn set_disabled(&mut self, cx: &mut Cx, disabled: bool) {
         let _ = self.set_disabled(cx, disabled);
     }
@@ -194,6 +263,9 @@ impl Component for GLabel {
         if self.disabled {
             self.switch_state(LabelState::Disabled);
         }
+        if self.rich {
+            self.spans = parse_rich(self.text.as_ref());
+        }
         // dbg!(self.style.basic.color);
         // [sync to draw_text] -------------------------------------------------------
         let is_set_disabled_color = self.is_set_disabled_color();
@@ -216,8 +288,31 @@ impl Component for GLabel {
         Ok(())
     }
 
-    fn handle_widget_event(&mut self, _cx: &mut Cx, _event: &Event, _hit: Hit, _area: Area) {
-        ()
+    fn handle_widget_event(&mut self, cx: &mut Cx, _event: &Event, hit: Hit, _area: Area) {
+        match hit {
+            Hit::FingerHoverIn(e) | Hit::FingerHoverOver(e) => {
+                let is_link = self
+                    .span_at(e.abs)
+                    .and_then(|index| self.spans.get(index))
+                    .map_or(false, |span| span.is_link());
+                cx.set_cursor(if is_link {
+                    MouseCursor::Hand
+                } else {
+                    MouseCursor::Default
+                });
+            }
+            Hit::FingerUp(e) => {
+                if e.is_over {
+                    let clicked = self
+                        .span_at(e.abs)
+                        .and_then(|index| self.spans.get(index).map(|span| (index, span.clone())));
+                    if let Some((index, RichSpan { text, href, .. })) = clicked {
+                        self.active_span_clicked(cx, e, index, text, href);
+                    }
+                }
+            }
+            _ => {}
+        }
     }
 
     fn play_animation(&mut self, _cx: &mut Cx, _state: &[LiveId; 2]) -> () {
@@ -257,9 +352,273 @@ impl GLabel {
         self.visible = other.visible;
         self.disabled = other.disabled;
         self.mode = other.mode;
+        self.rich = other.rich;
+        self.max_lines = other.max_lines;
         let _ = self.set_text(cx, other.get_text());
+        self.spans = other.spans.clone();
        
     }
+    /// spans to draw, `None` draws the text as it is
+    fn draw_spans(&self) -> Option<Vec<RichSpan>> {
+        if !self.spans.is_empty() {
+            return Some(self.spans.clone());
+        }
+        let text = self.text.as_ref();
+        match find_ignore_case(text, &self.highlight) {
+            // the keyword is a colored span, so it wraps and truncates with the text
+            Some(range) => Some(
+                [
+                    RichSpan::new(&text[..range.start]),
+                    RichSpan::new(&text[range.clone()])
+                        .color(self.highlight_theme.color(ACCENT_LEVEL).into()),
+                    RichSpan::new(&text[range.end..]),
+                ]
+                .into_iter()
+                .filter(|span| !span.text.is_empty())
+                .collect(),
+            ),
+            None => (self.max_lines > 0).then(|| vec![RichSpan::new(text)]),
+        }
+    }
+    /// draw the spans word by word, wrap them by the width of the label and truncate them by `max_lines`
+    fn draw_rich(&mut self, cx: &mut Cx2d, spans: Vec<RichSpan>) {
+        let color = self.draw_text.color;
+        let font_family = self.draw_text.text_style.font_family.clone();
+        let turtle_rect = cx.turtle().padded_rect();
+        let max_width = (self.style.get(self.state).flow == Flow::RightWrap
+            && !turtle_rect.size.x.is_nan())
+        .then_some(turtle_rect.size.x);
+
+        let layout = match self.rich_layout.take() {
+            Some(layout)
+                if layout.spans == spans
+                    && layout.max_width == max_width
+                    && layout.max_lines == self.max_lines
+                    && layout.font == self.font() =>
+            {
+                layout
+            }
+            _ => self.layout_rich(cx, spans, max_width),
+        };
+        let RichLayout {
+            spans,
+            atoms,
+            lines,
+            ..
+        } = &layout;
+
+        let mut tops = Vec::with_capacity(lines.len());
+        let mut height = 0.0;
+        for line in lines.iter() {
+            tops.push(height);
+            height += line;
+        }
+        let width = atoms
+            .iter()
+            .fold(0.0, |width, atom| f64::max(width, atom.x + atom.size.x));
+        let origin = turtle_rect.pos;
+        self.span_rects.clear();
+        for atom in atoms.iter() {
+            let span = &spans[atom.span];
+            let rect = Rect {
+                pos: origin + dvec2(atom.x, tops[atom.line] + lines[atom.line] - atom.size.y),
+                size: atom.size,
+            };
+            self.draw_atom(cx, span, &atom.text, rect, color);
+            self.span_rects.push((atom.span, rect));
+        }
+        self.rich_layout.replace(layout);
+        cx.walk_turtle(Walk::fixed(width, height));
+        self.draw_text.color = color;
+        self.draw_text.text_style.font_family = font_family;
+    }
+    fn font(&self) -> (f32, FontMode) {
+        (self.draw_text.text_style.font_size, self.mode)
+    }
+    /// measure the words of the spans and break them into lines
+    fn layout_rich(
+        &mut self,
+        cx: &mut Cx2d,
+        spans: Vec<RichSpan>,
+        max_width: Option<f64>,
+    ) -> RichLayout {
+        let mut atoms: Vec<RichAtom> = vec![];
+        // height of each line
+        let mut lines = vec![0.0];
+        let mut x = 0.0;
+        for (index, span) in spans.iter().enumerate() {
+            self.set_span_font(span);
+            for word in split_words(&span.text) {
+                let size = self.measure(cx, word);
+                if word == "\n" {
+                    let line = lines.len() - 1;
+                    lines[line] = f64::max(lines[line], size.y);
+                    lines.push(0.0);
+                    x = 0.0;
+                    continue;
+                }
+                if max_width.map_or(false, |max| x > 0.0 && x + size.x > max) {
+                    lines.push(0.0);
+                    x = 0.0;
+                }
+                let line = lines.len() - 1;
+                atoms.push(RichAtom {
+                    span: index,
+                    text: word.to_string(),
+                    x,
+                    size,
+                    line,
+                });
+                x += size.x;
+                lines[line] = f64::max(lines[line], size.y);
+            }
+        }
+        if self.max_lines > 0 && lines.len() > self.max_lines {
+            self.truncate(cx, &spans, &mut atoms, &mut lines, max_width);
+        }
+        RichLayout {
+            spans,
+            max_width,
+            max_lines: self.max_lines,
+            font: self.font(),
+            atoms,
+            lines,
+        }
+    }
+    /// keep `max_lines` lines and end the last one with the ellipsis
+    fn truncate(
+        &mut self,
+        cx: &mut Cx2d,
+        spans: &[RichSpan],
+        atoms: &mut Vec<RichAtom>,
+        lines: &mut Vec<f64>,
+        max_width: Option<f64>,
+    ) -> () {
+        let last = self.max_lines - 1;
+        atoms.retain(|atom| atom.line <= last);
+        lines.truncate(self.max_lines);
+        // the ellipsis uses the style of the last kept word
+        let span = atoms.last().map_or(0, |atom| atom.span);
+        self.set_span_font(&spans[span]);
+        let ellipsis = self.measure(cx, ELLIPSIS);
+        while let Some(atom) = atoms.last_mut().filter(|atom| atom.line == last) {
+            let trimmed = atom.text.trim_end().to_string();
+            if trimmed.is_empty() {
+                atoms.pop();
+                continue;
+            }
+            if trimmed.len() != atom.text.len() {
+                self.set_span_font(&spans[atom.span]);
+                atom.size.x = self.measure(cx, &trimmed).x;
+                atom.text = trimmed;
+            }
+            if max_width.map_or(true, |max| atom.x + atom.size.x + ellipsis.x <= max) {
+                break;
+            }
+            atoms.pop();
+        }
+        let x = atoms
+            .last()
+            .filter(|atom| atom.line == last)
+            .map_or(0.0, |atom| atom.x + atom.size.x);
+        atoms.push(RichAtom {
+            span,
+            text: ELLIPSIS.to_string(),
+            x,
+            size: ellipsis,
+            line: last,
+        });
+        lines[last] = f64::max(lines[last], ellipsis.y);
+    }
+    fn code_background(&self) -> Vec4 {
+        if self.code_background_color.w > 0.0 {
+            return self.code_background_color;
+        }
+        let (level, alpha) = CODE_BACKGROUND;
+        let mut color: Vec4 = self.style.basic.theme.color(level).into();
+        color.w = alpha;
+        color
+    }
+    fn draw_atom(&mut self, cx: &mut Cx2d, span: &RichSpan, text: &str, rect: Rect, color: Vec4) {
+        self.set_span_font(span);
+        if span.code {
+            self.draw_span.background_color = self.code_background();
+            self.draw_span.draw_abs(cx, rect);
+        }
+        let color = span.color.unwrap_or(if span.is_link() {
+            self.link_theme.color(ACCENT_LEVEL).into()
+        } else if span.code {
+            self.code_theme.color(ACCENT_LEVEL).into()
+        } else {
+            color
+        });
+        self.draw_text.color = color;
+        self.draw_text.draw_abs(cx, rect.pos, text);
+        let mut line = |y: f64| {
+            self.draw_span.background_color = color;
+            self.draw_span.draw_abs(
+                cx,
+                Rect {
+                    pos: dvec2(rect.pos.x, y),
+                    size: dvec2(rect.size.x, 1.0),
+                },
+            );
+        };
+        if span.is_link() {
+            line(rect.pos.y + rect.size.y - 1.0);
+        }
+        if span.strike {
+            line(rect.pos.y + rect.size.y * 0.5);
+        }
+    }
+    fn set_span_font(&mut self, span: &RichSpan) -> () {
+        let bold = span.bold || matches!(self.mode, FontMode::Bold | FontMode::BoldItalic);
+        let italic = span.italic || matches!(self.mode, FontMode::Italic | FontMode::BoldItalic);
+        self.draw_text.text_style.font_family = match (bold, italic) {
+            (false, false) => self.font_regular.font_family.clone(),
+            (true, false) => self.font_bold.font_family.clone(),
+            (false, true) => self.font_italic.font_family.clone(),
+            (true, true) => self.font_bold_italic.font_family.clone(),
+        };
+    }
+    fn measure(&mut self, cx: &mut Cx2d, text: &str) -> DVec2 {
+        let laidout = self
+            .draw_text
+            .layout(cx, 0.0, 0.0, None, Align::default(), text);
+        let scale = self.draw_text.font_scale as f64;
+        dvec2(
+            laidout.size_in_lpxs.width as f64 * scale,
+            laidout.size_in_lpxs.height as f64 * scale,
+        )
+    }
+    fn span_at(&self, abs: DVec2) -> Option<usize> {
+        self.span_rects
+            .iter()
+            .find(|(_, rect)| rect.contains(abs))
+            .map(|(index, _)| *index)
+    }
+    /// set the spans by the builder of `RichSpan`, it replaces the markup of `rich`
+    pub fn set_spans(&mut self, cx: &mut Cx, spans: Vec<RichSpan>) -> () {
+        self.rich = false;
+        self.spans = spans;
+        self.redraw(cx);
+    }
+    /// use the font color `key` of the theme (such as `SECONDARY`) as the basic color,
+    /// a color set by the user is kept
+    pub fn set_default_font_color(&mut self, cx: &mut Cx, key: &str) -> () {
+        let primary: Vec4 = ColorFontConf::from_key(PRIMARY).into();
+        if self.style.basic.color == primary {
+            self.style.basic.set_color(ColorFontConf::from_key(key).into());
+            let _ = self.render(cx);
+            self.redraw(cx);
+        }
+    }
+    active_event! {
+        active_span_clicked: LabelEvent::SpanClicked |meta: FingerUpEvent, index: usize, text: String, href: Option<String>| => LabelSpanClicked { meta, index, text, href }
+    }
+    event_option! {
+        span_clicked: LabelEvent::SpanClicked => LabelSpanClicked
+    }
     pub fn is_set_disabled_color(&mut self) -> bool {
         self.apply_state_map
             .get(&LabelState::Disabled)
@@ -277,6 +636,9 @@ impl GLabel {
             get_flow(Flow) {|c| {c.style.basic.get_flow()}},
             get_mode(FontMode) {|c| {c.mode}},
             get_text(String) {|c| {c.text.as_ref().to_string()}},
+            get_highlight(String) {|c| {c.highlight.to_string()}},
+            get_rich(bool) {|c| {c.rich}},
+            get_max_lines(usize) {|c| {c.max_lines}},
             get_visible(bool) {|c| {c.visible}},
             get_disabled(bool) {|c| {c.disabled}}
         }
@@ -291,7 +653,10 @@ impl GLabel {
             set_padding(padding: Padding) {|c, _cx| {c.style.basic.set_padding(padding); Ok(())}},
             set_flow(flow: Flow) {|c, _cx| {c.style.basic.set_flow(flow); Ok(())}},
             set_mode(mode: FontMode) {|c, _cx| {c.mode = mode; Ok(())}},
-            set_text(text: String) {|c, _cx| {c.text.as_mut_empty().push_str(&text); Ok(())}},
+            set_text(text: String) {|c, _cx| {c.text.as_mut_empty().push_str(&text); c.spans.clear(); Ok(())}},
+            set_highlight(highlight: String) {|c, _cx| {c.highlight = highlight; Ok(())}},
+            set_rich(rich: bool) {|c, _cx| {c.rich = rich; c.spans.clear(); Ok(())}},
+            set_max_lines(max_lines: usize) {|c, _cx| {c.max_lines = max_lines; Ok(())}},
             set_visible(visible: bool) {|c, _cx| {c.visible = visible; Ok(())}},
             set_disabled(disabled: bool) {|c, cx| {c.disabled = disabled; c.redraw(cx); Ok(())}}
         }
@@ -309,7 +674,43 @@ impl GLabelRef {
         get_flow, set_flow -> Flow,
         get_mode, set_mode -> FontMode,
         get_text, set_text -> String,
+        get_highlight, set_highlight -> String,
+        get_rich, set_rich -> bool,
+        get_max_lines, set_max_lines -> usize,
         get_visible, set_visible -> bool,
         get_disabled, set_disabled -> bool
     }
+    pub fn set_spans(&self, cx: &mut Cx, spans: Vec<RichSpan>) -> () {
+        self.borrow_mut().map(|mut c| c.set_spans(cx, spans));
+    }
+    pub fn spans(&self) -> Vec<RichSpan> {
+        self.borrow().map_or(vec![], |c| c.spans.clone())
+    }
+    pub fn set_default_font_color(&self, cx: &mut Cx, key: &str) -> () {
+        self.borrow_mut().map(|mut c| c.set_default_font_color(cx, key));
+    }
+    event_option_ref! {
+        span_clicked => LabelSpanClicked
+    }
+}
+
+/// the measured spans of a rich label
+struct RichLayout {
+    spans: Vec<RichSpan>,
+    max_width: Option<f64>,
+    max_lines: usize,
+    /// font size and mode
+    font: (f32, FontMode),
+    atoms: Vec<RichAtom>,
+    /// height of each line
+    lines: Vec<f64>,
+}
+
+/// a drawn piece of a span, position is relative to the start of the label
+struct RichAtom {
+    span: usize,
+    text: String,
+    x: f64,
+    size: DVec2,
+    line: usize,
 }
@@ -0,0 +1,343 @@
+use makepad_widgets::Vec4;
+
+use crate::prop::traits::ToColor;
+
+/// a piece of text with its own style in a rich `GLabel`
+///
+/// build spans by the builder:
+/// ```rust,ignore
+/// vec![
+///     RichSpan::new("Read the "),
+///     RichSpan::new("docs").link("https://github.com/genrs/GenM3"),
+///     RichSpan::new(" before ").bold(),
+///     RichSpan::new("cargo run").code(),
+/// ]
+/// ```
+/// or parse them from the markup by `parse_rich`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RichSpan {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+    pub strike: bool,
+    /// color of the span, `None` uses the color of the label
+    pub color: Option<Vec4>,
+    /// the span is clickable, the label emits `LabelEvent::SpanClicked` with the href
+    pub href: Option<String>,
+}
+
+impl RichSpan {
+    pub fn new<T>(text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+    pub fn code(mut self) -> Self {
+        self.code = true;
+        self
+    }
+    pub fn strike(mut self) -> Self {
+        self.strike = true;
+        self
+    }
+    pub fn color(mut self, color: Vec4) -> Self {
+        self.color.replace(color);
+        self
+    }
+    pub fn link<T>(mut self, href: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.href.replace(href.into());
+        self
+    }
+    pub fn is_link(&self) -> bool {
+        self.href.is_some()
+    }
+    pub(crate) fn same_style(&self, other: &RichSpan) -> bool {
+        self.bold == other.bold
+            && self.italic == other.italic
+            && self.code == other.code
+            && self.strike == other.strike
+            && self.color == other.color
+            && self.href == other.href
+    }
+}
+
+/// parse the markup of a rich `GLabel` into spans
+/// - `**bold**`, `*italic*`, `~~strike~~`
+/// - `` `code` ``
+/// - `[text](href)`: clickable span
+/// - `{#FF5500|text}`: colored span
+/// - `\` escapes the next char
+///
+/// unclosed marks are kept as plain text
+pub fn parse_rich(markup: &str) -> Vec<RichSpan> {
+    let mut parser = RichParser {
+        chars: markup.chars().collect(),
+        spans: vec![],
+        current: RichSpan::default(),
+        colors: vec![],
+    };
+    parser.parse(0, parser.chars.len());
+    parser.flush();
+    parser.spans
+}
+
+struct RichParser {
+    chars: Vec<char>,
+    spans: Vec<RichSpan>,
+    current: RichSpan,
+    colors: Vec<Option<Vec4>>,
+}
+
+impl RichParser {
+    fn parse(&mut self, start: usize, end: usize) -> () {
+        let mut i = start;
+        while i < end {
+            let c = self.chars[i];
+            match c {
+                '\\' if i + 1 < end => {
+                    self.push(self.chars[i + 1]);
+                    i += 2;
+                }
+                '*' if self.starts_with(i, end, "**") => {
+                    i += self.mark(i, end, "**", |span| &mut span.bold);
+                }
+                '*' => {
+                    i += self.mark(i, end, "*", |span| &mut span.italic);
+                }
+                '~' if self.starts_with(i, end, "~~") => {
+                    i += self.mark(i, end, "~~", |span| &mut span.strike);
+                }
+                '`' => match self.find(i + 1, end, '`') {
+                    Some(close) => {
+                        self.flush();
+                        let code: String = self.chars[i + 1..close].iter().collect();
+                        let mut span = self.current.clone();
+                        span.text = code;
+                        span.code = true;
+                        self.spans.push(span);
+                        i = close + 1;
+                    }
+                    None => {
+                        self.push(c);
+                        i += 1;
+                    }
+                },
+                '[' => match self.link_end(i, end) {
+                    Some((text_end, href_end)) => {
+                        let href: String = self.chars[text_end + 2..href_end].iter().collect();
+                        self.flush();
+                        let outer = self.current.href.replace(href.trim().to_string());
+                        self.parse(i + 1, text_end);
+                        self.flush();
+                        self.current.href = outer;
+                        i = href_end + 1;
+                    }
+                    None => {
+                        self.push(c);
+                        i += 1;
+                    }
+                },
+                '{' => match self.color_start(i, end) {
+                    Some((color, bar)) => {
+                        self.flush();
+                        self.colors.push(self.current.color);
+                        self.current.color.replace(color);
+                        i = bar + 1;
+                    }
+                    None => {
+                        self.push(c);
+                        i += 1;
+                    }
+                },
+                '}' if !self.colors.is_empty() => {
+                    self.flush();
+                    self.current.color = self.colors.pop().flatten();
+                    i += 1;
+                }
+                _ => {
+                    self.push(c);
+                    i += 1;
+                }
+            }
+        }
+    }
+    fn push(&mut self, c: char) -> () {
+        self.current.text.push(c);
+    }
+    /// move the text of the current span into the spans, keeps the style
+    fn flush(&mut self) -> () {
+        if self.current.text.is_empty() {
+            return;
+        }
+        let text = std::mem::take(&mut self.current.text);
+        match self.spans.last_mut() {
+            Some(last) if last.same_style(&self.current) && !last.code => {
+                last.text.push_str(&text);
+            }
+            _ => {
+                let mut span = self.current.clone();
+                span.text = text;
+                self.spans.push(span);
+            }
+        }
+    }
+    /// toggle the style of the mark `pat` at `i`, an opening mark without a closing one is
+    /// kept as text, returns the length of the mark
+    fn mark<F>(&mut self, i: usize, end: usize, pat: &str, f: F) -> usize
+    where
+        F: Fn(&mut RichSpan) -> &mut bool,
+    {
+        let len = pat.chars().count();
+        let opened = *f(&mut self.current);
+        if opened || (i + len..end).any(|j| self.starts_with(j, end, pat)) {
+            self.flush();
+            let flag = f(&mut self.current);
+            *flag = !*flag;
+        } else {
+            self.current.text.push_str(pat);
+        }
+        len
+    }
+    fn starts_with(&self, i: usize, end: usize, pat: &str) -> bool {
+        let pat: Vec<char> = pat.chars().collect();
+        i + pat.len() <= end && self.chars[i..i + pat.len()] == pat[..]
+    }
+    fn find(&self, start: usize, end: usize, target: char) -> Option<usize> {
+        (start..end).find(|&i| self.chars[i] == target)
+    }
+    /// `[text](href)` -> (index of `]`, index of `)`)
+    fn link_end(&self, i: usize, end: usize) -> Option<(usize, usize)> {
+        let text_end = self.find(i + 1, end, ']')?;
+        if !self.starts_with(text_end, end, "](") {
+            return None;
+        }
+        let href_end = self.find(text_end + 2, end, ')')?;
+        Some((text_end, href_end))
+    }
+    /// `{#FF5500|` -> (color, index of `|`)
+    fn color_start(&self, i: usize, end: usize) -> Option<(Vec4, usize)> {
+        let bar = self.find(i + 1, end, '|')?;
+        let hex: String = self.chars[i + 1..bar].iter().collect();
+        let digits = hex.trim().strip_prefix('#')?;
+        // `Hex` only accepts 3, 6 or 8 hex digits
+        if !matches!(digits.len(), 3 | 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.find(bar + 1, end, '}')?;
+        Vec4::from_hex(digits).ok().map(|color| (color, bar))
+    }
+}
+
+/// split the text into the pieces which can be wrapped: words with their trailing whitespace,
+/// single CJK chars and `\n`
+pub(crate) fn split_words(text: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut start = 0;
+    let mut in_space = false;
+    for (i, c) in text.char_indices() {
+        let end = i + c.len_utf8();
+        if c == '\n' || is_cjk(c) {
+            if start < i {
+                words.push(&text[start..i]);
+            }
+            words.push(&text[i..end]);
+            start = end;
+            in_space = false;
+        } else if c.is_whitespace() {
+            in_space = true;
+        } else if in_space {
+            words.push(&text[start..i]);
+            start = i;
+            in_space = false;
+        }
+    }
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+    words
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c, '\u{2E80}'..='\u{9FFF}' | '\u{AC00}'..='\u{D7AF}' | '\u{F900}'..='\u{FAFF}' | '\u{FF00}'..='\u{FFEF}')
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RichSpan, parse_rich, split_words};
+
+    #[test]
+    fn split_words_test() {
+        assert_eq!(split_words("a bc  d"), vec!["a ", "bc  ", "d"]);
+        assert_eq!(split_words("a\nb "), vec!["a", "\n", "b "]);
+        assert_eq!(split_words("树节点 ok"), vec!["树", "节", "点", " ", "ok"]);
+        assert_eq!(split_words(" a"), vec![" ", "a"]);
+        assert!(split_words("").is_empty());
+    }
+
+    #[test]
+    fn parse_rich_test() {
+        assert_eq!(
+            parse_rich("a **b** *c* ~~d~~ `e*`"),
+            vec![
+                RichSpan::new("a "),
+                RichSpan::new("b").bold(),
+                RichSpan::new(" "),
+                RichSpan::new("c").italic(),
+                RichSpan::new(" "),
+                RichSpan::new("d").strike(),
+                RichSpan::new(" "),
+                RichSpan::new("e*").code(),
+            ]
+        );
+        assert_eq!(
+            parse_rich("see [**docs**](https://github.com) \\*"),
+            vec![
+                RichSpan::new("see "),
+                RichSpan::new("docs").bold().link("https://github.com"),
+                RichSpan::new(" *"),
+            ]
+        );
+        assert_eq!(parse_rich("[a](b"), vec![RichSpan::new("[a](b")]);
+        assert_eq!(parse_rich("`a"), vec![RichSpan::new("`a")]);
+    }
+
+    #[test]
+    fn parse_rich_unclosed_test() {
+        assert_eq!(parse_rich("**a"), vec![RichSpan::new("**a")]);
+        assert_eq!(parse_rich("a *b ~~c"), vec![RichSpan::new("a *b ~~c")]);
+        assert_eq!(
+            parse_rich("**a** **b"),
+            vec![RichSpan::new("a").bold(), RichSpan::new(" **b")]
+        );
+        assert_eq!(
+            parse_rich("[**a](b) c**"),
+            vec![RichSpan::new("**a").link("b"), RichSpan::new(" c**")]
+        );
+    }
+
+    #[test]
+    fn parse_rich_color_test() {
+        let spans = parse_rich("{#FF0000|red} {plain}");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].text, "red");
+        assert!(spans[0].color.is_some());
+        assert_eq!(spans[1], RichSpan::new(" {plain}"));
+        assert_eq!(parse_rich("{#zz|x}"), vec![RichSpan::new("{#zz|x}")]);
+    }
+}
@@ -0,0 +1,167 @@
+use makepad_widgets::{Cx, HeapLiveIdPath, LiveId, WidgetUid, error};
+
+use crate::{components::router::GRouter, prop::LinkTarget};
+
+use super::LinkOpenerGlobal;
+
+/// prefix of the `href` which navigates the router
+pub const ROUTE_PREFIX: &str = "route:";
+
+/// schemes of the urls which are opened by the `LinkOpener`, others (such as `file:`,
+/// `javascript:` or custom protocols) are refused
+pub const ALLOWED_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+/// the url has a scheme in `ALLOWED_SCHEMES`
+pub fn is_allowed_url(url: &str) -> bool {
+    url.split_once(':').map_or(false, |(scheme, rest)| {
+        !rest.is_empty()
+            && ALLOWED_SCHEMES
+                .iter()
+                .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+    })
+}
+
+/// parsed `href` of `GLink`
+/// - `route:/settings/profile`: navigate the router to the page `settings/profile`
+/// - `https://...`, `http://...` or `mailto:...`: open by the `LinkOpener`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Href {
+    /// the segments of the route path
+    Route(Vec<LiveId>),
+    External(String),
+}
+
+impl Href {
+    pub fn parse(href: &str) -> Option<Self> {
+        let href = href.trim();
+        if let Some(route) = href.strip_prefix(ROUTE_PREFIX) {
+            let path: Vec<LiveId> = route
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(LiveId::from_str)
+                .collect();
+            return (!path.is_empty()).then_some(Href::Route(path));
+        }
+        is_allowed_url(href).then(|| Href::External(href.to_string()))
+    }
+}
+
+/// follow the href as `GLink` does, returns `true` if the href is followed
+/// - routes navigate the nearest `GRouter` of the scope path
+/// - `http`, `https` and `mailto` urls are opened by the `LinkOpener`
+pub fn follow_href(
+    cx: &mut Cx,
+    uid: WidgetUid,
+    scope_path: Option<&HeapLiveIdPath>,
+    href: &str,
+    target: LinkTarget,
+) -> bool {
+    match Href::parse(href) {
+        Some(Href::Route(path)) => {
+            let Some(scope_path) = scope_path else {
+                return false;
+            };
+            GRouter::nav_to_scope_path(cx, uid, scope_path, &path);
+            true
+        }
+        Some(Href::External(url)) => {
+            let opener = cx.global::<LinkOpenerGlobal>().opener.clone();
+            if let Err(e) = opener.open(&url, target) {
+                error!("can not open {}: {}", url, e);
+                return false;
+            }
+            true
+        }
+        None => {
+            error!("invalid or not allowed href: {}", href);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Href, follow_href};
+    use crate::{
+        components::link::{LinkOpener, RecordingOpener, set_link_opener},
+        prop::LinkTarget,
+    };
+    use makepad_widgets::{Cx, LiveId, WidgetUid};
+
+    #[test]
+    fn parse_href_test() {
+        assert_eq!(
+            Href::parse("route:/settings/profile"),
+            Some(Href::Route(vec![
+                LiveId::from_str("settings"),
+                LiveId::from_str("profile")
+            ]))
+        );
+        assert_eq!(
+            Href::parse("https://github.com"),
+            Some(Href::External("https://github.com".to_string()))
+        );
+        assert_eq!(
+            Href::parse("mailto:someone@example.com"),
+            Some(Href::External("mailto:someone@example.com".to_string()))
+        );
+        assert_eq!(
+            Href::parse("HTTP://github.com"),
+            Some(Href::External("HTTP://github.com".to_string()))
+        );
+        assert_eq!(Href::parse("route:/"), None);
+        assert_eq!(Href::parse("settings/profile"), None);
+        assert_eq!(Href::parse(""), None);
+    }
+
+    #[test]
+    fn recording_opener_test() {
+        let opener = RecordingOpener::default();
+        opener
+            .open("https://github.com", LinkTarget::Blank)
+            .unwrap();
+        assert_eq!(
+            opener.opened(),
+            vec![("https://github.com".to_string(), LinkTarget::Blank)]
+        );
+    }
+
+    #[test]
+    fn refuse_scheme_test() {
+        for href in [
+            "file:///etc/passwd",
+            "foo:bar",
+            "javascript:alert(1)",
+            "https:",
+        ] {
+            assert_eq!(Href::parse(href), None, "{}", href);
+        }
+    }
+
+    /// the FingerUp of `GLink` follows its href by `follow_href`, which opens it by the global opener
+    #[test]
+    fn follow_href_opener_test() {
+        let mut cx = Cx::new(Box::new(|_, _| {}));
+        let opener = RecordingOpener::default();
+        set_link_opener(&mut cx, opener.clone());
+        let uid = WidgetUid(0);
+        assert!(follow_href(
+            &mut cx,
+            uid,
+            None,
+            "https://github.com/genrs",
+            LinkTarget::Blank
+        ));
+        assert!(!follow_href(
+            &mut cx,
+            uid,
+            None,
+            "file:///etc/passwd",
+            LinkTarget::Blank
+        ));
+        assert_eq!(
+            opener.opened(),
+            vec![("https://github.com/genrs".to_string(), LinkTarget::Blank)]
+        );
+    }
+}
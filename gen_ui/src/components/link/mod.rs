@@ -1,8 +1,12 @@
 mod event;
+mod href;
+mod opener;
 mod prop;
 
 pub use event::*;
+pub use href::*;
 use makepad_widgets::{shader::draw_text::TextStyle, *};
+pub use opener::*;
 pub use prop::*;
 
 use crate::{
@@ -16,9 +20,9 @@ use crate::{
     event_option, hit_finger_down, hit_finger_up, hit_hover_in, hit_hover_out, lifecycle,
     play_animation,
     prop::{
-        manuel::{BASIC, DISABLED, HOVER, PRESSED},
+        manuel::{BASIC, DISABLED, HOVER, PRESSED, VISITED},
         traits::ToFloat,
-        ApplyStateMap,
+        ApplyStateMap, LinkTarget,
     },
     pure_after_apply, set_animation, set_index, set_scope_path,
     shader::draw_link::DrawLink,
@@ -97,8 +101,19 @@ pub struct GLink {
     // --- others ----------------
     #[live]
     pub text: ArcStringMut,
+    /// - `route:/settings/profile`: navigate the nearest `GRouter`
+    /// - `http://...`, `https://...`, `mailto:...`: open by the `LinkOpener` (see `set_link_opener`),
+    ///   other schemes are refused
     #[live]
     pub href: Option<ArcStringMut>,
+    #[live]
+    pub target: LinkTarget,
+    /// follow the href when clicked, set it `false` to only handle `clicked`
+    #[live(true)]
+    pub auto_follow: bool,
+    /// the href has been followed, the link uses the `visited` style when not hovered
+    #[rust]
+    pub visited: bool,
     #[rust]
     index: usize,
     #[rust]
@@ -254,6 +269,7 @@ impl LiveHook for GLink {
                 live_id!(hover),
                 live_id!(pressed),
                 live_id!(disabled),
+                live_id!(visited),
             ],
             |_| {},
             |prefix, component, applys| match prefix.to_string().as_str() {
@@ -271,6 +287,9 @@ impl LiveHook for GLink {
                 PRESSED => {
                     component.apply_state_map.insert(LinkState::Pressed, applys);
                 }
+                VISITED => {
+                    component.apply_state_map.insert(LinkState::Visited, applys);
+                }
                 _ => {}
             },
         );
@@ -329,21 +348,24 @@ impl Component for GLink {
                 hit_hover_in!(self, cx, e);
             }
             Hit::FingerHoverOut(e) => {
-                self.switch_state_with_animation(cx, LinkState::Basic);
+                self.switch_state_with_animation(cx, self.rest_state());
                 hit_hover_out!(self, cx, e);
             }
             Hit::FingerUp(e) => {
                 if e.is_over {
+                    if self.auto_follow {
+                        self.follow_href(cx);
+                    }
                     if e.has_hovers() {
                         self.switch_state_with_animation(cx, LinkState::Hover);
                         self.play_animation(cx, id!(hover.on));
                     } else {
-                        self.switch_state_with_animation(cx, LinkState::Basic);
+                        self.switch_state_with_animation(cx, self.rest_state());
                         self.play_animation(cx, id!(hover.off));
                     }
                     self.active_clicked(cx, e);
                 } else {
-                    self.switch_state_with_animation(cx, LinkState::Basic);
+                    self.switch_state_with_animation(cx, self.rest_state());
                     hit_finger_up!(self, cx, e);
                 }
             }
@@ -537,7 +559,7 @@ impl Component for GLink {
             let state = self.state;
             let style = self.style.get(state);
             let index = match state {
-                LinkState::Basic => nodes.child_by_path(
+                LinkState::Basic | LinkState::Visited => nodes.child_by_path(
                     self.index,
                     &[
                         live_id!(animator).as_field(),
@@ -633,6 +655,29 @@ impl Component for GLink {
 }
 
 impl GLink {
+    /// the state when the link is not hovered or pressed
+    fn rest_state(&self) -> LinkState {
+        if self.visited {
+            LinkState::Visited
+        } else {
+            LinkState::Basic
+        }
+    }
+    /// navigate the router or open the url by the `href`
+    pub fn follow_href(&mut self, cx: &mut Cx) -> () {
+        let Some(href) = self.href.as_ref().map(|href| href.as_ref().to_string()) else {
+            return;
+        };
+        if follow_href(
+            cx,
+            self.widget_uid(),
+            self.scope_path.as_ref(),
+            &href,
+            self.target,
+        ) {
+            self.visited = true;
+        }
+    }
     active_event! {
         active_hover_in: LinkEvent::HoverIn |meta: FingerHoverEvent| => LinkHoverIn { meta },
         active_hover_out: LinkEvent::HoverOut |meta: FingerHoverEvent| => LinkHoverOut { meta },
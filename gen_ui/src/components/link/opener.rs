@@ -0,0 +1,102 @@
+use std::{cell::RefCell, io, process::Command, rc::Rc};
+
+use makepad_widgets::Cx;
+
+use crate::prop::LinkTarget;
+
+use super::is_allowed_url;
+
+/// opens the external `href` of `GLink`, replace it by `set_link_opener`
+pub trait LinkOpener {
+    fn open(&self, url: &str, target: LinkTarget) -> io::Result<()>;
+}
+
+/// the default opener, asks the OS to open the url, only the urls allowed by `is_allowed_url`
+/// are opened and no shell is involved
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemOpener;
+
+impl LinkOpener for SystemOpener {
+    fn open(&self, url: &str, _target: LinkTarget) -> io::Result<()> {
+        if !is_allowed_url(url) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("the scheme of {} is not allowed", url),
+            ));
+        }
+        system_command(url)?.spawn().map(|_| ())
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn system_command(url: &str) -> io::Result<Command> {
+    let mut command = Command::new("open");
+    command.arg(url);
+    Ok(command)
+}
+
+#[cfg(target_os = "windows")]
+fn system_command(url: &str) -> io::Result<Command> {
+    // `cmd /C start` would read `&`, `|` and `^` in the url as shell operators
+    let mut command = Command::new("explorer.exe");
+    command.arg(url);
+    Ok(command)
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+fn system_command(url: &str) -> io::Result<Command> {
+    let mut command = Command::new("xdg-open");
+    command.arg(url);
+    Ok(command)
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    all(unix, not(any(target_os = "ios", target_os = "android")))
+)))]
+fn system_command(_url: &str) -> io::Result<Command> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "opening links is not supported on this platform, use `set_link_opener`",
+    ))
+}
+
+/// records the opened urls instead of opening them, use it in tests
+#[derive(Debug, Clone, Default)]
+pub struct RecordingOpener {
+    opened: Rc<RefCell<Vec<(String, LinkTarget)>>>,
+}
+
+impl RecordingOpener {
+    pub fn opened(&self) -> Vec<(String, LinkTarget)> {
+        self.opened.borrow().clone()
+    }
+}
+
+impl LinkOpener for RecordingOpener {
+    fn open(&self, url: &str, target: LinkTarget) -> io::Result<()> {
+        self.opened.borrow_mut().push((url.to_string(), target));
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct LinkOpenerGlobal {
+    pub opener: Rc<dyn LinkOpener>,
+}
+
+impl Default for LinkOpenerGlobal {
+    fn default() -> Self {
+        Self {
+            opener: Rc::new(SystemOpener),
+        }
+    }
+}
+
+/// replace the opener of all `GLink`s
+pub fn set_link_opener(cx: &mut Cx, opener: impl LinkOpener + 'static) {
+    cx.global::<LinkOpenerGlobal>().opener = Rc::new(opener);
+}
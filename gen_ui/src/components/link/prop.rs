@@ -14,7 +14,7 @@ use crate::{
             BORDER_RADIUS, BORDER_WIDTH, CLIP_X, CLIP_Y, COLOR, CURSOR, DISABLED, FLOW, FONT_SIZE,
             HOVER, LINE_SPACING, MARGIN, PADDING, PRESSED, ROTATION, SCALE, SHADOW_COLOR,
             SHADOW_OFFSET, SPREAD_RADIUS, THEME, UNDERLINE_COLOR, UNDERLINE_VISIBLE,
-            UNDERLINE_WIDTH,
+            UNDERLINE_WIDTH, VISITED,
         },
         traits::{AbsPos, FromLiveColor, FromLiveValue, NewFrom, ToColor, ToTomlValue},
         ApplyStateMapImpl, Radius,
@@ -29,7 +29,8 @@ prop_interconvert! {
         basic => BASIC, LinkBasicStyle::default(), |v| (v, LinkState::Basic).try_into(),
         hover => HOVER, LinkBasicStyle::from_state(Theme::default(), LinkState::Hover), |v| (v, LinkState::Hover).try_into(),
         pressed => PRESSED, LinkBasicStyle::from_state(Theme::default(), LinkState::Pressed), |v| (v, LinkState::Pressed).try_into(),
-        disabled => DISABLED, LinkBasicStyle::from_state(Theme::default(), LinkState::Disabled), |v| (v, LinkState::Disabled).try_into()
+        disabled => DISABLED, LinkBasicStyle::from_state(Theme::default(), LinkState::Disabled), |v| (v, LinkState::Disabled).try_into(),
+        visited => VISITED, LinkBasicStyle::from_state(Theme::default(), LinkState::Visited), |v| (v, LinkState::Visited).try_into()
     }, "[component.link] should be a table"
 }
 
@@ -41,11 +42,12 @@ impl Style for LinkStyle {
         LinkState::Basic => basic,
         LinkState::Hover => hover,
         LinkState::Pressed => pressed,
-        LinkState::Disabled => disabled
+        LinkState::Disabled => disabled,
+        LinkState::Visited => visited
     }
 
     fn len() -> usize {
-        4 * LinkBasicStyle::len()
+        5 * LinkBasicStyle::len()
     }
 
     fn sync(&mut self, map: &crate::prop::ApplyStateMap<Self::State>) -> ()
@@ -59,6 +61,7 @@ impl Style for LinkStyle {
                 (LinkState::Hover, &mut self.hover),
                 (LinkState::Pressed, &mut self.pressed),
                 (LinkState::Disabled, &mut self.disabled),
+                (LinkState::Visited, &mut self.visited),
             ],
         );
     }
@@ -284,7 +287,8 @@ impl BasicStyle for LinkBasicStyle {
         LinkState::Basic => (300, 300, 500, 500, 400),
         LinkState::Hover => (200, 200, 400, 400, 300),
         LinkState::Pressed => (400, 400, 600, 600, 500),
-        LinkState::Disabled => (100, 100, 300, 300, 200)
+        LinkState::Disabled => (100, 100, 300, 300, 200),
+        LinkState::Visited => (500, 500, 600, 600, 500)
     }
 
     fn live_props() -> LiveProps {
@@ -422,7 +426,8 @@ component_state! {
         Basic => BASIC,
         Hover => HOVER,
         Pressed => PRESSED,
-        Disabled => DISABLED
+        Disabled => DISABLED,
+        Visited => VISITED
     },
     _ => LinkState::Basic
 }
@@ -440,6 +445,7 @@ impl From<LinkState> for ViewState {
             LinkState::Hover => ViewState::Hover,
             LinkState::Pressed => ViewState::Pressed,
             LinkState::Disabled => ViewState::Disabled,
+            LinkState::Visited => ViewState::Basic,
         }
     }
 }
@@ -0,0 +1,14 @@
+use makepad_widgets::*;
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum MarkdownEvent {
+    LinkClicked(MarkdownLinkClicked),
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub struct MarkdownLinkClicked {
+    pub meta: FingerUpEvent,
+    pub text: String,
+    pub href: String,
+}
@@ -0,0 +1,511 @@
+mod event;
+mod parse;
+
+pub use event::*;
+pub use parse::*;
+
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        image::GImageWidgetRefExt,
+        label::{GLabelWidgetExt, GLabelWidgetRefExt, RichSpan, split_words},
+        link::{GLinkWidgetRefExt, LinkEvent, LinkState},
+        traits::Style,
+        view::{GView, GViewWidgetExt, GViewWidgetRefExt},
+    },
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+    prop::LinkTarget,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GMarkdownBase = {{GMarkdown}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Down,
+                spacing: 12.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// font size of the headings (h1 ~ h6) based on the font size of the `heading` template
+const HEADING_SCALES: [f32; 6] = [2.0, 1.6, 1.35, 1.15, 1.0, 0.9];
+
+/// render CommonMark by GenUI widgets, so every element is styled by `ComponentsConf`
+/// - headings, paragraphs and table cells: `GLabel` (rich spans)
+/// - links: `GLink`, the paragraph (heading or cell) of them is a wrapped row of words and links
+/// - images: `GImage`, horizontal rules: `GDivider`
+/// - lists, block quotes, code blocks and tables: `GView`
+///
+/// clicking a link emits `MarkdownEvent::LinkClicked`, the links are not followed unless
+/// `open_links` is set, so untrusted markdown (such as a chat answer) can not open urls by itself.
+///
+/// use `append` when streaming, only the text of the last blocks is parsed again and only
+/// the changed blocks are updated
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GMarkdown {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub text: String,
+    /// target of the external links
+    #[live]
+    pub link_target: LinkTarget,
+    /// follow the links as `GLink` does when clicked (only `route:`, `http`, `https` and `mailto`)
+    #[live(false)]
+    pub open_links: bool,
+    #[live(true)]
+    pub event_open: bool,
+    // --- templates ----------------
+    #[live]
+    heading: Option<LivePtr>,
+    #[live]
+    paragraph: Option<LivePtr>,
+    #[live]
+    list: Option<LivePtr>,
+    #[live]
+    list_item: Option<LivePtr>,
+    #[live]
+    list_marker: Option<LivePtr>,
+    #[live]
+    list_content: Option<LivePtr>,
+    #[live]
+    quote: Option<LivePtr>,
+    #[live]
+    code_block: Option<LivePtr>,
+    #[live]
+    code: Option<LivePtr>,
+    #[live]
+    table: Option<LivePtr>,
+    #[live]
+    table_row: Option<LivePtr>,
+    #[live]
+    table_head_cell: Option<LivePtr>,
+    #[live]
+    table_cell: Option<LivePtr>,
+    #[live]
+    image: Option<LivePtr>,
+    #[live]
+    divider: Option<LivePtr>,
+    /// the wrapped row of the blocks with links
+    #[live]
+    inline: Option<LivePtr>,
+    /// a word in `inline`
+    #[live]
+    inline_text: Option<LivePtr>,
+    #[live]
+    link: Option<LivePtr>,
+    /// the rendered blocks, each one is a child of the view
+    #[rust]
+    blocks: Vec<MdBlock>,
+    #[rust]
+    stream: MdStream,
+}
+
+inherits_view_widget_node!(GMarkdown);
+
+impl Widget for GMarkdown {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if !self.event_open {
+            return;
+        }
+        for action in actions.iter() {
+            let Some(action) = action.as_widget_action() else {
+                continue;
+            };
+            if let LinkEvent::Clicked(e) = action.cast() {
+                let link = self.deref_widget.uid_to_widget(action.widget_uid);
+                let Some((text, href)) = link.as_glink().borrow().and_then(|link| {
+                    link.href
+                        .as_ref()
+                        .map(|href| (link.text.as_ref().to_string(), href.as_ref().to_string()))
+                }) else {
+                    continue;
+                };
+                cx.widget_action(
+                    self.widget_uid(),
+                    &scope.path,
+                    MarkdownEvent::LinkClicked(MarkdownLinkClicked {
+                        meta: e.meta,
+                        text,
+                        href,
+                    }),
+                );
+            }
+        }
+    }
+}
+
+impl LiveHook for GMarkdown {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        // the children are built from the text, rebuild them after the templates are applied
+        self.blocks.clear();
+        self.deref_widget.children.clear();
+        self.render_markdown(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GMarkdown {
+    pub fn set_text(&mut self, cx: &mut Cx, text: String) -> () {
+        self.text = text;
+        self.render_markdown(cx);
+    }
+    /// append the text (such as a chunk of a streaming response), only the last blocks are parsed
+    /// again and only the changed blocks are updated
+    pub fn append(&mut self, cx: &mut Cx, text: &str) -> () {
+        self.text.push_str(text);
+        let (from, blocks) = self.stream.parse(&self.text);
+        self.render_blocks(cx, from, blocks);
+    }
+    pub fn clear(&mut self, cx: &mut Cx) -> () {
+        self.set_text(cx, String::new());
+    }
+    pub fn blocks(&self) -> &[MdBlock] {
+        &self.blocks
+    }
+    /// parse all the text, the stream is reset as the text may not be appended
+    fn render_markdown(&mut self, cx: &mut Cx) -> () {
+        self.stream = MdStream::default();
+        let blocks = parse_markdown(&self.text);
+        self.render_blocks(cx, 0, blocks);
+    }
+    /// replace the blocks from `from` by `blocks`
+    fn render_blocks(&mut self, cx: &mut Cx, from: usize, blocks: Vec<MdBlock>) -> () {
+        let from = from.min(self.blocks.len());
+        if self.blocks[from..] == blocks[..] {
+            return;
+        }
+        let mut children = std::mem::take(&mut self.deref_widget.children);
+        let old = self.blocks.split_off(from);
+        self.blocks.extend(blocks);
+        if children.len() == from + old.len() {
+            self.sync_blocks(cx, &mut children, from, &old, &self.blocks[from..]);
+        } else {
+            // children are always created from the blocks, rebuild all if they are out of sync
+            children.clear();
+            self.sync_blocks(cx, &mut children, 0, &[], &self.blocks);
+        }
+        self.deref_widget.children = children;
+        self.redraw(cx);
+    }
+    /// keep the unchanged blocks, update the changed blocks in place if possible, otherwise recreate them.
+    /// `old` and `new` are the blocks of the children from `offset`
+    fn sync_blocks(
+        &self,
+        cx: &mut Cx,
+        children: &mut SmallVec<[(LiveId, WidgetRef); 2]>,
+        offset: usize,
+        old: &[MdBlock],
+        new: &[MdBlock],
+    ) -> () {
+        // children are always created from `old`, rebuild them if they are out of sync
+        let old = if children.len() == offset + old.len() {
+            old
+        } else {
+            children.truncate(offset);
+            &[]
+        };
+        for (index, block) in new.iter().enumerate() {
+            let child = offset + index;
+            if let Some((_, widget)) = children.get(child) {
+                let updated = match old.get(index) {
+                    Some(old) if old == block => true,
+                    Some(old) => self.update_block(cx, widget, old, block),
+                    None => false,
+                };
+                if !updated {
+                    children[child].1 = self.create_block(cx, block);
+                }
+            } else {
+                children.push((child_id(child), self.create_block(cx, block)));
+            }
+        }
+        children.truncate(offset + new.len());
+    }
+    fn update_block(&self, cx: &mut Cx, widget: &WidgetRef, old: &MdBlock, new: &MdBlock) -> bool {
+        match (old, new) {
+            // the blocks with links are recreated
+            (
+                MdBlock::Heading {
+                    level: old,
+                    spans: old_spans,
+                },
+                MdBlock::Heading { level, spans },
+            ) if old == level && !has_link(old_spans) && !has_link(spans) => {
+                widget.as_glabel().set_spans(cx, heading_spans(spans));
+                true
+            }
+            (MdBlock::Paragraph(old), MdBlock::Paragraph(spans))
+                if !has_link(old) && !has_link(spans) =>
+            {
+                widget.as_glabel().set_spans(cx, spans.clone());
+                true
+            }
+            (MdBlock::Code { .. }, MdBlock::Code { code, .. }) => {
+                let _ = widget.glabel(id!(code)).set_text(cx, code.to_string());
+                true
+            }
+            (MdBlock::Quote(old), MdBlock::Quote(blocks)) => {
+                if let Some(mut view) = widget.as_gview().borrow_mut() {
+                    self.sync_blocks(cx, &mut view.children, 0, old, blocks);
+                }
+                true
+            }
+            (
+                MdBlock::List {
+                    start: old_start,
+                    items: old_items,
+                },
+                MdBlock::List { start, items },
+            ) if old_start == start => {
+                if let Some(mut list) = widget.as_gview().borrow_mut() {
+                    if list.children.len() != old_items.len() {
+                        return false;
+                    }
+                    for (index, item) in items.iter().enumerate() {
+                        if index < old_items.len() {
+                            let row = list.children[index].1.clone();
+                            if let Some(mut content) = row.gview(id!(content)).borrow_mut() {
+                                self.sync_blocks(
+                                    cx,
+                                    &mut content.children,
+                                    0,
+                                    &old_items[index],
+                                    item,
+                                );
+                            }
+                        } else {
+                            let row = self.create_list_item(cx, *start, index, item);
+                            list.children.push((child_id(index), row));
+                        }
+                    }
+                    list.children.truncate(items.len());
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+    fn create_block(&self, cx: &mut Cx, block: &MdBlock) -> WidgetRef {
+        match block {
+            MdBlock::Heading { level, spans } => self.create_text(
+                cx,
+                self.heading,
+                &heading_spans(spans),
+                HEADING_SCALES[(*level as usize).clamp(1, 6) - 1],
+            ),
+            MdBlock::Paragraph(spans) => self.create_text(cx, self.paragraph, spans, 1.0),
+            MdBlock::List { start, items } => {
+                let widget = WidgetRef::new_from_ptr(cx, self.list);
+                if let Some(mut list) = widget.as_gview().borrow_mut() {
+                    for (index, item) in items.iter().enumerate() {
+                        let row = self.create_list_item(cx, *start, index, item);
+                        list.children.push((child_id(index), row));
+                    }
+                }
+                widget
+            }
+            MdBlock::Quote(blocks) => {
+                let widget = WidgetRef::new_from_ptr(cx, self.quote);
+                if let Some(mut view) = widget.as_gview().borrow_mut() {
+                    self.sync_blocks(cx, &mut view.children, 0, &[], blocks);
+                }
+                widget
+            }
+            MdBlock::Code { code, .. } => {
+                let widget = WidgetRef::new_from_ptr(cx, self.code_block);
+                if let Some(mut view) = widget.as_gview().borrow_mut() {
+                    let label = WidgetRef::new_from_ptr(cx, self.code);
+                    let _ = label.as_glabel().set_text(cx, code.to_string());
+                    view.children.push((live_id!(code), label));
+                }
+                widget
+            }
+            MdBlock::Table { head, rows } => {
+                let widget = WidgetRef::new_from_ptr(cx, self.table);
+                if let Some(mut table) = widget.as_gview().borrow_mut() {
+                    let head: Vec<Vec<RichSpan>> =
+                        head.iter().map(|cell| heading_spans(cell)).collect();
+                    let head = self.create_table_row(cx, self.table_head_cell, &head);
+                    table.children.push((live_id!(head), head));
+                    for (index, row) in rows.iter().enumerate() {
+                        let row = self.create_table_row(cx, self.table_cell, row);
+                        table.children.push((child_id(index), row));
+                    }
+                }
+                widget
+            }
+            MdBlock::Image { src, .. } => {
+                let widget = WidgetRef::new_from_ptr(cx, self.image);
+                if let Some(mut image) = widget.as_gimage().borrow_mut() {
+                    if let Err(e) = image.load(cx, src) {
+                        error!("GMarkdown can not load image {}: {}", src, e);
+                    }
+                }
+                widget
+            }
+            MdBlock::Rule => WidgetRef::new_from_ptr(cx, self.divider),
+        }
+    }
+    /// a label of the spans, or a wrapped row of words and `GLink`s if the spans have links.
+    /// The font size of the label template is scaled by `scale`
+    fn create_text(
+        &self,
+        cx: &mut Cx,
+        ptr: Option<LivePtr>,
+        spans: &[RichSpan],
+        scale: f32,
+    ) -> WidgetRef {
+        let label = WidgetRef::new_from_ptr(cx, ptr);
+        let mut font_size = 0.0;
+        if let Some(mut label) = label.as_glabel().borrow_mut() {
+            font_size = label.get_font_size() * scale;
+            if scale != 1.0 {
+                let _ = label.set_font_size(cx, font_size);
+            }
+        }
+        if !has_link(spans) {
+            label.as_glabel().set_spans(cx, spans.to_vec());
+            return label;
+        }
+        let widget = WidgetRef::new_from_ptr(cx, self.inline);
+        if let Some(mut view) = widget.as_gview().borrow_mut() {
+            for span in spans {
+                if let Some(href) = span.href.as_ref() {
+                    let link = self.create_link(cx, span, href, font_size);
+                    let id = child_id(view.children.len());
+                    view.children.push((id, link));
+                    continue;
+                }
+                // words are wrapped one by one as the links
+                for word in split_words(&span.text) {
+                    let text = WidgetRef::new_from_ptr(cx, self.inline_text);
+                    if let Some(mut text) = text.as_glabel().borrow_mut() {
+                        let _ = text.set_font_size(cx, font_size);
+                        text.set_spans(
+                            cx,
+                            vec![RichSpan {
+                                text: word.to_string(),
+                                ..span.clone()
+                            }],
+                        );
+                    }
+                    let id = child_id(view.children.len());
+                    view.children.push((id, text));
+                }
+            }
+        }
+        widget
+    }
+    fn create_link(&self, cx: &mut Cx, span: &RichSpan, href: &str, font_size: f32) -> WidgetRef {
+        let widget = WidgetRef::new_from_ptr(cx, self.link);
+        if let Some(mut link) = widget.as_glink().borrow_mut() {
+            link.text.as_mut_empty().push_str(&span.text);
+            let mut link_href = ArcStringMut::default();
+            link_href.as_mut_empty().push_str(href);
+            link.href.replace(link_href);
+            link.target = self.link_target;
+            link.auto_follow = self.open_links;
+            for state in [
+                LinkState::Basic,
+                LinkState::Hover,
+                LinkState::Pressed,
+                LinkState::Disabled,
+                LinkState::Visited,
+            ] {
+                link.style.get_mut(state).set_font_size(font_size);
+            }
+        }
+        widget
+    }
+    /// a row of the marker and the content, the blocks of the item are the children of the content
+    fn create_list_item(
+        &self,
+        cx: &mut Cx,
+        start: Option<u64>,
+        index: usize,
+        blocks: &[MdBlock],
+    ) -> WidgetRef {
+        let widget = WidgetRef::new_from_ptr(cx, self.list_item);
+        if let Some(mut row) = widget.as_gview().borrow_mut() {
+            let marker = WidgetRef::new_from_ptr(cx, self.list_marker);
+            let text = match start {
+                Some(start) => format!("{}.", start + index as u64),
+                None => "•".to_string(),
+            };
+            let _ = marker.as_glabel().set_text(cx, text);
+            let content = WidgetRef::new_from_ptr(cx, self.list_content);
+            if let Some(mut content) = content.as_gview().borrow_mut() {
+                self.sync_blocks(cx, &mut content.children, 0, &[], blocks);
+            }
+            row.children.push((live_id!(marker), marker));
+            row.children.push((live_id!(content), content));
+        }
+        widget
+    }
+    fn create_table_row(
+        &self,
+        cx: &mut Cx,
+        cell: Option<LivePtr>,
+        cells: &[Vec<RichSpan>],
+    ) -> WidgetRef {
+        let widget = WidgetRef::new_from_ptr(cx, self.table_row);
+        if let Some(mut row) = widget.as_gview().borrow_mut() {
+            for (index, spans) in cells.iter().enumerate() {
+                let cell = self.create_text(cx, cell, spans, 1.0);
+                row.children.push((child_id(index), cell));
+            }
+        }
+        widget
+    }
+    event_option! {
+        link_clicked: MarkdownEvent::LinkClicked => MarkdownLinkClicked
+    }
+}
+
+/// id of the child widget at `index`, `LiveId(0)` is the empty id so the ids start at 1
+fn child_id(index: usize) -> LiveId {
+    LiveId(index as u64 + 1)
+}
+
+fn has_link(spans: &[RichSpan]) -> bool {
+    spans.iter().any(|span| span.href.is_some())
+}
+
+/// headings and table heads are always bold
+fn heading_spans(spans: &[RichSpan]) -> Vec<RichSpan> {
+    spans.iter().cloned().map(RichSpan::bold).collect()
+}
+
+impl GMarkdownRef {
+    pub fn set_text(&self, cx: &mut Cx, text: String) -> () {
+        self.borrow_mut().map(|mut c| c.set_text(cx, text));
+    }
+    pub fn append(&self, cx: &mut Cx, text: &str) -> () {
+        self.borrow_mut().map(|mut c| c.append(cx, text));
+    }
+    pub fn clear(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.clear(cx));
+    }
+    pub fn text(&self) -> String {
+        self.borrow().map_or(String::new(), |c| c.text.to_string())
+    }
+    event_option_ref! {
+        link_clicked => MarkdownLinkClicked
+    }
+}
@@ -0,0 +1,465 @@
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+
+use crate::components::label::RichSpan;
+
+/// block of the markdown, each block is rendered as a widget of `GMarkdown`
+#[derive(Debug, Clone, PartialEq)]
+pub enum MdBlock {
+    Heading {
+        /// 1 ~ 6
+        level: u8,
+        spans: Vec<RichSpan>,
+    },
+    Paragraph(Vec<RichSpan>),
+    List {
+        /// start number of the ordered list, `None` is unordered
+        start: Option<u64>,
+        items: Vec<Vec<MdBlock>>,
+    },
+    Quote(Vec<MdBlock>),
+    Code {
+        lang: String,
+        code: String,
+    },
+    Table {
+        head: Vec<Vec<RichSpan>>,
+        rows: Vec<Vec<Vec<RichSpan>>>,
+    },
+    Image {
+        src: String,
+        alt: String,
+    },
+    Rule,
+}
+
+fn options() -> Options {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options
+}
+
+/// parse CommonMark (with tables and strikethrough) into blocks
+pub fn parse_markdown(text: &str) -> Vec<MdBlock> {
+    let mut builder = MdBuilder::default();
+    for event in Parser::new_ext(text, options()) {
+        builder.event(event);
+    }
+    builder.finish()
+}
+
+/// byte offset of the line which starts the last top-level block which is separated from
+/// the blocks before it, so appending text can not change the blocks before it:
+/// the block follows a blank line (no lazy continuation, setext heading or table row)
+/// and its first line is complete (`2` may become the item `2.` of the list before it)
+fn last_block_start(text: &str) -> usize {
+    let mut depth = 0_usize;
+    let mut starts = vec![];
+    for (event, range) in Parser::new_ext(text, options()).into_offset_iter() {
+        let start = match event {
+            Event::Start(_) => {
+                depth += 1;
+                depth == 1
+            }
+            Event::End(_) => {
+                depth = depth.saturating_sub(1);
+                false
+            }
+            Event::Rule => depth == 0,
+            _ => false,
+        };
+        if start {
+            starts.push(text[..range.start].rfind('\n').map_or(0, |index| index + 1));
+        }
+    }
+    starts
+        .into_iter()
+        .rev()
+        .find(|start| {
+            *start > 0
+                && text[*start..].contains('\n')
+                && text[..*start - 1]
+                    .rsplit('\n')
+                    .next()
+                    .map_or(true, |line| line.trim().is_empty())
+        })
+        .unwrap_or_default()
+}
+
+/// parse the markdown while it is appended (such as a streaming response).
+/// Appending text only changes the last top-level blocks, the blocks before the last one which
+/// follows a blank line are finished and only the text from it is parsed again.
+/// Link reference definitions only apply to the blocks parsed with them.
+#[derive(Debug, Clone, Default)]
+pub struct MdStream {
+    /// byte offset of the first block which is not finished
+    open: usize,
+    /// count of the finished blocks
+    closed: usize,
+}
+
+impl MdStream {
+    /// parse the text which starts with the text of the last call,
+    /// returns the index of the first block which may be changed and the blocks from it
+    pub fn parse(&mut self, text: &str) -> (usize, Vec<MdBlock>) {
+        if self.open > text.len() || !text.is_char_boundary(self.open) {
+            *self = Self::default();
+        }
+        let from = self.closed;
+        let tail = &text[self.open..];
+        let last = last_block_start(tail);
+        let mut blocks = parse_markdown(&tail[..last]);
+        self.closed += blocks.len();
+        self.open += last;
+        blocks.extend(parse_markdown(&tail[last..]));
+        (from, blocks)
+    }
+}
+
+enum MdContainer {
+    Quote(Vec<MdBlock>),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<MdBlock>>,
+    },
+    Item(Vec<MdBlock>),
+}
+
+#[derive(Clone, Copy)]
+enum MdInline {
+    Paragraph,
+    Heading(u8),
+    Cell,
+}
+
+#[derive(Default)]
+struct MdTable {
+    head: Vec<Vec<RichSpan>>,
+    rows: Vec<Vec<Vec<RichSpan>>>,
+    row: Vec<Vec<RichSpan>>,
+}
+
+#[derive(Default)]
+struct MdBuilder {
+    blocks: Vec<MdBlock>,
+    stack: Vec<MdContainer>,
+    inline: Option<MdInline>,
+    spans: Vec<RichSpan>,
+    /// style of the next text, the text of it is not used
+    style: RichSpan,
+    code: Option<(String, String)>,
+    table: Option<MdTable>,
+    image: Option<(String, String)>,
+}
+
+impl MdBuilder {
+    fn event(&mut self, event: Event) -> () {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                if let Some((_, code)) = self.code.as_mut() {
+                    code.push_str(&text);
+                } else if let Some((_, alt)) = self.image.as_mut() {
+                    alt.push_str(&text);
+                } else {
+                    self.text(&text, false);
+                }
+            }
+            Event::Code(text) => self.text(&text, true),
+            Event::SoftBreak => self.text(" ", false),
+            Event::HardBreak => self.text("\n", false),
+            Event::TaskListMarker(checked) => self.text(if checked { "☑ " } else { "☐ " }, false),
+            Event::Rule => {
+                self.flush();
+                self.push(MdBlock::Rule);
+            }
+            _ => {}
+        }
+    }
+    fn start(&mut self, tag: Tag) -> () {
+        match tag {
+            Tag::Paragraph => {
+                self.flush();
+                self.inline.replace(MdInline::Paragraph);
+            }
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.inline.replace(MdInline::Heading(level as u8));
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.stack.push(MdContainer::Quote(vec![]));
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.stack.push(MdContainer::List {
+                    start,
+                    items: vec![],
+                });
+            }
+            Tag::Item => {
+                self.flush();
+                self.stack.push(MdContainer::Item(vec![]));
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                let lang = match kind {
+                    CodeBlockKind::Fenced(lang) => lang.trim().to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code.replace((lang, String::new()));
+            }
+            Tag::Table(_) => {
+                self.flush();
+                self.table.replace(MdTable::default());
+            }
+            Tag::TableHead | Tag::TableRow => {
+                self.table.as_mut().map(|table| table.row.clear());
+            }
+            Tag::TableCell => {
+                self.spans.clear();
+                self.inline.replace(MdInline::Cell);
+            }
+            Tag::Emphasis => self.style.italic = true,
+            Tag::Strong => self.style.bold = true,
+            Tag::Strikethrough => self.style.strike = true,
+            Tag::Link { dest_url, .. } => {
+                self.style.href.replace(dest_url.to_string());
+            }
+            Tag::Image { dest_url, .. } => {
+                self.image.replace((dest_url.to_string(), String::new()));
+            }
+            _ => {}
+        }
+    }
+    fn end(&mut self, tag: TagEnd) -> () {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Heading(_) => self.flush(),
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                if let Some(MdContainer::Quote(blocks)) = self.stack.pop() {
+                    self.push(MdBlock::Quote(blocks));
+                }
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                if let Some(MdContainer::List { start, items }) = self.stack.pop() {
+                    self.push(MdBlock::List { start, items });
+                }
+            }
+            TagEnd::Item => {
+                self.flush();
+                if let Some(MdContainer::Item(blocks)) = self.stack.pop() {
+                    if let Some(MdContainer::List { items, .. }) = self.stack.last_mut() {
+                        items.push(blocks);
+                    }
+                }
+            }
+            TagEnd::CodeBlock => {
+                if let Some((lang, code)) = self.code.take() {
+                    let code = code.trim_end_matches('\n').to_string();
+                    self.push(MdBlock::Code { lang, code });
+                }
+            }
+            TagEnd::Table => {
+                if let Some(MdTable { head, rows, .. }) = self.table.take() {
+                    self.push(MdBlock::Table { head, rows });
+                }
+            }
+            TagEnd::TableHead => {
+                self.table
+                    .as_mut()
+                    .map(|table| table.head = std::mem::take(&mut table.row));
+            }
+            TagEnd::TableRow => {
+                self.table.as_mut().map(|table| {
+                    let row = std::mem::take(&mut table.row);
+                    table.rows.push(row);
+                });
+            }
+            TagEnd::TableCell => {
+                let cell = std::mem::take(&mut self.spans);
+                self.inline = None;
+                self.table.as_mut().map(|table| table.row.push(cell));
+            }
+            TagEnd::Emphasis => self.style.italic = false,
+            TagEnd::Strong => self.style.bold = false,
+            TagEnd::Strikethrough => self.style.strike = false,
+            TagEnd::Link => self.style.href = None,
+            TagEnd::Image => {
+                if let Some((src, alt)) = self.image.take() {
+                    if let Some(MdInline::Cell) = self.inline {
+                        // tables only contain text
+                        self.text(&alt, false);
+                    } else {
+                        // the image breaks the paragraph, the text after it is a new paragraph
+                        let inline = self.inline;
+                        self.flush();
+                        self.push(MdBlock::Image { src, alt });
+                        self.inline = inline;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    fn text(&mut self, text: &str, code: bool) -> () {
+        if text.is_empty() {
+            return;
+        }
+        // the items of tight lists contain text without paragraph
+        if self.inline.is_none() {
+            self.inline.replace(MdInline::Paragraph);
+        }
+        match self.spans.last_mut() {
+            Some(last) if !code && !last.code && last.same_style(&self.style) => {
+                last.text.push_str(text);
+            }
+            _ => {
+                let mut span = self.style.clone();
+                span.text = text.to_string();
+                span.code = code;
+                self.spans.push(span);
+            }
+        }
+    }
+    /// finish the current paragraph or heading
+    fn flush(&mut self) -> () {
+        let spans = std::mem::take(&mut self.spans);
+        match self.inline.take() {
+            Some(MdInline::Paragraph) if !spans.is_empty() => {
+                self.push(MdBlock::Paragraph(spans));
+            }
+            Some(MdInline::Heading(level)) => {
+                self.push(MdBlock::Heading { level, spans });
+            }
+            Some(MdInline::Cell) => {
+                // cells are finished by the end of the cell
+                self.spans = spans;
+                self.inline.replace(MdInline::Cell);
+            }
+            _ => {}
+        }
+    }
+    fn push(&mut self, block: MdBlock) -> () {
+        match self.stack.last_mut() {
+            Some(MdContainer::Quote(blocks)) | Some(MdContainer::Item(blocks)) => {
+                blocks.push(block)
+            }
+            Some(MdContainer::List { items, .. }) => match items.last_mut() {
+                Some(item) => item.push(block),
+                None => items.push(vec![block]),
+            },
+            None => self.blocks.push(block),
+        }
+    }
+    fn finish(mut self) -> Vec<MdBlock> {
+        self.flush();
+        while !self.stack.is_empty() {
+            self.end(match self.stack.last() {
+                Some(MdContainer::Quote(_)) => TagEnd::BlockQuote(None),
+                Some(MdContainer::List { .. }) => TagEnd::List(false),
+                _ => TagEnd::Item,
+            });
+        }
+        self.blocks
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MdBlock, MdStream, parse_markdown};
+    use crate::components::label::RichSpan;
+
+    #[test]
+    fn parse_markdown_test() {
+        let blocks = parse_markdown(
+            "# Title\n\nHello **GenUI**, see [docs](https://github.com).\n\n- a\n- b `c`\n\n> quote\n\n```rust\nfn main() {}\n```\n\n---\n",
+        );
+        assert_eq!(
+            blocks,
+            vec![
+                MdBlock::Heading {
+                    level: 1,
+                    spans: vec![RichSpan::new("Title")]
+                },
+                MdBlock::Paragraph(vec![
+                    RichSpan::new("Hello "),
+                    RichSpan::new("GenUI").bold(),
+                    RichSpan::new(", see "),
+                    RichSpan::new("docs").link("https://github.com"),
+                    RichSpan::new("."),
+                ]),
+                MdBlock::List {
+                    start: None,
+                    items: vec![
+                        vec![MdBlock::Paragraph(vec![RichSpan::new("a")])],
+                        vec![MdBlock::Paragraph(vec![
+                            RichSpan::new("b "),
+                            RichSpan::new("c").code()
+                        ])],
+                    ]
+                },
+                MdBlock::Quote(vec![MdBlock::Paragraph(vec![RichSpan::new("quote")])]),
+                MdBlock::Code {
+                    lang: "rust".to_string(),
+                    code: "fn main() {}".to_string()
+                },
+                MdBlock::Rule,
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_markdown_table_image_test() {
+        let blocks = parse_markdown("| a | b |\n|---|---|\n| 1 | ~~2~~ |\n\n![logo](logo.png)\n");
+        assert_eq!(
+            blocks,
+            vec![
+                MdBlock::Table {
+                    head: vec![vec![RichSpan::new("a")], vec![RichSpan::new("b")]],
+                    rows: vec![vec![
+                        vec![RichSpan::new("1")],
+                        vec![RichSpan::new("2").strike()]
+                    ]],
+                },
+                MdBlock::Image {
+                    src: "logo.png".to_string(),
+                    alt: "logo".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_markdown_streaming_test() {
+        // an unclosed fence is still a code block while streaming
+        assert_eq!(
+            parse_markdown("```\nlet a"),
+            vec![MdBlock::Code {
+                lang: String::new(),
+                code: "let a".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_markdown_stream_test() {
+        let text = "# Title\n\nHello **GenUI**,\nsee [docs](https://github.com).\n\n- a\n- b\n\n```rust\nfn main() {}\n```\n\n> quote\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n---\nend";
+        let mut stream = MdStream::default();
+        let mut blocks = vec![];
+        let mut appended = String::new();
+        for c in text.chars() {
+            appended.push(c);
+            let (from, tail) = stream.parse(&appended);
+            assert!(from <= blocks.len());
+            blocks.truncate(from);
+            blocks.extend(tail);
+            assert_eq!(blocks, parse_markdown(&appended), "{:?}", appended);
+        }
+        // only the blocks from the rule (`end` may be a setext heading of it) are parsed again
+        assert_eq!(stream.parse(text).0, blocks.len() - 2);
+    }
+}
@@ -0,0 +1,422 @@
+use std::{path::PathBuf, sync::Arc};
+
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        button::GButtonWidgetExt,
+        label::GLabelWidgetExt,
+        slider::{GSliderWidgetExt, SliderEvent},
+        view::GView,
+    },
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+};
+
+use super::{
+    decoder::{AudioData, AudioDecoder, WavDecoder, format_time},
+    event::*,
+    mixer::{MediaMixer, MixerVoice},
+    threads::MediaThreads,
+    waveform::GWaveformWidgetExt,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GAudioBase = {{GAudio}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Right,
+                spacing: 8.0,
+                align: {y: 0.5},
+            }
+        }
+    }
+}
+
+/// audio player, the template contains:
+/// - `play`: `GButton` toggles play and pause
+/// - `wave`: `GWaveform` of the audio (shown if `waveform`), or `progress`: `GSlider` to seek
+/// - `time`: `GLabel` of the current time and the duration
+/// - `volume`: `GSlider` in `0.0..=1.0`
+///
+/// `src` is a local file decoded by the `AudioDecoder` (`WavDecoder` by default)
+///
+/// ```rust,ignore
+/// let audio = self.gaudio(id!(audio));
+/// audio.set_src(cx, "/path/to/voice.wav");
+/// audio.play(cx);
+/// ```
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GAudio {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub src: String,
+    /// play once loaded
+    #[live]
+    pub autoplay: bool,
+    /// play again from the start when ended
+    #[live]
+    pub looping: bool,
+    #[live(1.0)]
+    pub volume: f32,
+    /// show the waveform instead of the progress slider
+    #[live(true)]
+    pub waveform: bool,
+    #[live(true)]
+    pub event_open: bool,
+    /// text of the `play` button while paused
+    #[live("Play".to_string())]
+    pub play_text: String,
+    /// text of the `play` button while playing
+    #[live("Pause".to_string())]
+    pub pause_text: String,
+    #[rust]
+    pub decoder: Option<Arc<dyn AudioDecoder>>,
+    #[rust]
+    voice: Option<MixerVoice>,
+    /// the src loading or loaded
+    #[rust]
+    loaded: String,
+    /// id of the last decoding, results of the older ones are dropped
+    #[rust]
+    load: u64,
+    #[rust]
+    duration: f64,
+    #[rust]
+    playing: bool,
+    /// the progress slider is dragged, do not move it
+    #[rust]
+    seeking: bool,
+    #[rust]
+    next_frame: NextFrame,
+}
+
+/// posted from the decoder thread back to the `GAudio` which started the decoding
+#[derive(Debug)]
+pub struct AudioDecoded {
+    pub audio: WidgetUid,
+    pub load: u64,
+    pub result: Result<Arc<AudioData>, String>,
+}
+
+inherits_view_widget_node!(GAudio);
+
+impl Widget for GAudio {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        if let Event::Actions(actions) = event {
+            for action in actions {
+                if let Some(AudioDecoded {
+                    audio,
+                    load,
+                    result,
+                }) = action.downcast_ref()
+                {
+                    if *audio == self.widget_uid() && *load == self.load {
+                        self.handle_decoded(cx, result);
+                    }
+                }
+            }
+        }
+        if self.next_frame.is_event(event).is_some() {
+            self.tick(cx);
+        }
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if self.gbutton(id!(play)).clicked(&actions).is_some() {
+            self.toggle(cx);
+        }
+        if let Some(e) = self.gwaveform(id!(wave)).seek(&actions) {
+            self.seek(cx, e.progress as f64 * self.duration);
+        }
+        match actions
+            .find_widget_action(self.gslider(id!(progress)).widget_uid())
+            .cast()
+        {
+            SliderEvent::FingerDown(_) => self.seeking = true,
+            SliderEvent::FingerUp(_) => self.seeking = false,
+            SliderEvent::Changed(e) => {
+                self.seeking = false;
+                self.seek(cx, e.value);
+            }
+            _ => {}
+        }
+        if let SliderEvent::Changed(e) = actions
+            .find_widget_action(self.gslider(id!(volume)).widget_uid())
+            .cast()
+        {
+            self.set_volume(cx, e.value as f32);
+        }
+    }
+}
+
+impl LiveHook for GAudio {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GAudio {
+    fn render(&mut self, cx: &mut Cx) -> () {
+        let _ = self.gwaveform(id!(wave)).set_visible(cx, self.waveform);
+        let _ = self.gslider(id!(progress)).set_visible(cx, !self.waveform);
+        if let Some(mut volume) = self.gslider(id!(volume)).borrow_mut() {
+            volume.value = self.volume;
+        }
+        if self.src != self.loaded {
+            self.load(cx);
+        }
+        self.sync(cx);
+    }
+    /// decode the `src` in a background thread
+    fn load(&mut self, cx: &mut Cx) -> () {
+        self.voice = None;
+        self.playing = false;
+        self.duration = 0.0;
+        self.loaded = self.src.to_string();
+        self.load += 1;
+        self.gwaveform(id!(wave)).set_peaks(cx, vec![]);
+        if self.src.is_empty() {
+            return;
+        }
+        let decoder = self.decoder.clone().unwrap_or_else(|| Arc::new(WavDecoder));
+        let (audio, load, src) = (self.widget_uid(), self.load, self.src.to_string());
+        MediaThreads::execute(cx, audio, move || {
+            let result = decoder.decode(&PathBuf::from(&src)).map(Arc::new);
+            Cx::post_action(AudioDecoded {
+                audio,
+                load,
+                result,
+            });
+        });
+    }
+    fn handle_decoded(&mut self, cx: &mut Cx, result: &Result<Arc<AudioData>, String>) -> () {
+        match result {
+            Ok(data) => {
+                self.duration = data.duration();
+                self.gwaveform(id!(wave)).set_peaks(cx, data.peaks(PEAKS));
+                if let Some(mut progress) = self.gslider(id!(progress)).borrow_mut() {
+                    progress.min = 0.0;
+                    progress.max = self.duration as f32;
+                    progress.value = 0.0;
+                }
+                self.voice
+                    .replace(MediaMixer::add(cx, data.clone(), self.volume));
+                self.active(
+                    cx,
+                    MediaEvent::Loaded(MediaLoaded {
+                        duration: self.duration,
+                    }),
+                );
+                if self.autoplay {
+                    self.play(cx);
+                }
+            }
+            Err(error) => {
+                error!("GAudio can not decode {}: {}", self.loaded, error);
+                self.active(
+                    cx,
+                    MediaEvent::Failed(MediaFailed {
+                        error: error.to_string(),
+                    }),
+                );
+            }
+        }
+        self.sync(cx);
+    }
+    /// follow the mixer while playing
+    fn tick(&mut self, cx: &mut Cx) -> () {
+        if !self.playing {
+            return;
+        }
+        if self.voice.as_ref().map_or(false, |voice| voice.ended()) {
+            if self.looping {
+                self.voice.as_ref().map(|voice| voice.set_playing(true));
+            } else {
+                self.playing = false;
+                self.active(
+                    cx,
+                    MediaEvent::Ended(MediaEnded {
+                        duration: self.duration,
+                    }),
+                );
+            }
+        }
+        self.sync(cx);
+        if self.playing {
+            self.next_frame = cx.new_next_frame();
+        }
+    }
+    fn active(&mut self, cx: &mut Cx, e: MediaEvent) -> () {
+        if !self.event_open {
+            return;
+        }
+        self.scope_path.as_ref().map(|path| {
+            cx.widget_action(self.widget_uid(), path, e);
+        });
+    }
+    /// sync the controls with the current time
+    fn sync(&mut self, cx: &mut Cx) -> () {
+        let time = self.time();
+        let play_text = if self.playing {
+            &self.pause_text
+        } else {
+            &self.play_text
+        };
+        let _ = self.gbutton(id!(play)).set_text(cx, play_text);
+        let _ = self.glabel(id!(time)).set_text(
+            cx,
+            format!("{} / {}", format_time(time), format_time(self.duration)),
+        );
+        let progress = if self.duration > 0.0 {
+            (time / self.duration) as f32
+        } else {
+            0.0
+        };
+        self.gwaveform(id!(wave)).set_progress(cx, progress);
+        if !self.seeking {
+            if let Some(mut slider) = self.gslider(id!(progress)).borrow_mut() {
+                slider.value = time as f32;
+            }
+        }
+        self.redraw(cx);
+    }
+    pub fn set_src(&mut self, cx: &mut Cx, src: &str) -> () {
+        self.src = src.to_string();
+        self.render(cx);
+    }
+    /// set the decoder and load the `src` again
+    pub fn set_decoder<D>(&mut self, cx: &mut Cx, decoder: D) -> ()
+    where
+        D: AudioDecoder,
+    {
+        self.decoder.replace(Arc::new(decoder));
+        self.loaded.clear();
+        self.render(cx);
+    }
+    pub fn play(&mut self, cx: &mut Cx) -> () {
+        let Some(voice) = self.voice.as_ref() else {
+            return;
+        };
+        if self.playing {
+            return;
+        }
+        voice.set_playing(true);
+        self.playing = true;
+        self.next_frame = cx.new_next_frame();
+        self.active(cx, MediaEvent::Play(MediaPlay { time: self.time() }));
+        self.sync(cx);
+    }
+    pub fn pause(&mut self, cx: &mut Cx) -> () {
+        if !self.playing {
+            return;
+        }
+        self.voice.as_ref().map(|voice| voice.set_playing(false));
+        self.playing = false;
+        self.active(cx, MediaEvent::Pause(MediaPause { time: self.time() }));
+        self.sync(cx);
+    }
+    pub fn toggle(&mut self, cx: &mut Cx) -> () {
+        if self.playing {
+            self.pause(cx);
+        } else {
+            self.play(cx);
+        }
+    }
+    /// `time` in seconds
+    pub fn seek(&mut self, cx: &mut Cx, time: f64) -> () {
+        let Some(voice) = self.voice.as_ref() else {
+            return;
+        };
+        let time = time.clamp(0.0, self.duration);
+        voice.seek(time);
+        self.active(cx, MediaEvent::Seek(MediaSeek { time }));
+        self.sync(cx);
+    }
+    /// `volume` in `0.0..=1.0`
+    pub fn set_volume(&mut self, cx: &mut Cx, volume: f32) -> () {
+        self.volume = volume.clamp(0.0, 1.0);
+        self.voice
+            .as_ref()
+            .map(|voice| voice.set_volume(self.volume));
+        if let Some(mut slider) = self.gslider(id!(volume)).borrow_mut() {
+            slider.value = self.volume;
+        }
+        self.redraw(cx);
+    }
+    /// current time in seconds
+    pub fn time(&self) -> f64 {
+        self.voice.as_ref().map_or(0.0, |voice| voice.time())
+    }
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+    event_option! {
+        loaded: MediaEvent::Loaded => MediaLoaded,
+        played: MediaEvent::Play => MediaPlay,
+        paused: MediaEvent::Pause => MediaPause,
+        seeked: MediaEvent::Seek => MediaSeek,
+        ended: MediaEvent::Ended => MediaEnded,
+        failed: MediaEvent::Failed => MediaFailed
+    }
+}
+
+impl GAudioRef {
+    pub fn set_src(&self, cx: &mut Cx, src: &str) -> () {
+        self.borrow_mut().map(|mut c| c.set_src(cx, src));
+    }
+    pub fn set_decoder<D>(&self, cx: &mut Cx, decoder: D) -> ()
+    where
+        D: AudioDecoder,
+    {
+        self.borrow_mut().map(|mut c| c.set_decoder(cx, decoder));
+    }
+    pub fn play(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.play(cx));
+    }
+    pub fn pause(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.pause(cx));
+    }
+    pub fn toggle(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.toggle(cx));
+    }
+    pub fn seek(&self, cx: &mut Cx, time: f64) -> () {
+        self.borrow_mut().map(|mut c| c.seek(cx, time));
+    }
+    pub fn set_volume(&self, cx: &mut Cx, volume: f32) -> () {
+        self.borrow_mut().map(|mut c| c.set_volume(cx, volume));
+    }
+    pub fn time(&self) -> f64 {
+        self.borrow().map_or(0.0, |c| c.time())
+    }
+    pub fn duration(&self) -> f64 {
+        self.borrow().map_or(0.0, |c| c.duration())
+    }
+    pub fn is_playing(&self) -> bool {
+        self.borrow().map_or(false, |c| c.is_playing())
+    }
+    event_option_ref! {
+        loaded => MediaLoaded,
+        played => MediaPlay,
+        paused => MediaPause,
+        seeked => MediaSeek,
+        ended => MediaEnded,
+        failed => MediaFailed
+    }
+}
+
+/// bars of the waveform are resampled from these peaks
+const PEAKS: usize = 256;
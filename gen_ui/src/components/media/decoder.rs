@@ -0,0 +1,363 @@
+use std::path::Path;
+
+use makepad_widgets::image_cache::ImageBuffer;
+
+use crate::{components::image::format::decode_frames, prop::ImgFormat};
+
+/// # Audio Data
+/// Decoded pcm of an audio file, `samples` are interleaved by channel in `-1.0..=1.0`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AudioData {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub samples: Vec<f32>,
+}
+
+impl AudioData {
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels.max(1) as usize
+    }
+    /// seconds
+    pub fn duration(&self) -> f64 {
+        self.frames() as f64 / self.sample_rate.max(1) as f64
+    }
+    /// sample of the channel at the frame, the last channel is used for the missing ones
+    pub fn sample(&self, frame: usize, channel: usize) -> f32 {
+        let channels = self.channels.max(1) as usize;
+        self.samples
+            .get(frame * channels + channel.min(channels - 1))
+            .copied()
+            .unwrap_or_default()
+    }
+    /// max amplitude of `count` equal parts of the audio, for drawing the waveform
+    pub fn peaks(&self, count: usize) -> Vec<f32> {
+        let frames = self.frames();
+        if count == 0 || frames == 0 {
+            return vec![0.0; count];
+        }
+        let channels = self.channels.max(1) as usize;
+        (0..count)
+            .map(|part| {
+                let start = part * frames / count;
+                let end = ((part + 1) * frames / count).max(start + 1).min(frames);
+                self.samples[start * channels..end * channels]
+                    .iter()
+                    .fold(0.0_f32, |peak, sample| peak.max(sample.abs()))
+            })
+            .collect()
+    }
+}
+
+/// # Audio Decoder
+/// Decode an audio file for `GAudio`, `decode` runs in a background thread so it can block.
+/// `WavDecoder` is used if no decoder is set, plug in your own (such as one built on symphonia)
+/// for compressed formats.
+pub trait AudioDecoder: Send + Sync + 'static {
+    fn decode(&self, path: &Path) -> Result<AudioData, String>;
+}
+
+impl<F> AudioDecoder for F
+where
+    F: Fn(&Path) -> Result<AudioData, String> + Send + Sync + 'static,
+{
+    fn decode(&self, path: &Path) -> Result<AudioData, String> {
+        self(path)
+    }
+}
+
+/// decode pcm (8, 16, 24, 32 bits) and float (32 bits) wav files
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WavDecoder;
+
+impl AudioDecoder for WavDecoder {
+    fn decode(&self, path: &Path) -> Result<AudioData, String> {
+        let data = std::fs::read(path).map_err(|e| e.to_string())?;
+        decode_wav(&data)
+    }
+}
+
+/// wav is riff chunks: id(4) + size(4, little endian) + data(size, padded to even)
+pub fn decode_wav(data: &[u8]) -> Result<AudioData, String> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return Err("not a wav file".to_string());
+    }
+    let mut format = None;
+    let mut offset = 12;
+    while offset + 8 <= data.len() {
+        let id = &data[offset..offset + 4];
+        let size = u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = offset + 8;
+        let end = (body + size).min(data.len());
+        match id {
+            b"fmt " => {
+                // the size may be larger than the file, check the bytes really read
+                let fmt = &data[body..end];
+                if fmt.len() < 16 {
+                    return Err("the fmt chunk is truncated".to_string());
+                }
+                let u16_at = |offset: usize| u16::from_le_bytes([fmt[offset], fmt[offset + 1]]);
+                let mut tag = u16_at(0);
+                // WAVE_FORMAT_EXTENSIBLE, the tag is the head of the sub format guid
+                if tag == 0xFFFE {
+                    if fmt.len() < 40 {
+                        return Err("the extensible fmt chunk is truncated".to_string());
+                    }
+                    tag = u16_at(24);
+                }
+                let sample_rate = u32::from_le_bytes(fmt[4..8].try_into().unwrap());
+                format.replace((tag, u16_at(2), sample_rate, u16_at(14)));
+            }
+            b"data" => {
+                let (tag, channels, sample_rate, bits) =
+                    format.ok_or("the data chunk is before the fmt chunk".to_string())?;
+                let samples = decode_samples(&data[body..end], tag, bits)?;
+                return Ok(AudioData {
+                    sample_rate,
+                    channels,
+                    samples,
+                });
+            }
+            _ => {}
+        }
+        offset = body + size + size % 2;
+    }
+    Err("no data chunk in the wav file".to_string())
+}
+
+fn decode_samples(data: &[u8], tag: u16, bits: u16) -> Result<Vec<f32>, String> {
+    let samples = match (tag, bits) {
+        (1, 8) => data.iter().map(|b| (*b as f32 - 128.0) / 128.0).collect(),
+        (1, 16) => data
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0)
+            .collect(),
+        (1, 24) => data
+            .chunks_exact(3)
+            .map(|b| i32::from_le_bytes([0, b[0], b[1], b[2]]) as f32 / 2147483648.0)
+            .collect(),
+        (1, 32) => data
+            .chunks_exact(4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2147483648.0)
+            .collect(),
+        (3, 32) => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        _ => return Err(format!("unsupported wav format {} with {} bits", tag, bits)),
+    };
+    Ok(samples)
+}
+
+/// size and length of a video
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VideoInfo {
+    pub width: usize,
+    pub height: usize,
+    /// seconds
+    pub duration: f64,
+}
+
+/// a decoded frame, `data` is argb pixels (the same as the texture of `GImage`)
+#[derive(Debug, Clone, Default)]
+pub struct VideoFrame {
+    /// display time (seconds) from the start
+    pub time: f64,
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<u32>,
+}
+
+/// # Video Stream
+/// An opened video, frames are pulled in display order by the decoder thread of `GVideo`
+pub trait VideoStream: Send + 'static {
+    fn info(&self) -> VideoInfo;
+    /// the next frame, `None` at the end
+    fn next_frame(&mut self) -> Result<Option<VideoFrame>, String>;
+    /// the next frame is the one shown at `time` (seconds)
+    fn seek(&mut self, time: f64) -> Result<(), String>;
+    /// the decoded sound track, played by the mixer of `GAudio` and `GVideo`
+    fn audio(&mut self) -> Option<AudioData> {
+        None
+    }
+}
+
+/// # Video Decoder
+/// Open a video file for `GVideo`, `open` runs in the decoder thread so it can block.
+/// GenUI does not decode video formats (mp4, webm ...) itself: `AnimationDecoder` (animated gif,
+/// apng and webp only) is used if no decoder is set, plug in your own (such as one built on ffmpeg)
+/// to play videos.
+pub trait VideoDecoder: Send + Sync + 'static {
+    fn open(&self, path: &Path) -> Result<Box<dyn VideoStream>, String>;
+}
+
+impl<F> VideoDecoder for F
+where
+    F: Fn(&Path) -> Result<Box<dyn VideoStream>, String> + Send + Sync + 'static,
+{
+    fn open(&self, path: &Path) -> Result<Box<dyn VideoStream>, String> {
+        self(path)
+    }
+}
+
+/// play the frames of animated images (gif, apng and webp) as a video without sound.
+/// All frames are decoded into memory when opened, so it is only meant for short animations,
+/// other files (such as mp4) are refused with an error.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnimationDecoder;
+
+impl VideoDecoder for AnimationDecoder {
+    fn open(&self, path: &Path) -> Result<Box<dyn VideoStream>, String> {
+        let data = std::fs::read(path).map_err(|e| e.to_string())?;
+        if !matches!(
+            ImgFormat::sniff(&data),
+            Some(ImgFormat::Gif | ImgFormat::Apng | ImgFormat::WebP)
+        ) {
+            return Err(format!(
+                "{} is not an animated gif, png or webp, set a `VideoDecoder` to play videos",
+                path.display()
+            ));
+        }
+        let frames = decode_frames(&data).map_err(|e| format!("{:?}", e))?;
+        if frames.is_empty() {
+            return Err(format!("{} has no frames", path.display()));
+        }
+        Ok(Box::new(AnimationStream::new(frames)))
+    }
+}
+
+struct AnimationStream {
+    frames: Vec<VideoFrame>,
+    info: VideoInfo,
+    index: usize,
+}
+
+impl AnimationStream {
+    fn new(frames: Vec<(ImageBuffer, f64)>) -> Self {
+        let mut time = 0.0;
+        let frames = frames
+            .into_iter()
+            .map(|(buffer, delay)| {
+                let frame = VideoFrame {
+                    time,
+                    width: buffer.width,
+                    height: buffer.height,
+                    data: buffer.data,
+                };
+                time += delay;
+                frame
+            })
+            .collect::<Vec<_>>();
+        let info = frames
+            .first()
+            .map_or(VideoInfo::default(), |frame| VideoInfo {
+                width: frame.width,
+                height: frame.height,
+                duration: time,
+            });
+        Self {
+            frames,
+            info,
+            index: 0,
+        }
+    }
+}
+
+impl VideoStream for AnimationStream {
+    fn info(&self) -> VideoInfo {
+        self.info
+    }
+    fn next_frame(&mut self) -> Result<Option<VideoFrame>, String> {
+        let frame = self.frames.get(self.index).cloned();
+        self.index += 1;
+        Ok(frame)
+    }
+    fn seek(&mut self, time: f64) -> Result<(), String> {
+        // the last frame which starts before the time
+        self.index = self
+            .frames
+            .iter()
+            .rposition(|frame| frame.time <= time)
+            .unwrap_or_default();
+        Ok(())
+    }
+}
+
+/// `m:ss`, or `h:mm:ss` if longer than an hour
+pub fn format_time(seconds: f64) -> String {
+    let seconds = seconds.max(0.0) as u64;
+    let (h, m, s) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{}:{:02}", m, s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn wav(channels: u16, samples: &[i16]) -> Vec<u8> {
+        let data = samples
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect::<Vec<_>>();
+        let mut wav = b"RIFF".to_vec();
+        wav.extend(((36 + data.len()) as u32).to_le_bytes());
+        wav.extend(b"WAVEfmt ");
+        wav.extend(16_u32.to_le_bytes());
+        wav.extend(1_u16.to_le_bytes());
+        wav.extend(channels.to_le_bytes());
+        wav.extend(8000_u32.to_le_bytes());
+        wav.extend((8000 * 2 * channels as u32).to_le_bytes());
+        wav.extend((2 * channels).to_le_bytes());
+        wav.extend(16_u16.to_le_bytes());
+        // an unknown chunk with odd size is skipped
+        wav.extend(b"LIST");
+        wav.extend(3_u32.to_le_bytes());
+        wav.extend([1, 2, 3, 0]);
+        wav.extend(b"data");
+        wav.extend((data.len() as u32).to_le_bytes());
+        wav.extend(data);
+        wav
+    }
+
+    #[test]
+    fn wav_pcm() {
+        let audio = decode_wav(&wav(2, &[0, 16384, -32768, 0])).unwrap();
+        assert_eq!(audio.sample_rate, 8000);
+        assert_eq!(audio.frames(), 2);
+        assert_eq!(audio.sample(0, 1), 0.5);
+        assert_eq!(audio.sample(1, 0), -1.0);
+        assert_eq!(audio.peaks(2), vec![0.5, 1.0]);
+        assert!(decode_wav(b"RIFF0000WAVX").is_err());
+    }
+
+    #[test]
+    fn wav_truncated() {
+        let wav = wav(1, &[0, 1]);
+        // the fmt chunk ends after 4 of its 16 bytes
+        assert_eq!(
+            decode_wav(&wav[..24]),
+            Err("the fmt chunk is truncated".to_string())
+        );
+        // an extensible fmt chunk without the sub format
+        let mut extensible = wav[..20].to_vec();
+        extensible.extend(0xFFFE_u16.to_le_bytes());
+        extensible.extend(&wav[22..36]);
+        assert_eq!(
+            decode_wav(&extensible),
+            Err("the extensible fmt chunk is truncated".to_string())
+        );
+        for len in 0..wav.len() {
+            let _ = decode_wav(&wav[..len]);
+        }
+    }
+
+    #[test]
+    fn time() {
+        assert_eq!(format_time(0.0), "0:00");
+        assert_eq!(format_time(75.9), "1:15");
+        assert_eq!(format_time(3725.0), "1:02:05");
+    }
+}
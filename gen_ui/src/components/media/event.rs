@@ -0,0 +1,63 @@
+use makepad_widgets::*;
+
+/// events of `GAudio` and `GVideo`
+#[derive(Clone, Debug, DefaultNone)]
+pub enum MediaEvent {
+    Loaded(MediaLoaded),
+    Play(MediaPlay),
+    Pause(MediaPause),
+    Seek(MediaSeek),
+    Ended(MediaEnded),
+    Failed(MediaFailed),
+    Fullscreen(MediaFullscreen),
+    None,
+}
+
+/// the file is decoded, `duration` in seconds
+#[derive(Debug, Clone)]
+pub struct MediaLoaded {
+    pub duration: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MediaPlay {
+    pub time: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MediaPause {
+    pub time: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MediaSeek {
+    pub time: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MediaEnded {
+    pub duration: f64,
+}
+
+/// decoding failed
+#[derive(Debug, Clone)]
+pub struct MediaFailed {
+    pub error: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MediaFullscreen {
+    pub fullscreen: bool,
+}
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum WaveformEvent {
+    Seek(WaveformSeek),
+    None,
+}
+
+/// clicked on the waveform, `progress` in `0.0..=1.0`
+#[derive(Debug, Clone)]
+pub struct WaveformSeek {
+    pub progress: f32,
+}
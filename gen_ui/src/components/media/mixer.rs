@@ -0,0 +1,134 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use makepad_widgets::*;
+
+use super::decoder::AudioData;
+
+/// sample rate of the audio output if the device does not tell it
+const DEFAULT_OUTPUT_SAMPLE_RATE: f64 = 48000.0;
+
+/// a sound played by the mixer
+struct Voice {
+    data: Arc<AudioData>,
+    /// frame of `data`, fractional when the sample rates are different
+    position: f64,
+    playing: bool,
+    ended: bool,
+    volume: f32,
+}
+
+/// # Media Mixer
+/// All `GAudio` and the sound tracks of `GVideo` are mixed into the first audio output,
+/// the output is started when the first voice is added.
+///
+/// The audio thread never waits for the UI thread, if the voices are being changed it outputs
+/// silence for that buffer.
+#[derive(Default)]
+pub struct MediaMixer {
+    voices: Arc<Mutex<HashMap<u64, Voice>>>,
+    started: bool,
+    next_id: u64,
+}
+
+impl MediaMixer {
+    /// add a paused voice, it is removed when the handle is dropped
+    pub fn add(cx: &mut Cx, data: Arc<AudioData>, volume: f32) -> MixerVoice {
+        let mixer = cx.global::<MediaMixer>();
+        mixer.next_id += 1;
+        let id = mixer.next_id;
+        let voices = mixer.voices.clone();
+        voices.lock().unwrap().insert(
+            id,
+            Voice {
+                data,
+                position: 0.0,
+                playing: false,
+                ended: false,
+                volume,
+            },
+        );
+        if !std::mem::replace(&mut mixer.started, true) {
+            let output = voices.clone();
+            cx.audio_output(0, move |info, buffer| {
+                buffer.zero();
+                let Ok(mut voices) = output.try_lock() else {
+                    return;
+                };
+                let (frames, channels) = (buffer.frame_count(), buffer.channel_count());
+                let output_rate = if info.sample_rate > 0.0 {
+                    info.sample_rate
+                } else {
+                    DEFAULT_OUTPUT_SAMPLE_RATE
+                };
+                for voice in voices.values_mut().filter(|voice| voice.playing) {
+                    let step = voice.data.sample_rate as f64 / output_rate;
+                    for frame in 0..frames {
+                        let index = voice.position as usize;
+                        if index >= voice.data.frames() {
+                            voice.playing = false;
+                            voice.ended = true;
+                            break;
+                        }
+                        for channel in 0..channels {
+                            buffer.channel_mut(channel)[frame] +=
+                                voice.data.sample(index, channel) * voice.volume;
+                        }
+                        voice.position += step;
+                    }
+                }
+            });
+        }
+        MixerVoice { voices, id }
+    }
+}
+
+/// handle of a voice in the `MediaMixer`
+pub struct MixerVoice {
+    voices: Arc<Mutex<HashMap<u64, Voice>>>,
+    id: u64,
+}
+
+impl MixerVoice {
+    fn with<R>(&self, f: impl FnOnce(&mut Voice) -> R) -> Option<R> {
+        self.voices.lock().unwrap().get_mut(&self.id).map(f)
+    }
+    pub fn set_playing(&self, playing: bool) -> () {
+        self.with(|voice| {
+            // play again from the start after ended
+            if playing && voice.ended {
+                voice.position = 0.0;
+            }
+            voice.playing = playing;
+            voice.ended = false;
+        });
+    }
+    /// `time` in seconds
+    pub fn seek(&self, time: f64) -> () {
+        self.with(|voice| {
+            voice.position =
+                (time.max(0.0) * voice.data.sample_rate as f64).min(voice.data.frames() as f64);
+            voice.ended = false;
+        });
+    }
+    pub fn set_volume(&self, volume: f32) -> () {
+        self.with(|voice| voice.volume = volume.clamp(0.0, 1.0));
+    }
+    /// current time (seconds) of the voice
+    pub fn time(&self) -> f64 {
+        self.with(|voice| voice.position / voice.data.sample_rate.max(1) as f64)
+            .unwrap_or_default()
+    }
+    /// the voice played to the end, cleared by the next `set_playing` or `seek`
+    pub fn ended(&self) -> bool {
+        self.with(|voice| voice.ended).unwrap_or_default()
+    }
+}
+
+impl Drop for MixerVoice {
+    fn drop(&mut self) {
+        self.voices.lock().unwrap().remove(&self.id);
+    }
+}
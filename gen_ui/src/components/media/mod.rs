@@ -0,0 +1,16 @@
+pub mod audio;
+pub mod decoder;
+mod event;
+pub mod mixer;
+mod register;
+mod threads;
+pub mod video;
+pub mod waveform;
+
+pub use audio::*;
+pub use decoder::*;
+pub use event::*;
+pub use mixer::{MediaMixer, MixerVoice};
+pub use register::register as media_register;
+pub use video::*;
+pub use waveform::*;
@@ -0,0 +1,7 @@
+use makepad_widgets::Cx;
+
+pub fn register(cx: &mut Cx) {
+    crate::components::media::audio::live_design(cx);
+    crate::components::media::video::live_design(cx);
+    crate::components::media::waveform::live_design(cx);
+}
@@ -0,0 +1,28 @@
+use makepad_widgets::*;
+
+/// # Media Threads
+/// Decoder threads shared by `GAudio` and `GVideo`, the pool is created at the first load.
+/// A `GVideo` holds a thread while its src is opened (frames are decoded ahead of the clock),
+/// so the pool has at least 4 threads.
+#[derive(Default)]
+pub struct MediaThreads {
+    pool: Option<TagThreadPool<u64>>,
+}
+
+impl MediaThreads {
+    /// run `f` in the pool, a task of the same widget which is still waiting is replaced
+    pub fn execute<F>(cx: &mut Cx, widget: WidgetUid, f: F) -> ()
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        if cx.global::<MediaThreads>().pool.is_none() {
+            let pool = TagThreadPool::new(cx, cx.cpu_cores().max(4));
+            cx.global::<MediaThreads>().pool = Some(pool);
+        }
+        cx.global::<MediaThreads>()
+            .pool
+            .as_mut()
+            .unwrap()
+            .execute_rev(widget.0, move |_| f());
+    }
+}
@@ -0,0 +1,868 @@
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        mpsc::{Receiver, Sender, SyncSender, TryRecvError},
+    },
+};
+
+use makepad_widgets::{image_cache::ImageBuffer, *};
+
+use crate::{
+    components::{
+        button::GButtonWidgetExt,
+        image::GImageWidgetExt,
+        label::GLabelWidgetExt,
+        slider::{GSliderWidgetExt, SliderEvent},
+        view::GView,
+    },
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+    shader::draw_view::DrawView,
+};
+
+use super::{
+    decoder::{AnimationDecoder, AudioData, VideoDecoder, VideoFrame, VideoInfo, format_time},
+    event::*,
+    mixer::{MediaMixer, MixerVoice},
+    threads::MediaThreads,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GVideoBase = {{GVideo}} {
+        style: {
+            basic: {
+                height: 360.0,
+                width: Fill,
+                flow: Down,
+                background_color: #000000,
+            }
+        }
+    }
+}
+
+/// video player, the template contains:
+/// - `screen`: `GImage` shows the frames
+/// - `play`: `GButton` toggles play and pause
+/// - `progress`: `GSlider` to seek
+/// - `time`: `GLabel` of the current time and the duration
+/// - `volume`: `GSlider` in `0.0..=1.0`
+/// - `fullscreen`: `GButton` toggles fullscreen (covers the window, `Esc` exits)
+///
+/// `src` is a local file opened by the `VideoDecoder`, the default `AnimationDecoder` only plays
+/// animated gif, apng and webp, set a decoder (`set_decoder`) to play real video formats.
+/// frames are decoded in a background thread and uploaded into the texture of `screen`,
+/// the sound track (if any) is played by the mixer and drives the clock
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GVideo {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub src: String,
+    /// play once opened
+    #[live]
+    pub autoplay: bool,
+    /// play again from the start when ended
+    #[live]
+    pub looping: bool,
+    #[live(1.0)]
+    pub volume: f32,
+    #[live(true)]
+    pub event_open: bool,
+    /// text of the `play` button while paused
+    #[live("Play".to_string())]
+    pub play_text: String,
+    /// text of the `play` button while playing
+    #[live("Pause".to_string())]
+    pub pause_text: String,
+    /// text of the `fullscreen` button while not fullscreen
+    #[live("Fullscreen".to_string())]
+    pub fullscreen_text: String,
+    /// text of the `fullscreen` button while fullscreen
+    #[live("Exit".to_string())]
+    pub exit_fullscreen_text: String,
+    #[live]
+    pub draw_backdrop: DrawView,
+    #[live]
+    draw_list: DrawList2d,
+    #[rust]
+    pub decoder: Option<Arc<dyn VideoDecoder>>,
+    #[rust]
+    feed: VideoFeed,
+    /// the src opening or opened
+    #[rust]
+    loaded: String,
+    /// id of the last opening, results of the older ones are dropped
+    #[rust]
+    load: u64,
+    #[rust]
+    info: VideoInfo,
+    #[rust]
+    voice: Option<MixerVoice>,
+    /// current time in seconds
+    #[rust]
+    time: f64,
+    #[rust]
+    playing: bool,
+    /// the progress slider is dragged, do not move it
+    #[rust]
+    seeking: bool,
+    #[rust]
+    fullscreen: bool,
+    #[rust]
+    last_tick: Option<f64>,
+    #[rust]
+    texture: Option<Texture>,
+    #[rust]
+    texture_size: (usize, usize),
+    #[rust]
+    next_frame: NextFrame,
+}
+
+/// posted from the decoder thread when the video is opened
+#[derive(Debug)]
+pub struct VideoOpened {
+    pub video: WidgetUid,
+    pub load: u64,
+    pub result: Result<(VideoInfo, Option<Arc<AudioData>>), String>,
+}
+
+enum VideoCommand {
+    /// `(generation, time)`
+    Seek(u64, f64),
+}
+
+enum VideoPacket {
+    Frame(VideoFrame),
+    End,
+    Error(String),
+}
+
+/// channels between the widget and its decoder thread, the thread stops when it is dropped
+struct VideoPipe {
+    commands: Sender<VideoCommand>,
+    packets: Receiver<(u64, VideoPacket)>,
+}
+
+/// the pipe and the ends used by the decoder thread
+fn video_pipe() -> (
+    VideoPipe,
+    Receiver<VideoCommand>,
+    SyncSender<(u64, VideoPacket)>,
+) {
+    let (commands, command_rx) = std::sync::mpsc::channel();
+    let (packet_tx, packets) = std::sync::mpsc::sync_channel(FRAME_BUFFER);
+    (VideoPipe { commands, packets }, command_rx, packet_tx)
+}
+
+/// frames from the decoder thread waiting to be shown, apart from the widget so it works
+/// without `Cx`
+#[derive(Default)]
+struct VideoFeed {
+    pipe: Option<VideoPipe>,
+    /// frames of older generations (before the last seek) are dropped
+    generation: u64,
+    /// the next packet, waiting for its time
+    pending: Option<VideoPacket>,
+    /// all frames are shown
+    at_end: bool,
+    /// show the next frame even if paused (after opened or seeked)
+    refresh: bool,
+}
+
+impl VideoFeed {
+    fn open(&mut self, pipe: VideoPipe) -> () {
+        *self = Self {
+            pipe: Some(pipe),
+            refresh: true,
+            ..Default::default()
+        };
+    }
+    /// drop the pipe (the decoder thread stops) and the packets
+    fn close(&mut self) -> () {
+        *self = Self::default();
+    }
+    fn is_open(&self) -> bool {
+        self.pipe.is_some()
+    }
+    /// all frames are shown
+    fn ended(&self) -> bool {
+        self.at_end && self.pending.is_none()
+    }
+    /// `time` in seconds
+    fn seek(&mut self, time: f64) -> bool {
+        let Some(pipe) = self.pipe.as_ref() else {
+            return false;
+        };
+        self.generation += 1;
+        let _ = pipe
+            .commands
+            .send(VideoCommand::Seek(self.generation, time));
+        // unblock the decoder thread, the frames before the seek are dropped
+        while pipe.packets.try_recv().is_ok() {}
+        self.pending = None;
+        self.at_end = false;
+        self.refresh = true;
+        true
+    }
+    /// take the packets of the current generation, return the last frame before `time`
+    /// and the error of the decoder
+    fn poll(&mut self, time: f64) -> (Option<VideoFrame>, Option<String>) {
+        let Some(pipe) = self.pipe.as_ref() else {
+            return (None, None);
+        };
+        let mut show = None;
+        let mut error = None;
+        loop {
+            if self.pending.is_none() {
+                match pipe.packets.try_recv() {
+                    Ok((generation, packet)) if generation == self.generation => {
+                        self.pending.replace(packet);
+                    }
+                    Ok(_) => continue,
+                    Err(_) => break,
+                }
+            }
+            match self.pending.take() {
+                Some(VideoPacket::Frame(frame)) if frame.time <= time || self.refresh => {
+                    self.refresh = false;
+                    show.replace(frame);
+                }
+                Some(VideoPacket::Frame(frame)) => {
+                    self.pending.replace(VideoPacket::Frame(frame));
+                    break;
+                }
+                Some(VideoPacket::End) => {
+                    self.refresh = false;
+                    self.at_end = true;
+                    break;
+                }
+                Some(VideoPacket::Error(e)) => {
+                    self.refresh = false;
+                    error.replace(e);
+                    break;
+                }
+                None => break,
+            }
+        }
+        (show, error)
+    }
+}
+
+/// open the src and decode frames until the pipe is dropped, `opened` gets the result of opening
+fn decode_video<F>(
+    decoder: Arc<dyn VideoDecoder>,
+    src: String,
+    command_rx: Receiver<VideoCommand>,
+    packet_tx: SyncSender<(u64, VideoPacket)>,
+    opened: F,
+) -> ()
+where
+    F: FnOnce(Result<(VideoInfo, Option<Arc<AudioData>>), String>),
+{
+    let mut stream = match decoder.open(Path::new(&src)) {
+        Ok(stream) => stream,
+        Err(e) => {
+            opened(Err(e));
+            return;
+        }
+    };
+    let audio = stream.audio().map(Arc::new);
+    opened(Ok((stream.info(), audio)));
+    let mut generation = 0;
+    let mut ended = false;
+    loop {
+        // wait for a seek at the end, check it between the frames while decoding
+        let command = if ended {
+            match command_rx.recv() {
+                Ok(command) => Some(command),
+                Err(_) => return,
+            }
+        } else {
+            match command_rx.try_recv() {
+                Ok(command) => Some(command),
+                Err(TryRecvError::Empty) => None,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        };
+        if let Some(VideoCommand::Seek(seek_generation, time)) = command {
+            generation = seek_generation;
+            ended = false;
+            if let Err(e) = stream.seek(time) {
+                ended = true;
+                if packet_tx.send((generation, VideoPacket::Error(e))).is_err() {
+                    return;
+                }
+            }
+            continue;
+        }
+        let packet = match stream.next_frame() {
+            Ok(Some(frame)) => VideoPacket::Frame(frame),
+            Ok(None) => {
+                ended = true;
+                VideoPacket::End
+            }
+            Err(e) => {
+                ended = true;
+                VideoPacket::Error(e)
+            }
+        };
+        // blocks while the buffer is full, the widget drains it when seeking
+        if packet_tx.send((generation, packet)).is_err() {
+            return;
+        }
+    }
+}
+
+fn spawn_decode(
+    cx: &mut Cx,
+    decoder: Arc<dyn VideoDecoder>,
+    video: WidgetUid,
+    load: u64,
+    src: String,
+) -> VideoPipe {
+    let (pipe, command_rx, packet_tx) = video_pipe();
+    MediaThreads::execute(cx, video, move || {
+        decode_video(decoder, src, command_rx, packet_tx, |result| {
+            Cx::post_action(VideoOpened {
+                video,
+                load,
+                result,
+            });
+        });
+    });
+    pipe
+}
+
+inherits_view_widget_node!(GVideo);
+
+impl Widget for GVideo {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        if !self.fullscreen {
+            return self.deref_widget.draw_walk(cx, scope, walk);
+        }
+        // keep the place in the layout, draw over the window
+        cx.walk_turtle(walk);
+        self.draw_list.begin_overlay_reuse(cx);
+        cx.begin_pass_sized_turtle(Layout::flow_down());
+        let size = cx.current_pass_size();
+        self.draw_backdrop.begin(
+            cx,
+            Walk {
+                abs_pos: Some(DVec2::default()),
+                width: Size::Fixed(size.x),
+                height: Size::Fixed(size.y),
+                ..Default::default()
+            },
+            Layout::default(),
+        );
+        let _ = self.deref_widget.draw_walk(
+            cx,
+            scope,
+            Walk {
+                width: Size::Fill,
+                height: Size::Fill,
+                ..Default::default()
+            },
+        );
+        self.draw_backdrop.end(cx);
+        cx.end_pass_sized_turtle();
+        self.draw_list.end(cx);
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        if let Event::Actions(actions) = event {
+            for action in actions {
+                if let Some(VideoOpened {
+                    video,
+                    load,
+                    result,
+                }) = action.downcast_ref()
+                {
+                    if *video == self.widget_uid() && *load == self.load {
+                        self.handle_opened(cx, result);
+                    }
+                }
+            }
+        }
+        if let Some(e) = self.next_frame.is_event(event) {
+            self.tick(cx, e.time);
+        }
+        if let Event::KeyDown(e) = event {
+            if self.fullscreen && e.key_code == KeyCode::Escape {
+                self.set_fullscreen(cx, false);
+            }
+        }
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if self.gbutton(id!(play)).clicked(&actions).is_some() {
+            self.toggle(cx);
+        }
+        if self.gbutton(id!(fullscreen)).clicked(&actions).is_some() {
+            self.set_fullscreen(cx, !self.fullscreen);
+        }
+        match actions
+            .find_widget_action(self.gslider(id!(progress)).widget_uid())
+            .cast()
+        {
+            SliderEvent::FingerDown(_) => self.seeking = true,
+            SliderEvent::FingerUp(_) => self.seeking = false,
+            SliderEvent::Changed(e) => {
+                self.seeking = false;
+                self.seek(cx, e.value);
+            }
+            _ => {}
+        }
+        if let SliderEvent::Changed(e) = actions
+            .find_widget_action(self.gslider(id!(volume)).widget_uid())
+            .cast()
+        {
+            self.set_volume(cx, e.value as f32);
+        }
+    }
+}
+
+impl LiveHook for GVideo {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GVideo {
+    fn render(&mut self, cx: &mut Cx) -> () {
+        if let Some(mut volume) = self.gslider(id!(volume)).borrow_mut() {
+            volume.value = self.volume;
+        }
+        if self.src != self.loaded {
+            self.load(cx);
+        }
+        self.sync(cx);
+    }
+    /// open the `src` in the decoder thread, the thread of the last src stops
+    fn load(&mut self, cx: &mut Cx) -> () {
+        self.feed.close();
+        self.voice = None;
+        self.playing = false;
+        self.time = 0.0;
+        self.info = VideoInfo::default();
+        self.loaded = self.src.to_string();
+        self.load += 1;
+        if self.src.is_empty() {
+            return;
+        }
+        let decoder = self
+            .decoder
+            .clone()
+            .unwrap_or_else(|| Arc::new(AnimationDecoder));
+        let pipe = spawn_decode(
+            cx,
+            decoder,
+            self.widget_uid(),
+            self.load,
+            self.src.to_string(),
+        );
+        self.feed.open(pipe);
+        self.next_frame = cx.new_next_frame();
+    }
+    fn handle_opened(
+        &mut self,
+        cx: &mut Cx,
+        result: &Result<(VideoInfo, Option<Arc<AudioData>>), String>,
+    ) -> () {
+        match result {
+            Ok((info, audio)) => {
+                self.info = *info;
+                if let Some(mut progress) = self.gslider(id!(progress)).borrow_mut() {
+                    progress.min = 0.0;
+                    progress.max = info.duration as f32;
+                    progress.value = 0.0;
+                }
+                self.voice = audio
+                    .as_ref()
+                    .map(|audio| MediaMixer::add(cx, audio.clone(), self.volume));
+                self.active(
+                    cx,
+                    MediaEvent::Loaded(MediaLoaded {
+                        duration: info.duration,
+                    }),
+                );
+                if self.autoplay {
+                    self.play(cx);
+                }
+            }
+            Err(error) => self.fail(cx, error),
+        }
+        self.sync(cx);
+    }
+    fn fail(&mut self, cx: &mut Cx, error: &str) -> () {
+        error!("GVideo can not decode {}: {}", self.loaded, error);
+        // stop the decoder thread and the frame requests
+        self.feed.close();
+        self.next_frame = NextFrame::default();
+        self.playing = false;
+        self.voice.as_ref().map(|voice| voice.set_playing(false));
+        self.active(
+            cx,
+            MediaEvent::Failed(MediaFailed {
+                error: error.to_string(),
+            }),
+        );
+    }
+    /// move the clock and show the frames due
+    fn tick(&mut self, cx: &mut Cx, now: f64) -> () {
+        if !self.playing && !self.feed.refresh {
+            return;
+        }
+        if self.playing {
+            let delta = self.last_tick.map_or(0.0, |last| now - last);
+            // the sound track drives the clock
+            self.time = match self.voice.as_ref() {
+                Some(voice) => voice.time(),
+                None => self.time + delta,
+            };
+            self.last_tick = Some(now);
+        }
+        self.present(cx);
+        let ended = self.feed.ended() && self.voice.as_ref().map_or(true, |voice| voice.ended());
+        if self.playing && ended {
+            if self.looping {
+                self.seek(cx, 0.0);
+                self.voice.as_ref().map(|voice| voice.set_playing(true));
+            } else {
+                self.playing = false;
+                self.time = self.info.duration;
+                self.active(
+                    cx,
+                    MediaEvent::Ended(MediaEnded {
+                        duration: self.info.duration,
+                    }),
+                );
+            }
+        }
+        self.sync(cx);
+        if self.playing || self.feed.refresh {
+            self.next_frame = cx.new_next_frame();
+        }
+    }
+    /// show the last frame before the clock
+    fn present(&mut self, cx: &mut Cx) -> () {
+        let (show, error) = self.feed.poll(self.time);
+        if let Some(frame) = show {
+            self.show_frame(cx, frame);
+        }
+        if let Some(e) = error {
+            self.fail(cx, &e);
+        }
+    }
+    /// upload the frame into the texture of the screen
+    fn show_frame(&mut self, cx: &mut Cx, mut frame: VideoFrame) -> () {
+        let size = (frame.width, frame.height);
+        match self.texture.as_ref() {
+            Some(texture) if self.texture_size == size => {
+                texture.swap_vec_u32(cx, &mut frame.data);
+            }
+            _ => {
+                self.texture.replace(
+                    ImageBuffer {
+                        width: frame.width,
+                        height: frame.height,
+                        data: frame.data,
+                        animation: None,
+                    }
+                    .into_new_texture(cx),
+                );
+                self.texture_size = size;
+            }
+        }
+        self.gimage(id!(screen))
+            .set_texture(cx, self.texture.clone());
+        self.redraw(cx);
+    }
+    fn active(&mut self, cx: &mut Cx, e: MediaEvent) -> () {
+        if !self.event_open {
+            return;
+        }
+        self.scope_path.as_ref().map(|path| {
+            cx.widget_action(self.widget_uid(), path, e);
+        });
+    }
+    fn sync(&mut self, cx: &mut Cx) -> () {
+        let play_text = if self.playing {
+            &self.pause_text
+        } else {
+            &self.play_text
+        };
+        let _ = self.gbutton(id!(play)).set_text(cx, play_text);
+        let fullscreen_text = if self.fullscreen {
+            &self.exit_fullscreen_text
+        } else {
+            &self.fullscreen_text
+        };
+        let _ = self.gbutton(id!(fullscreen)).set_text(cx, fullscreen_text);
+        let _ = self.glabel(id!(time)).set_text(
+            cx,
+            format!(
+                "{} / {}",
+                format_time(self.time),
+                format_time(self.info.duration)
+            ),
+        );
+        if !self.seeking {
+            if let Some(mut slider) = self.gslider(id!(progress)).borrow_mut() {
+                slider.value = self.time as f32;
+            }
+        }
+        self.redraw(cx);
+    }
+    pub fn set_src(&mut self, cx: &mut Cx, src: &str) -> () {
+        self.src = src.to_string();
+        self.render(cx);
+    }
+    /// set the decoder and open the `src` again
+    pub fn set_decoder<D>(&mut self, cx: &mut Cx, decoder: D) -> ()
+    where
+        D: VideoDecoder,
+    {
+        self.decoder.replace(Arc::new(decoder));
+        self.loaded.clear();
+        self.render(cx);
+    }
+    pub fn play(&mut self, cx: &mut Cx) -> () {
+        if self.playing || !self.feed.is_open() {
+            return;
+        }
+        // play again from the start after ended
+        if self.feed.ended() {
+            self.seek(cx, 0.0);
+        }
+        self.playing = true;
+        self.last_tick = None;
+        self.voice.as_ref().map(|voice| voice.set_playing(true));
+        self.next_frame = cx.new_next_frame();
+        self.active(cx, MediaEvent::Play(MediaPlay { time: self.time }));
+        self.sync(cx);
+    }
+    pub fn pause(&mut self, cx: &mut Cx) -> () {
+        if !self.playing {
+            return;
+        }
+        self.playing = false;
+        self.voice.as_ref().map(|voice| voice.set_playing(false));
+        self.active(cx, MediaEvent::Pause(MediaPause { time: self.time }));
+        self.sync(cx);
+    }
+    pub fn toggle(&mut self, cx: &mut Cx) -> () {
+        if self.playing {
+            self.pause(cx);
+        } else {
+            self.play(cx);
+        }
+    }
+    /// `time` in seconds
+    pub fn seek(&mut self, cx: &mut Cx, time: f64) -> () {
+        let time = time.clamp(0.0, self.info.duration);
+        if !self.feed.seek(time) {
+            return;
+        }
+        self.time = time;
+        self.voice.as_ref().map(|voice| voice.seek(time));
+        self.next_frame = cx.new_next_frame();
+        self.active(cx, MediaEvent::Seek(MediaSeek { time }));
+        self.sync(cx);
+    }
+    /// `volume` in `0.0..=1.0`
+    pub fn set_volume(&mut self, cx: &mut Cx, volume: f32) -> () {
+        self.volume = volume.clamp(0.0, 1.0);
+        self.voice
+            .as_ref()
+            .map(|voice| voice.set_volume(self.volume));
+        if let Some(mut slider) = self.gslider(id!(volume)).borrow_mut() {
+            slider.value = self.volume;
+        }
+        self.redraw(cx);
+    }
+    pub fn set_fullscreen(&mut self, cx: &mut Cx, fullscreen: bool) -> () {
+        if self.fullscreen == fullscreen {
+            return;
+        }
+        self.fullscreen = fullscreen;
+        if fullscreen {
+            cx.set_key_focus(self.area());
+        }
+        self.active(cx, MediaEvent::Fullscreen(MediaFullscreen { fullscreen }));
+        self.sync(cx);
+    }
+    /// current time in seconds
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+    pub fn info(&self) -> VideoInfo {
+        self.info
+    }
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+    pub fn is_fullscreen(&self) -> bool {
+        self.fullscreen
+    }
+    event_option! {
+        loaded: MediaEvent::Loaded => MediaLoaded,
+        played: MediaEvent::Play => MediaPlay,
+        paused: MediaEvent::Pause => MediaPause,
+        seeked: MediaEvent::Seek => MediaSeek,
+        ended: MediaEvent::Ended => MediaEnded,
+        failed: MediaEvent::Failed => MediaFailed,
+        fullscreen_changed: MediaEvent::Fullscreen => MediaFullscreen
+    }
+}
+
+impl GVideoRef {
+    pub fn set_src(&self, cx: &mut Cx, src: &str) -> () {
+        self.borrow_mut().map(|mut c| c.set_src(cx, src));
+    }
+    pub fn set_decoder<D>(&self, cx: &mut Cx, decoder: D) -> ()
+    where
+        D: VideoDecoder,
+    {
+        self.borrow_mut().map(|mut c| c.set_decoder(cx, decoder));
+    }
+    pub fn play(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.play(cx));
+    }
+    pub fn pause(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.pause(cx));
+    }
+    pub fn toggle(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.toggle(cx));
+    }
+    pub fn seek(&self, cx: &mut Cx, time: f64) -> () {
+        self.borrow_mut().map(|mut c| c.seek(cx, time));
+    }
+    pub fn set_volume(&self, cx: &mut Cx, volume: f32) -> () {
+        self.borrow_mut().map(|mut c| c.set_volume(cx, volume));
+    }
+    pub fn set_fullscreen(&self, cx: &mut Cx, fullscreen: bool) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.set_fullscreen(cx, fullscreen));
+    }
+    pub fn time(&self) -> f64 {
+        self.borrow().map_or(0.0, |c| c.time())
+    }
+    pub fn info(&self) -> VideoInfo {
+        self.borrow().map_or(VideoInfo::default(), |c| c.info())
+    }
+    pub fn is_playing(&self) -> bool {
+        self.borrow().map_or(false, |c| c.is_playing())
+    }
+    pub fn is_fullscreen(&self) -> bool {
+        self.borrow().map_or(false, |c| c.is_fullscreen())
+    }
+    event_option_ref! {
+        loaded => MediaLoaded,
+        played => MediaPlay,
+        paused => MediaPause,
+        seeked => MediaSeek,
+        ended => MediaEnded,
+        failed => MediaFailed,
+        fullscreen_changed => MediaFullscreen
+    }
+}
+
+/// decoded frames waiting to be shown
+const FRAME_BUFFER: usize = 8;
+
+#[cfg(test)]
+mod test {
+    use std::{sync::mpsc::channel, time::Duration};
+
+    use super::super::decoder::VideoStream;
+    use super::*;
+
+    /// opened, but every frame fails
+    struct BrokenStream;
+
+    impl VideoStream for BrokenStream {
+        fn info(&self) -> VideoInfo {
+            VideoInfo::default()
+        }
+        fn next_frame(&mut self) -> Result<Option<VideoFrame>, String> {
+            Err("broken frame".to_string())
+        }
+        fn seek(&mut self, _time: f64) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    /// run the decoder in a thread, return the feed and the result of opening
+    fn open(
+        decoder: Arc<dyn VideoDecoder>,
+    ) -> (
+        VideoFeed,
+        Result<(VideoInfo, Option<Arc<AudioData>>), String>,
+        std::thread::JoinHandle<()>,
+    ) {
+        let (pipe, command_rx, packet_tx) = video_pipe();
+        let (opened_tx, opened_rx) = channel();
+        let decoding = std::thread::spawn(move || {
+            decode_video(
+                decoder,
+                "broken.mp4".to_string(),
+                command_rx,
+                packet_tx,
+                |result| {
+                    let _ = opened_tx.send(result);
+                },
+            );
+        });
+        let mut feed = VideoFeed::default();
+        feed.open(pipe);
+        let opened = opened_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        (feed, opened, decoding)
+    }
+
+    #[test]
+    fn open_failed() {
+        let decoder: Arc<dyn VideoDecoder> =
+            Arc::new(|_: &Path| -> Result<Box<dyn VideoStream>, String> {
+                Err("not an animation".to_string())
+            });
+        let (mut feed, opened, decoding) = open(decoder);
+        assert_eq!(opened.unwrap_err(), "not an animation");
+        // waits for the first frame until the widget fails
+        assert!(feed.refresh);
+        feed.close();
+        assert!(!feed.refresh);
+        assert!(!feed.is_open());
+        assert!(feed.pending.is_none());
+        decoding.join().unwrap();
+    }
+
+    #[test]
+    fn frame_failed() {
+        let decoder: Arc<dyn VideoDecoder> =
+            Arc::new(|_: &Path| -> Result<Box<dyn VideoStream>, String> {
+                Ok(Box::new(BrokenStream))
+            });
+        let (mut feed, opened, decoding) = open(decoder);
+        assert!(opened.is_ok());
+        let mut error = None;
+        for _ in 0..500 {
+            if let (_, Some(e)) = feed.poll(0.0) {
+                error.replace(e);
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(error.as_deref(), Some("broken frame"));
+        assert!(!feed.refresh);
+        // the decoder thread waits for a seek until the pipe is dropped
+        feed.close();
+        assert!(!feed.is_open());
+        decoding.join().unwrap();
+    }
+}
@@ -0,0 +1,160 @@
+use makepad_widgets::*;
+
+use crate::{event_option, event_option_ref, shader::draw_view::DrawView, themes::Theme, visible};
+
+use super::event::{WaveformEvent, WaveformSeek};
+
+live_design! {
+    link genui_basic;
+
+    pub GWaveformBase = {{GWaveform}} {}
+}
+
+/// the peaks of the audio drawn as bars, the played part is colored by `theme`,
+/// click or drag on it to seek
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GWaveform {
+    #[live]
+    pub theme: Theme,
+    #[live(3.0)]
+    pub bar_width: f64,
+    #[live(2.0)]
+    pub bar_spacing: f64,
+    #[live(40.0)]
+    pub height: f64,
+    #[live(true)]
+    pub visible: bool,
+    #[live(true)]
+    pub event_open: bool,
+    #[live]
+    pub draw_bar: DrawView,
+    /// played part in `0.0..=1.0`
+    #[live]
+    pub progress: f32,
+    /// max amplitude of each bar in `0.0..=1.0`
+    #[rust]
+    peaks: Vec<f32>,
+    #[rust]
+    area: Area,
+}
+
+impl WidgetNode for GWaveform {
+    fn uid_to_widget(&self, _uid: WidgetUid) -> WidgetRef {
+        WidgetRef::empty()
+    }
+
+    fn find_widgets(&self, _path: &[LiveId], _cached: WidgetCache, _results: &mut WidgetSet) {
+        ()
+    }
+
+    fn walk(&mut self, _cx: &mut Cx) -> Walk {
+        Walk {
+            width: Size::Fill,
+            height: Size::Fixed(self.height),
+            ..Default::default()
+        }
+    }
+
+    fn area(&self) -> Area {
+        self.area
+    }
+
+    fn redraw(&mut self, cx: &mut Cx) {
+        self.area.redraw(cx);
+    }
+
+    visible!();
+}
+
+impl Widget for GWaveform {
+    fn draw_walk(&mut self, cx: &mut Cx2d, _scope: &mut Scope, walk: Walk) -> DrawStep {
+        if !self.visible {
+            return DrawStep::done();
+        }
+        cx.begin_turtle(walk, Layout::default());
+        let rect = cx.turtle().rect();
+        let step = self.bar_width + self.bar_spacing;
+        let count = ((rect.size.x + self.bar_spacing) / step).max(0.0) as usize;
+        let played = (self.progress.clamp(0.0, 1.0) as f64 * count as f64) as usize;
+        for index in 0..count {
+            // resample the peaks to the bars
+            let peak = if self.peaks.is_empty() {
+                0.0
+            } else {
+                self.peaks[index * self.peaks.len() / count] as f64
+            };
+            let height = (peak * rect.size.y).max(self.bar_width);
+            self.draw_bar.background_color = if index < played {
+                self.theme.color(500).into()
+            } else {
+                Theme::Dark.color(500).into()
+            };
+            self.draw_bar.draw_abs(
+                cx,
+                Rect {
+                    pos: dvec2(
+                        rect.pos.x + index as f64 * step,
+                        rect.pos.y + (rect.size.y - height) * 0.5,
+                    ),
+                    size: dvec2(self.bar_width, height),
+                },
+            );
+        }
+        cx.end_turtle_with_area(&mut self.area);
+        DrawStep::done()
+    }
+
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible {
+            return;
+        }
+        match event.hits(cx, self.area) {
+            Hit::FingerHoverIn(_) => cx.set_cursor(MouseCursor::Hand),
+            Hit::FingerHoverOut(_) => cx.set_cursor(MouseCursor::Default),
+            Hit::FingerDown(e) => self.seek_to(cx, &scope.path, e.abs, e.rect),
+            Hit::FingerMove(e) => self.seek_to(cx, &scope.path, e.abs, e.rect),
+            _ => {}
+        }
+    }
+}
+
+impl LiveHook for GWaveform {}
+
+impl GWaveform {
+    fn seek_to(&mut self, cx: &mut Cx, path: &HeapLiveIdPath, abs: DVec2, rect: Rect) -> () {
+        let progress = ((abs.x - rect.pos.x) / rect.size.x.max(1.0)).clamp(0.0, 1.0) as f32;
+        self.set_progress(cx, progress);
+        if self.event_open {
+            cx.widget_action(
+                self.widget_uid(),
+                path,
+                WaveformEvent::Seek(WaveformSeek { progress }),
+            );
+        }
+    }
+    pub fn set_peaks(&mut self, cx: &mut Cx, peaks: Vec<f32>) -> () {
+        self.peaks = peaks;
+        self.redraw(cx);
+    }
+    pub fn set_progress(&mut self, cx: &mut Cx, progress: f32) -> () {
+        if self.progress != progress {
+            self.progress = progress;
+            self.redraw(cx);
+        }
+    }
+    event_option! {
+        seek: WaveformEvent::Seek => WaveformSeek
+    }
+}
+
+impl GWaveformRef {
+    pub fn set_peaks(&self, cx: &mut Cx, peaks: Vec<f32>) -> () {
+        self.borrow_mut().map(|mut c| c.set_peaks(cx, peaks));
+    }
+    pub fn set_progress(&self, cx: &mut Cx, progress: f32) -> () {
+        self.borrow_mut().map(|mut c| c.set_progress(cx, progress));
+    }
+    event_option_ref! {
+        seek => WaveformSeek
+    }
+}
@@ -0,0 +1,89 @@
+use makepad_widgets::*;
+
+use super::GSubMenuWidgetRefExt;
+
+/// turn the sub menus into flyouts, the top level ones drop down from the menu bar,
+/// `bar: false` turns them back into inline sub menus
+pub(crate) fn nested_menu_bar(child: &WidgetRef, bar: bool, top: bool) {
+    if let Some(mut sub) = child.as_gsub_menu().borrow_mut() {
+        sub.flyout = bar;
+        sub.menu_bar = bar && top;
+        for (_, sub_child) in sub.body.children.iter() {
+            nested_menu_bar(sub_child, bar, false);
+        }
+    }
+}
+
+/// top level sub menu whose header is under the position
+pub(crate) fn find_bar_menu_at(
+    children: &[(LiveId, WidgetRef)],
+    cx: &Cx,
+    pos: DVec2,
+) -> Option<String> {
+    children.iter().find_map(|(_, child)| {
+        child.as_gsub_menu().borrow().and_then(|sub| {
+            (sub.visible && !sub.disabled && sub.area_header().rect(cx).contains(pos))
+                .then(|| sub.value.to_string())
+        })
+    })
+}
+
+/// top level sub menu of the mnemonic (ignore case)
+pub(crate) fn find_mnemonic(children: &[(LiveId, WidgetRef)], c: char) -> Option<String> {
+    children.iter().find_map(|(_, child)| {
+        child.as_gsub_menu().borrow().and_then(|sub| {
+            (sub.visible
+                && !sub.disabled
+                && sub
+                    .mnemonic
+                    .chars()
+                    .next()
+                    .map_or(false, |m| m.eq_ignore_ascii_case(&c)))
+            .then(|| sub.value.to_string())
+        })
+    })
+}
+
+/// character of a letter or digit key
+pub(crate) fn key_char(key_code: KeyCode) -> Option<char> {
+    let c = match key_code {
+        KeyCode::KeyA => 'a',
+        KeyCode::KeyB => 'b',
+        KeyCode::KeyC => 'c',
+        KeyCode::KeyD => 'd',
+        KeyCode::KeyE => 'e',
+        KeyCode::KeyF => 'f',
+        KeyCode::KeyG => 'g',
+        KeyCode::KeyH => 'h',
+        KeyCode::KeyI => 'i',
+        KeyCode::KeyJ => 'j',
+        KeyCode::KeyK => 'k',
+        KeyCode::KeyL => 'l',
+        KeyCode::KeyM => 'm',
+        KeyCode::KeyN => 'n',
+        KeyCode::KeyO => 'o',
+        KeyCode::KeyP => 'p',
+        KeyCode::KeyQ => 'q',
+        KeyCode::KeyR => 'r',
+        KeyCode::KeyS => 's',
+        KeyCode::KeyT => 't',
+        KeyCode::KeyU => 'u',
+        KeyCode::KeyV => 'v',
+        KeyCode::KeyW => 'w',
+        KeyCode::KeyX => 'x',
+        KeyCode::KeyY => 'y',
+        KeyCode::KeyZ => 'z',
+        KeyCode::Key0 => '0',
+        KeyCode::Key1 => '1',
+        KeyCode::Key2 => '2',
+        KeyCode::Key3 => '3',
+        KeyCode::Key4 => '4',
+        KeyCode::Key5 => '5',
+        KeyCode::Key6 => '6',
+        KeyCode::Key7 => '7',
+        KeyCode::Key8 => '8',
+        KeyCode::Key9 => '9',
+        _ => return None,
+    };
+    Some(c)
+}
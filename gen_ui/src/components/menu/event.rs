@@ -30,6 +30,7 @@ pub enum MenuEvent {
     HoverIn(MenuHoverIn),
     HoverOut(MenuHoverOut),
     Changed(MenuChanged),
+    Selected(MenuSelected),
     None,
 }
 
@@ -50,6 +51,13 @@ pub struct MenuChanged {
     pub active: Option<String>,
 }
 
+/// a menu item is clicked or activated by keyboard (emitted even if it is already active)
+#[derive(Clone, Debug)]
+pub struct MenuSelected {
+    pub meta: Option<FingerUpEvent>,
+    pub value: String,
+}
+
 #[derive(Debug, Clone, DefaultNone)]
 pub enum SubMenuEvent {
     // HoverIn(SubMenuHoverIn),
@@ -15,7 +15,7 @@ use crate::{
     prop::{
         ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, ApplySlotMergeImpl, DeferWalks, SlotDrawer,
         ToSlotMap, ToStateMap,
-        manuel::{ACTIVE, BASIC, DISABLED, HOVER},
+        manuel::{ACTIVE, BASIC, DISABLED, HOVER, SECONDARY},
         traits::ToFloat,
     },
     pure_after_apply, set_animation, set_index, set_scope_path, setter,
@@ -99,6 +99,9 @@ pub struct GMenuItem {
     pub text: GLabel,
     #[live]
     pub extra: GView,
+    /// shortcut hint (such as `Ctrl+C`) at the end of the item, hidden if text is empty
+    #[live]
+    pub shortcut: GLabel,
     #[live]
     pub draw_item: DrawView,
     // --- animator ----------------
@@ -123,6 +126,9 @@ pub struct GMenuItem {
     pub active: bool,
     #[live]
     pub value: String,
+    /// only the icon is drawn (collapsed `GMenu`), set by `GMenu::set_collapsed`
+    #[rust]
+    pub collapsed: bool,
 }
 
 impl WidgetNode for GMenuItem {
@@ -163,6 +169,9 @@ impl WidgetNode for GMenuItem {
         if self.extra.visible {
             self.extra.redraw(cx);
         }
+        if self.has_shortcut() {
+            self.shortcut.redraw(cx);
+        }
     }
 
     fn state(&self) -> String {
@@ -187,15 +196,38 @@ impl Widget for GMenuItem {
 
         let _ = self.draw_item.begin(cx, walk, style.layout());
 
-        let _ = SlotDrawer::new(
-            [
-                (live_id!(icon), (&mut self.icon).into()),
-                (live_id!(text), (&mut self.text).into()),
-                (live_id!(extra), (&mut self.extra).into()),
-            ],
-            &mut self.defer_walks,
-        )
-        .draw_walk(cx, scope);
+        if self.collapsed {
+            let icon_walk = self.icon.walk(cx);
+            let _ = self.icon.draw_walk(cx, scope, icon_walk);
+        } else {
+            let _ = SlotDrawer::new(
+                [
+                    (live_id!(icon), (&mut self.icon).into()),
+                    (live_id!(text), (&mut self.text).into()),
+                    (live_id!(extra), (&mut self.extra).into()),
+                ],
+                &mut self.defer_walks,
+            )
+            .draw_walk(cx, scope);
+        }
+
+        if self.has_shortcut() {
+            // push the shortcut to the end
+            cx.begin_turtle(
+                Walk {
+                    width: Size::Fill,
+                    height: Size::Fit,
+                    ..Default::default()
+                },
+                Layout {
+                    align: Align { x: 1.0, y: 0.5 },
+                    ..Default::default()
+                },
+            );
+            let shortcut_walk = self.shortcut.walk(cx);
+            let _ = self.shortcut.draw_walk(cx, scope, shortcut_walk);
+            cx.end_turtle();
+        }
 
         self.draw_item.end(cx);
         self.set_scope_path(&scope.path);
@@ -225,7 +257,11 @@ impl Widget for GMenuItem {
 
         let area = self.area();
         let hit = event.hits(cx, area);
-        self.handle_widget_event(cx, event, hit, area);
+        if self.disabled {
+            self.handle_when_disabled(cx, event, hit);
+        } else {
+            self.handle_widget_event(cx, event, hit, area);
+        }
     }
 }
 
@@ -309,6 +345,8 @@ impl Component for GMenuItem {
         let _ = self.icon.render(cx)?;
         let _ = self.text.render(cx)?;
         let _ = self.extra.render(cx)?;
+        // the shortcut is muted text of the current theme
+        self.shortcut.set_default_font_color(cx, SECONDARY);
         Ok(())
     }
 
@@ -628,6 +666,26 @@ impl GMenuItem {
         }
         self.active_clicked(cx, None);
     }
+    fn has_shortcut(&self) -> bool {
+        !self.collapsed && self.shortcut.visible && !self.shortcut.text.as_ref().is_empty()
+    }
+    pub fn set_collapsed(&mut self, cx: &mut Cx, collapsed: bool) {
+        self.collapsed = collapsed;
+        self.redraw(cx);
+    }
+    /// highlight the item like hovering, used by keyboard navigation
+    pub fn set_focus(&mut self, cx: &mut Cx, focus: bool) {
+        if self.active || self.disabled {
+            return;
+        }
+        if focus {
+            self.switch_state_with_animation(cx, MenuItemState::Hover);
+            self.play_animation(cx, id!(hover.on));
+        } else {
+            self.switch_state_with_animation(cx, MenuItemState::Basic);
+            self.play_animation(cx, id!(hover.off));
+        }
+    }
     pub fn generate_value(&mut self, index_chain: &Vec<usize>) {
         if self.value.is_empty() {
             self.value = index_chain
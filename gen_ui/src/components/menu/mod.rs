@@ -1,5 +1,7 @@
+mod bar;
 mod event;
 mod item;
+mod nav;
 mod prop;
 mod register;
 mod sub;
@@ -15,28 +17,51 @@ use makepad_widgets::*;
 use crate::{
     ComponentAnInit, active_event, area, area_ref,
     components::{
+        divider::GDividerWidgetRefExt,
         lifecycle::LifeCycle,
         traits::{BasicStyle, Component, SlotComponent, SlotStyle, Style},
         view::{GView, ViewBasicStyle},
     },
     error::Error,
-    event_option, event_option_ref, getter_setter_ref, lifecycle,
+    event_option, event_option_ref, getter, getter_setter_ref, lifecycle,
     prop::{
-        ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, DeferWalks, MenuItemMode, SlotDrawer,
-        ToStateMap, manuel::BASIC,
+        ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, DeferWalks, MenuItemMode, MenuMode,
+        SlotDrawer, ToStateMap, manuel::BASIC,
     },
-    pure_after_apply, set_index, set_scope_path,
+    pure_after_apply, set_index, set_scope_path, setter,
     shader::draw_view::DrawView,
     sync,
     themes::conf::Conf,
     visible,
 };
 
+use nav::NavStep;
+
 live_design! {
     link genui_basic;
     use link::genui_animation_prop::*;
 
-    pub GMenuBase = {{GMenu}}{}
+    pub GMenuBase = {{GMenu}}{
+        animator: {
+            collapse = {
+                default: off
+                off = {
+                    from: {all: Forward {duration: (AN_DURATION)}}
+                    ease: ExpDecay {d1: 0.96, d2: 0.97}
+                    apply: {
+                        collapse_fold: [{time: 0.0, value: 1.0}, {time: 1.0, value: 0.0}]
+                    }
+                }
+                on = {
+                    from: {all: Forward {duration: (AN_DURATION)}}
+                    ease: ExpDecay {d1: 0.98, d2: 0.95}
+                    apply: {
+                        collapse_fold: [{time: 0.0, value: 0.0}, {time: 1.0, value: 1.0}]
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
@@ -68,10 +93,36 @@ pub struct GMenu {
     pub footer: GView,
     #[live]
     pub active: Option<String>,
+    /// `Horizontal`: menu bar, all sub menus become flyouts and the top level ones drop down.
+    /// top level items should use `Fit` width
+    #[live]
+    pub mode: MenuMode,
+    /// rail mode of a vertical menu: only icons of the top level items are shown
+    /// and sub menus open as flyouts
+    #[live]
+    pub collapsed: bool,
+    #[live(64.0)]
+    pub collapsed_width: f64,
+    /// 0.0: expanded, 1.0: collapsed (animated)
+    #[live]
+    pub collapse_fold: f64,
+    /// width before collapsing when the menu does not have a fixed width
+    #[rust]
+    expanded_width: f64,
+    /// the menu bar layout has been applied by `apply_mode`
+    #[rust]
+    bar_applied: bool,
+    #[animator]
+    pub animator: Animator,
     #[rust]
     pub item_modes: Vec<MenuItemMode>,
+    /// keyboard navigation: up/down move between items, right/left open/close sub menus,
+    /// enter/space select the focused item
     #[live(true)]
     pub event_key: bool,
+    /// value of the item focused by keyboard navigation
+    #[rust]
+    pub focus: Option<String>,
     #[rust]
     pub lifecycle: LifeCycle,
     #[rust]
@@ -152,6 +203,7 @@ impl Widget for GMenu {
 
         let state = self.state;
         let style = self.style.get(state);
+        let walk = self.collapse_walk(walk);
 
         let _ = self.draw_menu.begin(
             cx,
@@ -178,6 +230,9 @@ impl Widget for GMenu {
         .draw_walk(cx, scope);
 
         self.draw_menu.end(cx);
+        if self.collapse_fold == 0.0 {
+            self.expanded_width = self.area().rect(cx).size.x;
+        }
         self.set_scope_path(&scope.path);
         DrawStep::done()
     }
@@ -189,6 +244,12 @@ impl Widget for GMenu {
 
         self.set_animation(cx);
         cx.global::<ComponentAnInit>().menu = true;
+        if self.animator_handle_event(cx, event).must_redraw() {
+            self.redraw(cx);
+        }
+        if self.mode.is_horizontal() {
+            self.handle_menu_bar(cx, event);
+        }
         if self.header.visible {
             self.header.handle_event(cx, event, scope);
         }
@@ -212,11 +273,23 @@ impl Widget for GMenu {
 
                 if let Some(e_type) = active {
                     let (meta, _value) = match e_type {
-                        MenuActionType::SubMenu(SubMenuChanged { value, meta, .. }) => {
+                        MenuActionType::SubMenu(SubMenuChanged {
+                            value,
+                            meta,
+                            active,
+                        }) => {
+                            // only one flyout of a level can be opened
+                            if active {
+                                self.close_flyouts(cx, &value);
+                            }
                             (meta, value)
                         }
                         MenuActionType::MenuItem(MenuItemClicked { meta, value, .. }) => {
                             self.active = Some(value.to_string());
+                            if self.mode.is_horizontal() {
+                                self.close_flyouts(cx, "");
+                            }
+                            self.active_selected(cx, meta.clone(), value.to_string());
                             (meta, value)
                         }
                     };
@@ -232,7 +305,7 @@ impl Widget for GMenu {
         }
 
         let area = self.area();
-        let hit = event.hits(cx, area);
+        let hit = event.hits_with_capture_overload(cx, area, self.grab_key_focus);
         self.handle_widget_event(cx, event, hit, area);
     }
 }
@@ -265,6 +338,11 @@ impl LiveHook for GMenu {
                 _ => {}
             },
         );
+        self.apply_mode();
+        if self.collapsed {
+            self.collapse(cx, true);
+            self.animator_cut(cx, id!(collapse.on));
+        }
         // if active is some -> set active or find
         if let Some(active) = self.active.as_ref() {
             self.set_active(cx, Some(active.to_string()));
@@ -292,8 +370,24 @@ impl Component for GMenu {
         Ok(())
     }
 
-    fn handle_widget_event(&mut self, _cx: &mut Cx, _event: &Event, _hit: Hit, _area: Area) {
-        ()
+    fn handle_widget_event(&mut self, cx: &mut Cx, _event: &Event, hit: Hit, area: Area) {
+        if !self.event_key {
+            return;
+        }
+        match hit {
+            Hit::FingerDown(_) => {
+                if self.grab_key_focus {
+                    cx.set_key_focus(area);
+                }
+            }
+            Hit::KeyDown(e) => {
+                let _ = self.handle_key_down(cx, &e);
+            }
+            Hit::KeyFocusLost(_) => {
+                self.set_focus(cx, None);
+            }
+            _ => {}
+        }
     }
 
     fn switch_state(&mut self, state: Self::State) -> () {
@@ -368,10 +462,22 @@ impl GMenu {
             });
         }
     }
+    pub fn active_selected(&mut self, cx: &mut Cx, meta: Option<FingerUpEvent>, value: String) {
+        if self.event_open {
+            self.scope_path.as_ref().map(|path| {
+                cx.widget_action(
+                    self.widget_uid(),
+                    path,
+                    MenuEvent::Selected(MenuSelected { meta, value }),
+                );
+            });
+        }
+    }
     event_option! {
         hover_in: MenuEvent::HoverIn => MenuHoverIn,
         hover_out: MenuEvent::HoverOut => MenuHoverOut,
-        changed: MenuEvent::Changed => MenuChanged
+        changed: MenuEvent::Changed => MenuChanged,
+        selected: MenuEvent::Selected => MenuSelected
     }
     area! {
         area_header, header,
@@ -379,7 +485,7 @@ impl GMenu {
         area_footer, footer
     }
     /// 从body.children中查找激活的菜单项(MenuItem)
-    /// 1. 在body的children中只能存在GSubMenu和GMenuItem，出现其他类型panic!
+    /// 1. 在body的children中只能存在GSubMenu、GMenuItem和GDivider(分隔线)，出现其他类型panic!
     /// 2. 激活的菜单项只能是GMenuItem
     /// 3. 如果没有激活的菜单项，返回None，也无需强制指定，将设置权交给使用者/GRouter
     /// 4. 如果有多个激活的菜单项，只会返回第一个
@@ -420,8 +526,8 @@ impl GMenu {
                 if child.active && active.is_none() {
                     active.replace(true);
                 }
-            } else {
-                panic!("GMenu only allows GMenuItem or GSubMenu as child!");
+            } else if child.as_gdivider().borrow().is_none() {
+                panic!("GMenu only allows GMenuItem, GSubMenu or GDivider as child!");
             }
         }
 
@@ -464,20 +570,282 @@ impl GMenu {
             handle_nested(cx, child, &self.active, &index_chain, &mut vec![], true);
         }
     }
+    /// is the position on the menu or one of its opened flyouts
+    pub fn contains_pos(&self, cx: &Cx, pos: DVec2) -> bool {
+        self.area().rect(cx).contains(pos)
+            || self.body.children.iter().any(|(_, child)| {
+                child
+                    .as_gsub_menu()
+                    .borrow()
+                    .map_or(false, |sub| sub.contains_pos(cx, pos))
+            })
+    }
+    /// close all opened flyouts except the `keep` sub menu and its ancestors
+    pub fn close_flyouts(&mut self, cx: &mut Cx, keep: &str) {
+        for (_, child) in self.body.children.iter() {
+            let _ = nav::nested_close_flyouts(cx, child, keep);
+        }
+    }
+    /// move the keyboard focus to the node (`None` clears the focus)
+    pub fn set_focus(&mut self, cx: &mut Cx, focus: Option<String>) {
+        for (value, is_focus) in [(self.focus.take(), false), (focus.clone(), true)] {
+            if let Some(node) = value.and_then(|value| nav::find_node(&self.body.children, &value))
+            {
+                nav::set_node_focus(cx, &node, is_focus);
+            }
+        }
+        self.focus = focus;
+    }
+    /// keyboard navigation, return whether the key is handled.
+    /// `GDropDown` forwards the key events to its popup menus by this function
+    pub fn handle_key_down(&mut self, cx: &mut Cx, e: &KeyEvent) -> bool {
+        let (nodes, parent) = match self
+            .focus
+            .as_ref()
+            .and_then(|focus| nav::find_level(&self.body.children, focus, None))
+        {
+            Some(level) => level,
+            None => (nav::level_nodes(&self.body.children), None),
+        };
+        let current = self
+            .focus
+            .as_ref()
+            .and_then(|focus| nodes.iter().position(|node| &node.value == focus));
+        let horizontal = self.mode.is_horizontal();
+        let is_bar = horizontal && parent.is_none();
+        let Some(step) = nav::nav_step(
+            &nodes,
+            current,
+            e.key_code,
+            horizontal,
+            is_bar,
+            parent.is_some(),
+        ) else {
+            return false;
+        };
+        let next = match step {
+            NavStep::Focus(next) => next,
+            NavStep::Enter(index) => {
+                let value = nodes[index].value.to_string();
+                self.open_sub_menu(cx, &value, true);
+                self.focus_first_child(cx, &value);
+                return true;
+            }
+            NavStep::SwitchBar(step) => return self.switch_bar_menu(cx, step),
+            NavStep::Back => {
+                let Some(parent) = parent else {
+                    return false;
+                };
+                let parent_is_bar = horizontal
+                    && nav::level_nodes(&self.body.children)
+                        .iter()
+                        .any(|node| node.value == parent);
+                if parent_is_bar {
+                    return self.switch_bar_menu(cx, -1);
+                }
+                self.open_sub_menu(cx, &parent, false);
+                self.set_focus(cx, Some(parent));
+                return true;
+            }
+            NavStep::Activate(index) => {
+                let node = &nodes[index];
+                let value = node.value.to_string();
+                if node.is_sub {
+                    self.open_sub_menu(cx, &value, !node.open);
+                } else {
+                    self.active = Some(value.to_string());
+                    self.set_target_active(cx);
+                    if horizontal {
+                        self.close_flyouts(cx, "");
+                        self.set_focus(cx, None);
+                    }
+                    self.active_changed(cx, None);
+                    self.active_selected(cx, None, value);
+                }
+                return true;
+            }
+            NavStep::Close => {
+                self.close_flyouts(cx, "");
+                self.set_focus(cx, None);
+                return true;
+            }
+        };
+        let next = &nodes[next];
+        // moving along the bar while a menu is opened switches to the focused one
+        if is_bar && self.opened_bar_menu().is_some() {
+            if next.is_sub {
+                self.open_sub_menu(cx, &next.value, true);
+            } else {
+                self.close_flyouts(cx, "");
+            }
+        }
+        self.set_focus(cx, Some(next.value.to_string()));
+        true
+    }
+    fn focus_first_child(&mut self, cx: &mut Cx, value: &str) {
+        let first = nav::find_node(&self.body.children, value).and_then(|sub| {
+            sub.as_gsub_menu().borrow().and_then(|sub| {
+                nav::level_nodes(&sub.body.children)
+                    .into_iter()
+                    .next()
+                    .map(|node| node.value)
+            })
+        });
+        if first.is_some() {
+            self.set_focus(cx, first);
+        }
+    }
+    pub fn toggle_collapsed(&mut self, cx: &mut Cx) {
+        let _ = self.set_collapsed(cx, !self.collapsed);
+    }
+    /// switch the rail mode of the top level items, the opened sub menus are restored by the active item
+    fn collapse(&mut self, cx: &mut Cx, collapsed: bool) {
+        self.collapsed = collapsed;
+        for (_, child) in self.body.children.iter() {
+            if let Some(mut sub) = child.as_gsub_menu().borrow_mut() {
+                sub.set_collapsed(cx, collapsed);
+            } else if let Some(mut item) = child.as_gmenu_item().borrow_mut() {
+                item.set_collapsed(cx, collapsed);
+            }
+        }
+        if !collapsed {
+            self.set_target_active(cx);
+        }
+    }
+    /// the width is animated between the expanded width and `collapsed_width`
+    fn collapse_walk(&self, walk: Walk) -> Walk {
+        let expanded = match walk.width {
+            Size::Fixed(width) => width,
+            _ => self.expanded_width,
+        };
+        if self.collapse_fold <= 0.0 || expanded <= 0.0 {
+            return walk;
+        }
+        Walk {
+            width: Size::Fixed(expanded + (self.collapsed_width - expanded) * self.collapse_fold),
+            ..walk
+        }
+    }
+    getter! {
+        GMenu {
+            get_collapsed(bool) {|c| {c.collapsed}}
+        }
+    }
+    setter! {
+        GMenu {
+            set_collapsed(collapsed: bool) {|c, cx| {
+                if c.collapsed != collapsed {
+                    c.collapse(cx, collapsed);
+                    c.animator_play(cx, if collapsed {id!(collapse.on)} else {id!(collapse.off)});
+                    c.redraw(cx);
+                }
+                Ok(())
+            }}
+        }
+    }
+    /// menu bar: lay the top level items out horizontally and turn the sub menus into flyouts,
+    /// switching back to vertical restores the vertical layout and the inline sub menus
+    fn apply_mode(&mut self) {
+        let horizontal = self.mode.is_horizontal();
+        if !horizontal && !self.bar_applied {
+            return;
+        }
+        let (flow, height) = if horizontal {
+            (Flow::Right, Size::Fit)
+        } else {
+            (Flow::Down, Size::Fill)
+        };
+        self.style.basic.container.set_flow(flow);
+        self.body.style.basic.set_flow(flow);
+        self.body.style.basic.set_height(height);
+        for (_, child) in self.body.children.iter() {
+            bar::nested_menu_bar(child, horizontal, true);
+        }
+        self.bar_applied = horizontal;
+    }
+    /// the opened top level sub menu of the menu bar
+    fn opened_bar_menu(&self) -> Option<String> {
+        nav::level_nodes(&self.body.children)
+            .into_iter()
+            .find(|node| node.is_sub && node.open)
+            .map(|node| node.value)
+    }
+    /// open the previous/next top level sub menu of the menu bar and focus its first item
+    fn switch_bar_menu(&mut self, cx: &mut Cx, step: isize) -> bool {
+        let nodes = nav::level_nodes(&self.body.children);
+        let Some(current) = nodes.iter().position(|node| node.is_sub && node.open) else {
+            return false;
+        };
+        let next = &nodes[(current as isize + step).rem_euclid(nodes.len() as isize) as usize];
+        let value = next.value.to_string();
+        if next.is_sub {
+            self.open_sub_menu(cx, &value, true);
+            self.focus_first_child(cx, &value);
+        } else {
+            self.close_flyouts(cx, "");
+            self.set_focus(cx, Some(value));
+        }
+        true
+    }
+    /// menu bar: switch the opened menu by hovering, alt + mnemonic opens a menu,
+    /// key events are handled while a menu is opened (if the menu bar has no key focus)
+    fn handle_menu_bar(&mut self, cx: &mut Cx, event: &Event) {
+        match event {
+            Event::MouseMove(e) => {
+                if let Some(opened) = self.opened_bar_menu() {
+                    if let Some(hovered) = bar::find_bar_menu_at(&self.body.children, cx, e.abs) {
+                        if hovered != opened {
+                            self.open_sub_menu(cx, &hovered, true);
+                        }
+                    }
+                }
+            }
+            Event::KeyDown(e) => {
+                if e.modifiers.alt {
+                    let mnemonic = bar::key_char(e.key_code)
+                        .and_then(|c| bar::find_mnemonic(&self.body.children, c));
+                    if let Some(value) = mnemonic {
+                        self.open_sub_menu(cx, &value, true);
+                        self.focus_first_child(cx, &value);
+                    }
+                } else if self.opened_bar_menu().is_some() && !cx.has_key_focus(self.area()) {
+                    let _ = self.handle_key_down(cx, e);
+                }
+            }
+            _ => {}
+        }
+    }
+    fn open_sub_menu(&mut self, cx: &mut Cx, value: &str, open: bool) {
+        if open {
+            self.close_flyouts(cx, value);
+        }
+        if let Some(node) = nav::find_node(&self.body.children, value) {
+            if let Some(mut sub) = node.as_gsub_menu().borrow_mut() {
+                let _ = sub.set_active(cx, open);
+            }
+        }
+        self.redraw(cx);
+    }
 }
 
 impl GMenuRef {
     event_option_ref! {
         hover_in => MenuHoverIn,
         hover_out => MenuHoverOut,
-        changed => MenuChanged
+        changed => MenuChanged,
+        selected => MenuSelected
     }
     area_ref! {
         area_header,
         area_body,
         area_footer
     }
-    getter_setter_ref! {}
+    getter_setter_ref! {
+        get_collapsed, set_collapsed -> bool
+    }
+    pub fn toggle_collapsed(&self, cx: &mut Cx) {
+        self.borrow_mut().map(|mut c| c.toggle_collapsed(cx));
+    }
 }
 
 fn handle_nested(
@@ -492,7 +860,7 @@ fn handle_nested(
         if child.value.is_empty() {
             child.generate_value(&index_chain);
         }
-        if !is_action {
+        if !is_action && !child.is_flyout() {
             if let Some(active) = active {
                 let active = child.value.eq(active);
                 let _ = child.set_active(cx, active);
@@ -513,7 +881,10 @@ fn handle_nested(
             );
             active_sub |= is_active;
         }
-        child.active = active_sub;
+        // flyouts are opened by hovering/clicking, not by the active item
+        if !child.is_flyout() {
+            child.active = active_sub;
+        }
         if !is_action {
             item_modes.push(MenuItemMode::SubMenu {
                 active: child.active,
@@ -521,7 +892,7 @@ fn handle_nested(
                 items: sub_menu_mode,
             });
         }
-        return child.active;
+        return active_sub;
     } else if let Some(mut child) = child.as_gmenu_item().borrow_mut() {
         if child.value.is_empty() {
             child.generate_value(&index_chain);
@@ -539,8 +910,10 @@ fn handle_nested(
             });
         }
         return child.active;
+    } else if child.as_gdivider().borrow().is_some() {
+        return false;
     } else {
-        panic!("GMenu only allows GMenuItem or GSubMenu as child!");
+        panic!("GMenu only allows GMenuItem, GSubMenu or GDivider as child!");
     }
 }
 
@@ -605,7 +978,7 @@ fn nested_action(
                 active.replace(MenuActionType::MenuItem(e));
             }
         }
-    } else {
-        panic!("GMenu only allows GMenuItem or GSubMenu as child!");
+    } else if child.as_gdivider().borrow().is_none() {
+        panic!("GMenu only allows GMenuItem, GSubMenu or GDivider as child!");
     }
 }
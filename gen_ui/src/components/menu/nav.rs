@@ -0,0 +1,245 @@
+use makepad_widgets::*;
+
+use super::{GMenuItemWidgetRefExt, GSubMenuWidgetRefExt};
+
+/// a visible and enabled node of the menu, used by keyboard navigation
+pub(crate) struct NavNode {
+    pub value: String,
+    pub is_sub: bool,
+    pub open: bool,
+}
+
+/// what a key does in the focused level, see `nav_step`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum NavStep {
+    /// focus the node at the index
+    Focus(usize),
+    /// open the sub menu at the index and focus its first child
+    Enter(usize),
+    /// back to the parent sub menu
+    Back,
+    /// switch to the previous (`-1`) or next (`1`) sub menu of the menu bar
+    SwitchBar(isize),
+    /// toggle the sub menu or select the item at the index
+    Activate(usize),
+    /// close the flyouts of the menu bar
+    Close,
+}
+
+/// the step of the key in the level of `nodes`, `current` is the index of the focused node.
+/// In the menu bar (`is_bar`) left/right move between the top level items and down opens the sub menu
+pub(crate) fn nav_step(
+    nodes: &[NavNode],
+    current: Option<usize>,
+    key_code: KeyCode,
+    horizontal: bool,
+    is_bar: bool,
+    has_parent: bool,
+) -> Option<NavStep> {
+    let len = nodes.len();
+    if len == 0 {
+        return None;
+    }
+    let key_code = match (is_bar, key_code) {
+        (true, KeyCode::ArrowRight) => KeyCode::ArrowDown,
+        (true, KeyCode::ArrowLeft) => KeyCode::ArrowUp,
+        (true, KeyCode::ArrowDown) => KeyCode::ArrowRight,
+        (true, KeyCode::ArrowUp) => return None,
+        (_, key_code) => key_code,
+    };
+    match key_code {
+        KeyCode::ArrowDown => Some(NavStep::Focus(current.map_or(0, |i| (i + 1) % len))),
+        KeyCode::ArrowUp => Some(NavStep::Focus(
+            current.map_or(len - 1, |i| (i + len - 1) % len),
+        )),
+        KeyCode::ArrowRight => match current.filter(|i| nodes[*i].is_sub) {
+            Some(i) => Some(NavStep::Enter(i)),
+            None => horizontal.then_some(NavStep::SwitchBar(1)),
+        },
+        KeyCode::ArrowLeft => has_parent.then_some(NavStep::Back),
+        KeyCode::ReturnKey | KeyCode::Space => current.map(NavStep::Activate),
+        KeyCode::Escape if horizontal => Some(NavStep::Close),
+        _ => None,
+    }
+}
+
+/// navigable nodes of one level (dividers, hidden and disabled nodes are skipped)
+pub(crate) fn level_nodes(children: &[(LiveId, WidgetRef)]) -> Vec<NavNode> {
+    let mut nodes = vec![];
+    for (_, child) in children.iter() {
+        if let Some(sub) = child.as_gsub_menu().borrow() {
+            if sub.visible && !sub.disabled {
+                nodes.push(NavNode {
+                    value: sub.value.to_string(),
+                    is_sub: true,
+                    open: sub.active,
+                });
+            }
+        } else if let Some(item) = child.as_gmenu_item().borrow() {
+            if item.visible && !item.disabled {
+                nodes.push(NavNode {
+                    value: item.value.to_string(),
+                    is_sub: false,
+                    open: false,
+                });
+            }
+        }
+    }
+    nodes
+}
+
+/// find the level (siblings and parent sub menu) which contains the node, only opened sub menus are searched
+pub(crate) fn find_level(
+    children: &[(LiveId, WidgetRef)],
+    value: &str,
+    parent: Option<&str>,
+) -> Option<(Vec<NavNode>, Option<String>)> {
+    let nodes = level_nodes(children);
+    if nodes.iter().any(|node| node.value == value) {
+        return Some((nodes, parent.map(|parent| parent.to_string())));
+    }
+    for (_, child) in children.iter() {
+        if let Some(sub) = child.as_gsub_menu().borrow() {
+            if sub.active {
+                if let Some(level) = find_level(&sub.body.children, value, Some(&sub.value)) {
+                    return Some(level);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// find the sub menu or menu item by value
+pub(crate) fn find_node(children: &[(LiveId, WidgetRef)], value: &str) -> Option<WidgetRef> {
+    for (_, child) in children.iter() {
+        if let Some(sub) = child.as_gsub_menu().borrow() {
+            if sub.value == value {
+                return Some(child.clone());
+            }
+            if let Some(node) = find_node(&sub.body.children, value) {
+                return Some(node);
+            }
+        } else if let Some(item) = child.as_gmenu_item().borrow() {
+            if item.value == value {
+                return Some(child.clone());
+            }
+        }
+    }
+    None
+}
+
+/// highlight (or remove the highlight of) the node
+pub(crate) fn set_node_focus(cx: &mut Cx, node: &WidgetRef, focus: bool) {
+    if let Some(mut sub) = node.as_gsub_menu().borrow_mut() {
+        sub.set_focus(cx, focus);
+    } else if let Some(mut item) = node.as_gmenu_item().borrow_mut() {
+        item.set_focus(cx, focus);
+    }
+}
+
+/// close opened flyouts except the `keep` sub menu and its ancestors, return whether the node contains `keep`
+pub(crate) fn nested_close_flyouts(cx: &mut Cx, child: &WidgetRef, keep: &str) -> bool {
+    if let Some(mut sub) = child.as_gsub_menu().borrow_mut() {
+        let mut contains = sub.value == keep;
+        for (_, sub_child) in sub.body.children.iter() {
+            contains |= nested_close_flyouts(cx, sub_child, keep);
+        }
+        if sub.is_flyout() && sub.active && !contains {
+            let _ = sub.set_active(cx, false);
+        }
+        contains
+    } else if let Some(item) = child.as_gmenu_item().borrow() {
+        item.value == keep
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn nodes() -> Vec<NavNode> {
+        ["file", "edit", "help"]
+            .into_iter()
+            .map(|value| NavNode {
+                value: value.to_string(),
+                is_sub: value != "help",
+                open: false,
+            })
+            .collect()
+    }
+
+    fn step(current: Option<usize>, key_code: KeyCode) -> Option<NavStep> {
+        nav_step(&nodes(), current, key_code, false, false, true)
+    }
+
+    fn bar_step(current: Option<usize>, key_code: KeyCode) -> Option<NavStep> {
+        nav_step(&nodes(), current, key_code, true, true, false)
+    }
+
+    #[test]
+    fn move_focus_and_wrap() {
+        assert_eq!(step(None, KeyCode::ArrowDown), Some(NavStep::Focus(0)));
+        assert_eq!(step(None, KeyCode::ArrowUp), Some(NavStep::Focus(2)));
+        assert_eq!(step(Some(1), KeyCode::ArrowDown), Some(NavStep::Focus(2)));
+        assert_eq!(step(Some(2), KeyCode::ArrowDown), Some(NavStep::Focus(0)));
+        assert_eq!(step(Some(0), KeyCode::ArrowUp), Some(NavStep::Focus(2)));
+    }
+
+    #[test]
+    fn enter_and_back() {
+        assert_eq!(step(Some(0), KeyCode::ArrowRight), Some(NavStep::Enter(0)));
+        // an item has nothing to enter
+        assert_eq!(step(Some(2), KeyCode::ArrowRight), None);
+        assert_eq!(step(Some(2), KeyCode::ArrowLeft), Some(NavStep::Back));
+        assert_eq!(
+            nav_step(&nodes(), Some(2), KeyCode::ArrowLeft, false, false, false),
+            None
+        );
+    }
+
+    #[test]
+    fn activate() {
+        assert_eq!(
+            step(Some(2), KeyCode::ReturnKey),
+            Some(NavStep::Activate(2))
+        );
+        assert_eq!(step(Some(0), KeyCode::Space), Some(NavStep::Activate(0)));
+        assert_eq!(step(None, KeyCode::ReturnKey), None);
+        // escape only closes the flyouts of a horizontal menu
+        assert_eq!(step(Some(0), KeyCode::Escape), None);
+        assert_eq!(bar_step(Some(0), KeyCode::Escape), Some(NavStep::Close));
+    }
+
+    #[test]
+    fn menu_bar() {
+        assert_eq!(
+            bar_step(Some(0), KeyCode::ArrowRight),
+            Some(NavStep::Focus(1))
+        );
+        assert_eq!(
+            bar_step(Some(0), KeyCode::ArrowLeft),
+            Some(NavStep::Focus(2))
+        );
+        assert_eq!(
+            bar_step(Some(1), KeyCode::ArrowDown),
+            Some(NavStep::Enter(1))
+        );
+        assert_eq!(bar_step(Some(1), KeyCode::ArrowUp), None);
+        // right on an item of a flyout switches to the next menu of the bar
+        assert_eq!(
+            nav_step(&nodes(), Some(2), KeyCode::ArrowRight, true, false, true),
+            Some(NavStep::SwitchBar(1))
+        );
+    }
+
+    #[test]
+    fn empty_level() {
+        assert_eq!(
+            nav_step(&[], None, KeyCode::ArrowDown, false, false, false),
+            None
+        );
+    }
+}
@@ -7,6 +7,7 @@ use makepad_widgets::*;
 use crate::{
     area,
     components::{
+        label::GLabelWidgetRefExt,
         lifecycle::LifeCycle,
         menu::event::{SubMenuChanged, SubMenuEvent},
         traits::{BasicStyle, Component, Style, SlotComponent, SlotStyle},
@@ -63,6 +64,8 @@ live_design! {
     }
 }
 
+const FLYOUT_MIN_WIDTH: f64 = 160.0;
+
 #[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
 pub struct GSubMenu {
     #[live]
@@ -79,6 +82,25 @@ pub struct GSubMenu {
     pub value: String,
     #[live]
     pub fold: f64,
+    /// draw the body as a popup beside the header instead of expanding it inline,
+    /// the flyout opens when hovering the header (used by context menus)
+    #[live]
+    pub flyout: bool,
+    #[live]
+    pub draw_flyout: DrawView,
+    #[live]
+    draw_list: DrawList2d,
+    /// alt + mnemonic opens the sub menu when it is a top level item of a menu bar
+    #[live]
+    pub mnemonic: String,
+    /// top level item of a menu bar (set by `GMenu`), the flyout drops down below the header
+    /// and is opened/closed by clicking
+    #[rust]
+    pub menu_bar: bool,
+    /// top level item of a collapsed `GMenu` (set by `GMenu::set_collapsed`),
+    /// header labels are hidden and the body opens as a flyout
+    #[rust]
+    pub collapsed: bool,
     #[live(true)]
     pub visible: bool,
     #[live]
@@ -134,7 +156,11 @@ impl Widget for GSubMenu {
         }
 
         if let Some(DrawSubMenuState::DrawBody) = self.draw_state.get() {
-            if self.fold == 1.0 {
+            if self.is_flyout() {
+                if self.active {
+                    self.draw_flyout_body(cx, scope, body_walk);
+                }
+            } else if self.fold == 1.0 {
                 self.animator_play(cx, id!(active.on));
                 let _ = self.body.draw_walk(cx, scope, body_walk);
             } else {
@@ -173,6 +199,14 @@ impl Widget for GSubMenu {
         self.set_animation(cx);
         cx.global::<ComponentAnInit>().sub_menu = true;
         let area = self.area_header();
+        if self.is_flyout() && self.active {
+            // click outside the header and the flyout closes it
+            if let Event::MouseDown(e) = event {
+                if !self.contains_pos(cx, e.abs) {
+                    self.toggle_active(cx, false, None);
+                }
+            }
+        }
         let hit = event.hits(cx, area);
         if self.disabled {
             self.handle_when_disabled(cx, event, hit);
@@ -222,6 +256,9 @@ impl WidgetNode for GSubMenu {
     fn redraw(&mut self, cx: &mut Cx) {
         let _ = self.render(cx);
         self.draw_sub_menu.redraw(cx);
+        if self.is_flyout() {
+            self.draw_list.redraw(cx);
+        }
         for (visible, slot) in [
             (self.header.visible, &mut self.header),
             (self.body.visible, &mut self.body),
@@ -247,6 +284,10 @@ impl LiveHook for GSubMenu {
     pure_after_apply!();
     fn after_new_before_apply(&mut self, cx: &mut Cx) {
         self.merge_conf_prop(cx);
+        // the flyout panel is colored by the theme of the container by default
+        let theme = self.style.basic.container.get_theme();
+        self.draw_flyout.background_color = theme.color(900).into();
+        self.draw_flyout.border_color = theme.color(700).into();
     }
     fn after_apply(&mut self, _cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
         let live_props = ViewBasicStyle::live_props();
@@ -350,6 +391,9 @@ impl Component for GSubMenu {
             }
             Hit::FingerHoverIn(_meta) => {
                 cx.set_cursor(self.style.get(self.state).header.cursor);
+                if self.is_flyout() && !self.active && !self.menu_bar {
+                    self.toggle_active(cx, true, None);
+                }
                 // self.switch_state_with_animation(cx, SubMenuState::Hover);
                 // self.active_hover_in(cx, meta);
             }
@@ -358,17 +402,10 @@ impl Component for GSubMenu {
                 // self.active_hover_out(cx, meta);
             }
             Hit::FingerUp(meta) => {
-                self.active = !self.active;
-                self.fold = self.active.to_f32() as f64;
-                if self.active {
-                    self.switch_state_with_animation(cx, SubMenuState::Active);
-                    self.animator_play(cx, id!(active.on));
-                } else {
-                    self.switch_state_with_animation(cx, SubMenuState::Basic);
-                    self.animator_play(cx, id!(active.off));
+                // a flyout stays opened when clicking its header (except the menu bar ones)
+                if !(self.is_flyout() && self.active) || self.menu_bar {
+                    self.toggle_active(cx, !self.active, Some(meta));
                 }
-                self.active_changed(cx, Some(meta));
-                self.animation_counter = true;
             }
             _ => {}
         }
@@ -564,6 +601,88 @@ impl GSubMenu {
             });
         }
     }
+    pub fn is_flyout(&self) -> bool {
+        self.flyout || self.collapsed
+    }
+    pub fn set_collapsed(&mut self, cx: &mut Cx, collapsed: bool) {
+        if self.collapsed == collapsed {
+            return;
+        }
+        self.collapsed = collapsed;
+        for (_, child) in self.header.children.iter() {
+            if let Some(mut label) = child.as_glabel().borrow_mut() {
+                label.visible = !collapsed;
+            }
+        }
+        // an inline expanded body should not pop up as a flyout
+        self.active = false;
+        self.redraw(cx);
+    }
+    /// expand/collapse the body (open/close the flyout) and emit `SubMenuEvent::Changed`
+    pub fn toggle_active(&mut self, cx: &mut Cx, active: bool, meta: Option<FingerUpEvent>) {
+        self.active = active;
+        self.fold = self.active.to_f32() as f64;
+        if self.active {
+            self.switch_state_with_animation(cx, SubMenuState::Active);
+            self.animator_play(cx, id!(active.on));
+        } else {
+            self.switch_state_with_animation(cx, SubMenuState::Basic);
+            self.animator_play(cx, id!(active.off));
+        }
+        self.active_changed(cx, meta);
+        self.animation_counter = true;
+    }
+    /// is the position on the header or the opened body (nested flyouts included)
+    pub fn contains_pos(&self, cx: &Cx, pos: DVec2) -> bool {
+        if self.area_header().rect(cx).contains(pos) {
+            return true;
+        }
+        if !self.active {
+            return false;
+        }
+        if self.is_flyout() && self.draw_flyout.area().rect(cx).contains(pos) {
+            return true;
+        }
+        self.body.children.iter().any(|(_, child)| {
+            child
+                .as_gsub_menu()
+                .borrow()
+                .map_or(false, |sub| sub.contains_pos(cx, pos))
+        })
+    }
+    /// highlight the header like hovering, used by keyboard navigation
+    pub fn set_focus(&mut self, cx: &mut Cx, focus: bool) {
+        if focus {
+            self.header.play_animation(cx, id!(hover.on));
+        } else {
+            self.header.play_animation(cx, id!(hover.off));
+        }
+    }
+    /// draw the body on top of everything, on the right side of the header (below it in a menu bar)
+    fn draw_flyout_body(&mut self, cx: &mut Cx2d, scope: &mut Scope, body_walk: Walk) {
+        let header = self.area_header().rect(cx);
+        // a flyout can not fill its parent, fallback to the width of the header
+        let walk = Walk {
+            width: match body_walk.width {
+                Size::Fill => Size::Fixed(header.size.x.max(FLYOUT_MIN_WIDTH)),
+                width => width,
+            },
+            height: Size::Fit,
+            ..body_walk
+        };
+        self.draw_list.begin_overlay_reuse(cx);
+        cx.begin_pass_sized_turtle(Layout::flow_down());
+        self.draw_flyout.begin(cx, Walk::fit(), Layout::flow_down());
+        let _ = self.body.draw_walk(cx, scope, walk);
+        self.draw_flyout.end(cx);
+        let shift = if self.menu_bar {
+            dvec2(0.0, header.size.y)
+        } else {
+            dvec2(header.size.x, 0.0)
+        };
+        cx.end_pass_sized_turtle_with_shift(self.area_header(), shift);
+        self.draw_list.end(cx);
+    }
     event_option! {
         changed: SubMenuEvent::Changed => SubMenuChanged
     }
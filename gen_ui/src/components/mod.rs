@@ -3,9 +3,13 @@ use makepad_widgets::*;
 mod badge;
 mod button;
 mod card;
+mod chat;
 mod checkbox;
+mod code;
 mod collapse;
 mod color_picker;
+mod contribution;
+mod dialog;
 mod divider;
 mod drop_down;
 mod image;
@@ -15,6 +19,8 @@ mod lifecycle;
 mod link;
 mod live_props;
 mod loading;
+mod markdown;
+mod media;
 mod menu;
 mod number_input;
 mod pagination;
@@ -25,21 +31,28 @@ mod rate;
 mod router;
 mod select;
 mod slider;
+mod steps;
 mod svg;
 mod switch;
 mod tabbar;
 mod tag;
+mod timeline;
 mod traits;
 mod tree;
+mod upload;
 mod verification;
 mod view;
 
 pub use badge::*;
 pub use button::*;
 pub use card::*;
+pub use chat::*;
 pub use checkbox::*;
+pub use code::*;
 pub use collapse::*;
 pub use color_picker::*;
+pub use contribution::*;
+pub use dialog::*;
 pub use divider::*;
 pub use drop_down::*;
 pub use image::*;
@@ -49,6 +62,8 @@ pub use lifecycle::*;
 pub use link::*;
 pub use live_props::*;
 pub use loading::*;
+pub use markdown::*;
+pub use media::*;
 pub use menu::*;
 pub use number_input::*;
 pub use pagination::*;
@@ -59,12 +74,15 @@ pub use rate::*;
 pub use router::*;
 pub use select::*;
 pub use slider::*;
+pub use steps::*;
 pub use svg::*;
 pub use switch::*;
 pub use tabbar::*;
 pub use tag::*;
+pub use timeline::*;
 pub use traits::*;
 pub use tree::*;
+pub use upload::*;
 pub use verification::*;
 pub use view::*;
 
@@ -245,7 +263,6 @@ live_design! {
     pub IconWaiting = <GSvg> {
         src: dep("crate://self/resources/icons/svg/waiting.svg")
     }
-    pub GImage = <GImageBase> {}
 
     pub GPopup = <GPopupBase>{}
 
@@ -278,6 +295,12 @@ live_design! {
                 border_radius: {left: 6.0, bottom: 6.0, top: 6.0, right: 6.0}
             }
         }
+        draw_popup: {
+            arrow_size: 6.0,
+            fn pixel(self) -> vec4 {
+                return self.arrow_pixel();
+            }
+        }
     }
 
     pub GPopupContainer = <GPopupContainerBase>{
@@ -315,6 +338,51 @@ live_design! {
         }
     }
 
+    pub GDialogBox = <GView> {
+        style: {
+            basic: {
+                height: Fit,
+                width: 420.0,
+                flow: Down,
+                spacing: 12.0,
+                padding: {left: 20.0, top: 16.0, right: 20.0, bottom: 16.0},
+                background_visible: true,
+            }
+        }
+        title = <GLabel> {
+            text: "Dialog"
+        }
+        content = <GLabel> {
+            text: ""
+        }
+        input = <GInputArea> {
+            visible: false
+        }
+        footer = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Right,
+                    spacing: 8.0,
+                    align: {x: 1.0, y: 0.5},
+                    background_visible: false,
+                }
+            }
+        }
+    }
+
+    pub GDialogManager = <GDialogManagerBase> {
+        dialog: <GDialogBox> {},
+        button: <GButton> {},
+        draw_backdrop: {
+            background_visible: 1.0,
+            background_color: #00000066,
+            border_width: 0.0,
+            border_radius: vec4(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
     pub GDrawerContainer = <GPopupContainer> {
         popup: <GDrawerPopup> {},
         draw_popup_container: {
@@ -413,6 +481,74 @@ live_design! {
         popup: <GTooltipContainer> {}
     }
 
+    pub GImageViewer = <GImageViewerBase> {
+        image: <GImageBase> {
+            style: {basic: {fit: Stretch}}
+            svg: <GSvg> {
+                style: {basic: {svg: {height: Fill, width: Fill}}}
+            }
+        }
+        toolbar: <GHLayout> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fit,
+                    spacing: 8.0,
+                    align: {y: 0.5},
+                    padding: {left: 12.0, right: 12.0, top: 8.0, bottom: 8.0},
+                    background_visible: true,
+                    background_color: #00000099,
+                    border_radius: {left: 8.0, bottom: 8.0, top: 8.0, right: 8.0}
+                }
+            }
+            prev = <GButton> {slot: {text: "<"}}
+            next = <GButton> {slot: {text: ">"}}
+            zoom_out = <GButton> {slot: {text: "-"}}
+            zoom_in = <GButton> {slot: {text: "+"}}
+            rotate_left = <GButton> {slot: {text: "-90°"}}
+            rotate_right = <GButton> {slot: {text: "+90°"}}
+            reset = <GButton> {slot: {text: "Reset"}}
+            close = <GButton> {slot: {text: "x"}}
+        }
+    }
+
+    pub GImageViewerContainer = <GDialogContainer> {
+        popup: <GPopup> {
+            style: {
+                basic: {
+                    height: Fill,
+                    width: Fill,
+                    background_visible: false,
+                    padding: {left: 0.0, right: 0.0, top: 0.0, bottom: 0.0},
+                }
+            }
+            viewer = <GImageViewer> {}
+        }
+        draw_popup_container: {
+            // this is a mask
+            fn pixel(self) -> vec4{
+                let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+                sdf.rect(self.pos.x, self.pos.y, self.rect_size.x, self.rect_size.y);
+                sdf.fill(vec4(0.0, 0.0, 0.0, 0.85));
+                return sdf.result;
+            }
+        }
+    }
+
+    pub GImage = <GImageBase> {
+        viewer: <GImageViewerContainer> {}
+        svg: <GSvg> {
+            style: {
+                basic: {
+                    svg: {
+                        height: Fill,
+                        width: Fill,
+                    }
+                }
+            }
+        }
+    }
+
     pub GTabbarItem = <GTabbarItemBase> {}
 
     pub GTabbar = <GTabbarBase> {}
@@ -544,6 +680,10 @@ live_design! {
         text: <GLabel> {
             text: "Menu Item"
         }
+        shortcut: <GLabel> {
+            text: "",
+            style: {basic: {font_size: 9.0}}
+        }
     }
 
     pub GSubMenu = <GSubMenuBase> {
@@ -553,6 +693,11 @@ live_design! {
             }
         }
         body: <GView> {}
+        draw_flyout: {
+            background_visible: 1.0,
+            border_width: 1.0,
+            border_radius: vec4(4.0, 4.0, 4.0, 4.0),
+        }
     }
 
     pub GMenu = <GMenuBase> {
@@ -586,41 +731,32 @@ live_design! {
         }
     }
 
+    pub GCollapseGroup = <GCollapseGroupBase> {}
+
     pub GColorPanel = <GColorPanelBase> {}
 
     pub GProgress = <GProgressBase> {}
 
     pub GLoading = <GLoadingBase> {}
 
-    pub GSlider = <GSliderBase> {}
-
-    pub GRate = <GRateBase> {}
-
-    pub GSelectItem = <GSelectItemBase> {
-        icon: <GSvg> {
-            visible: false
-        }
-
-        text: <GLabel> {
-            text: "Select Item"
-        }
-
-        suffix: <IconCheck> {}
-    }
-
-    pub GSelectOptions = <GSelectOptionsBase> {
-        // scroll_bars: <GScrollBars> {}
+    pub GSlider = <GSliderBase> {
+        mark: <GLabel> {
+            style: {basic: {font_size: 9.0}}
+        },
+        draw_mark: {
+            background_visible: 1.0,
+            border_radius: vec4(2.0, 2.0, 2.0, 2.0),
+        },
+        draw_tooltip: {
+            background_visible: 1.0,
+            border_radius: vec4(4.0, 4.0, 4.0, 4.0),
+        },
+        tooltip_text: <GLabel> {
+            style: {basic: {font_size: 9.0, color: #FFFFFF}}
+        },
     }
 
-    pub GSelect = <GSelectBase> {
-        select_options: <GSelectOptions> {}
-        prefix: <GView> {
-            visible: false
-        }
-        suffix: <GView> {
-            visible: false
-        }
-    }
+    pub GRate = <GRateBase> {}
 
     pub GBadgeDot = <GBadgeDotBase> {
         text: <GLabel>{
@@ -655,6 +791,76 @@ live_design! {
         }
     }
 
+    pub GSelectItem = <GSelectItemBase> {
+        icon: <GSvg> {
+            visible: false
+        }
+
+        text: <GLabel> {
+            text: "Select Item"
+        }
+
+        description: <GLabel> {
+            visible: false,
+            text: "Description"
+        }
+
+        suffix: <IconCheck> {}
+    }
+
+    pub GSelectGroup = <GSelectItem> {
+        group: true,
+        style: {
+            basic: {
+                container: {
+                    cursor: Default,
+                    background_visible: false,
+                    padding: {left: 8.0, top: 6.0, right: 8.0, bottom: 2.0}
+                },
+                text: {
+                    font_size: 10.0
+                }
+            }
+        }
+        text: {
+            text: "Select Group"
+        }
+    }
+
+    pub GSelectOptions = <GSelectOptionsBase> {
+        // scroll_bars: <GScrollBars> {}
+        search: <GInput> {
+            style: {
+                basic: {
+                    container: {
+                        margin: {bottom: 4.0}
+                    }
+                }
+            }
+            prefix: <GView> {
+                <IconSearch> {
+                    style: {basic: {svg: {height: 14.0, width: 14.0}}}
+                }
+            }
+            input: {
+                placeholder: "search..."
+            }
+            suffix: <GView> {
+                visible: false
+            }
+        }
+    }
+
+    pub GSelect = <GSelectBase> {
+        select_options: <GSelectOptions> {}
+        prefix: <GView> {
+            visible: false
+        }
+        suffix: <GView> {
+            visible: false
+        }
+    }
+
     pub GPagination = <GPaginationBase> {
         prefix: <GButton> {
             slot: {
@@ -671,6 +877,674 @@ live_design! {
                 text: ">"
             }
         }
+        total_text: <GLabel> {
+            visible: false,
+            style: {basic: {margin: {right: 8.0}}}
+        }
+        size: <GSelect> {
+            visible: false,
+            style: {basic: {container: {width: 120.0}}}
+            select_options: {
+                <GSelectItem> {value: "5", text: {text: "5 / page"}}
+                <GSelectItem> {value: "10", text: {text: "10 / page"}}
+                <GSelectItem> {value: "20", text: {text: "20 / page"}}
+                <GSelectItem> {value: "50", text: {text: "50 / page"}}
+                <GSelectItem> {value: "100", text: {text: "100 / page"}}
+            }
+            item: <GSelectItem> {}
+        }
+        jumper: <GNumberInput> {
+            visible: false,
+            style: {basic: {container: {width: 80.0}}}
+        }
+        pages_text: <GLabel> {
+            text: "/ 1"
+        }
+    }
+
+    pub GMarkdown = <GMarkdownBase> {
+        heading: <GLabel> {
+            style: {basic: {font_size: 14.0, width: Fill}}
+        }
+        paragraph: <GLabel> {
+            style: {basic: {width: Fill}}
+        }
+        list: <GView> {
+            style: {basic: {height: Fit, width: Fill, flow: Down, spacing: 6.0, background_visible: false}}
+        }
+        list_item: <GView> {
+            style: {basic: {height: Fit, width: Fill, flow: Right, spacing: 8.0, background_visible: false}}
+        }
+        list_marker: <GLabel> {}
+        list_content: <GView> {
+            style: {basic: {height: Fit, width: Fill, flow: Down, spacing: 6.0, background_visible: false}}
+        }
+        quote: <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Down,
+                    spacing: 8.0,
+                    theme: Dark,
+                    background_visible: true,
+                    border_width: 0.0,
+                    border_radius: {left: 0.0, top: 0.0, right: 0.0, bottom: 0.0},
+                    padding: {left: 12.0, top: 8.0, right: 8.0, bottom: 8.0}
+                }
+            }
+        }
+        code_block: <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    theme: Dark,
+                    background_visible: true,
+                    border_radius: {left: 4.0, top: 4.0, right: 4.0, bottom: 4.0},
+                    padding: {left: 12.0, top: 10.0, right: 12.0, bottom: 10.0}
+                }
+            }
+        }
+        code: <GLabel> {
+            style: {basic: {width: Fill}}
+        }
+        table: <GView> {
+            style: {basic: {height: Fit, width: Fill, flow: Down, spacing: 4.0, background_visible: false}}
+        }
+        table_row: <GView> {
+            style: {basic: {height: Fit, width: Fill, flow: Right, spacing: 8.0, background_visible: false}}
+        }
+        table_head_cell: <GLabel> {
+            style: {basic: {width: Fill}}
+        }
+        table_cell: <GLabel> {
+            style: {basic: {width: Fill}}
+        }
+        image: <GImage> {}
+        divider: <GDivider> {}
+        inline: <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: RightWrap,
+                    spacing: 0.0,
+                    background_visible: false,
+                    padding: {left: 0.0, top: 0.0, right: 0.0, bottom: 0.0}
+                }
+            }
+        }
+        inline_text: <GLabel> {}
+        link: <GLink> {}
+    }
+
+    pub GCode = <GCodeBase> {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Down,
+                theme: Dark,
+                background_visible: true,
+                border_radius: {left: 4.0, top: 4.0, right: 4.0, bottom: 4.0},
+                padding: {left: 0.0, top: 0.0, right: 0.0, bottom: 0.0}
+            }
+        }
+        header = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Right,
+                    align: {y: 0.5},
+                    background_visible: false,
+                    padding: {left: 12.0, top: 4.0, right: 8.0, bottom: 4.0}
+                }
+            }
+            lang = <GLabel> {
+                style: {basic: {font_size: 10.0}}
+            }
+            <GView> {
+                style: {basic: {height: 1.0, width: Fill, background_visible: false}}
+            }
+            copy = <GButton> {
+                style: {basic: {container: {padding: {left: 8.0, top: 2.0, right: 8.0, bottom: 2.0}}}}
+                slot: {
+                    style: {basic: {font_size: 10.0}}
+                    text: "Copy"
+                }
+            }
+        }
+        body = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Right,
+                    spacing: 12.0,
+                    clip_x: true,
+                    background_visible: false,
+                    padding: {left: 12.0, top: 4.0, right: 12.0, bottom: 10.0}
+                }
+            }
+            numbers = <GLabel> {
+                font_regular: <THEME_FONT_CODE> {}
+                style: {basic: {flow: Right}}
+            }
+            <GView> {
+                style: {basic: {height: Fit, width: Fill, clip_x: true, background_visible: false}}
+                scroll_bars: <GScrollBars> {show_scroll_x: true, show_scroll_y: false}
+                code = <GLabel> {
+                    font_regular: <THEME_FONT_CODE> {}
+                    style: {basic: {flow: Right}}
+                }
+            }
+        }
+        more = <GCollapse> {
+            style: {
+                basic: {
+                    container: {height: Fit, width: Fill, background_visible: false},
+                    header: {
+                        height: Fit,
+                        width: Fill,
+                        align: {x: 0.5},
+                        background_visible: false,
+                        padding: {left: 0.0, top: 4.0, right: 0.0, bottom: 6.0}
+                    },
+                    body: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Right,
+                        spacing: 12.0,
+                        clip_x: true,
+                        background_visible: false,
+                        padding: {left: 12.0, top: 0.0, right: 12.0, bottom: 4.0}
+                    }
+                }
+            },
+            position: Top,
+            header: <GView> {
+                more_text = <GLabel> {
+                    style: {basic: {font_size: 10.0}}
+                }
+            }
+            body: <GView> {
+                more_numbers = <GLabel> {
+                    font_regular: <THEME_FONT_CODE> {}
+                    style: {basic: {flow: Right}}
+                }
+                <GView> {
+                    style: {basic: {height: Fit, width: Fill, clip_x: true, background_visible: false}}
+                    scroll_bars: <GScrollBars> {show_scroll_x: true, show_scroll_y: false}
+                    more_code = <GLabel> {
+                        font_regular: <THEME_FONT_CODE> {}
+                        style: {basic: {flow: Right}}
+                    }
+                }
+            }
+        }
+    }
+
+    pub GBubble = <GBubbleBase> {
+        avatar = <GImage> {
+            style: {basic: {height: 32.0, width: 32.0}}
+        }
+        main = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Down,
+                    spacing: 4.0,
+                    background_visible: false,
+                }
+            }
+            name = <GLabel> {
+                style: {basic: {font_size: 10.0}}
+            }
+            content = <GView> {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fit,
+                        flow: Down,
+                        border_radius: {left: 8.0, top: 8.0, right: 8.0, bottom: 8.0},
+                        padding: {left: 12.0, top: 8.0, right: 12.0, bottom: 8.0},
+                    }
+                }
+                loading = <GLoading> {
+                    visible: false,
+                    style: {basic: {height: 16.0, width: 16.0}}
+                }
+                text = <GMarkdown> {}
+            }
+        }
+    }
+
+    pub GBubbleList = <GBubbleListBase> {
+        scroll_bars: <GScrollBars> {}
+        user: <GBubble> {role: User}
+        assistant: <GBubble> {role: Assistant}
+        system: <GBubble> {role: System}
+    }
+
+    pub GPrompts = <GPromptsBase> {
+        item: <GView> {
+            event_open: true,
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fit,
+                    cursor: Hand,
+                    border_width: 1.0,
+                    border_radius: {left: 6.0, top: 6.0, right: 6.0, bottom: 6.0},
+                    padding: {left: 12.0, top: 8.0, right: 12.0, bottom: 8.0},
+                }
+            }
+            text = <GLabel> {}
+        }
+    }
+
+    pub GWelcome = <GWelcomeBase> {
+        icon = <GImage> {
+            style: {basic: {height: 48.0, width: 48.0}}
+        }
+        <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Down,
+                    spacing: 6.0,
+                    background_visible: false,
+                }
+            }
+            title = <GLabel> {
+                style: {basic: {font_size: 16.0}}
+            }
+            description = <GLabel> {
+                style: {basic: {width: Fill}}
+            }
+        }
+    }
+
+    pub GSender = <GSenderBase> {
+        attachments = <GView> {
+            visible: false,
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: RightWrap,
+                    spacing: 6.0,
+                    background_visible: false,
+                }
+            }
+        }
+        <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Right,
+                    spacing: 8.0,
+                    align: {y: 1.0},
+                    background_visible: false,
+                }
+            }
+            attach = <GButton> {
+                slot: {text: "+"}
+            }
+            input = <GInputArea> {
+                draw_text: {
+                    text_style: <THEME_FONT_REGULAR>{}
+                }
+                placeholder: "Send a message, Shift + Enter to break the line"
+            }
+            send = <GButton> {
+                slot: {text: "Send"}
+            }
+            stop = <GButton> {
+                visible: false,
+                slot: {text: "Stop"}
+            }
+        }
+        attachment: <GTag> {
+            close: <IconClose>{
+                style: {basic: {svg: {width: 12.0}}}
+            }
+        }
+    }
+
+    pub GTimelineItem = <GTimelineItemBase> {
+        axis = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fit,
+                    flow: Down,
+                    spacing: 4.0,
+                    align: {x: 0.5},
+                    background_visible: false,
+                }
+            }
+            dot = <GBadgeDot> {
+                dot: true,
+                animation_open: false,
+                style: {
+                    basic: {
+                        container: {
+                            height: 12.0,
+                            width: 12.0,
+                            padding: {left: 0.0, top: 0.0, right: 0.0, bottom: 0.0}
+                        }
+                    }
+                }
+            }
+            loading = <GLoading> {
+                visible: false,
+                style: {basic: {height: 12.0, width: 12.0}}
+            }
+            line = <GDivider> {}
+        }
+        body = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Down,
+                    spacing: 4.0,
+                    background_visible: false,
+                    padding: {left: 0.0, top: 0.0, right: 12.0, bottom: 16.0}
+                }
+            }
+            time = <GLabel> {
+                style: {basic: {font_size: 10.0}}
+            }
+            content = <GView> {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        background_visible: false,
+                    }
+                }
+            }
+        }
+    }
+
+    pub GTimeline = <GTimelineBase> {
+        direction: Vertical,
+    }
+
+    pub GStep = <GStepBase> {
+        header = <GView> {
+            event_open: true,
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Right,
+                    spacing: 8.0,
+                    cursor: Hand,
+                    align: {y: 0.5},
+                    background_visible: false,
+                }
+            }
+            indicator = <GBadgeDot> {
+                animation_open: false,
+                style: {
+                    basic: {
+                        container: {
+                            height: 24.0,
+                            width: 24.0,
+                            border_radius: {left: 12.0, top: 12.0, right: 12.0, bottom: 12.0},
+                            padding: {left: 0.0, top: 0.0, right: 0.0, bottom: 0.0}
+                        }
+                    }
+                }
+                text: <GLabel> {
+                    visible: true,
+                    style: {basic: {font_size: 10.0}}
+                }
+            }
+            title = <GLabel> {}
+            line = <GDivider> {}
+        }
+        description = <GLabel> {
+            style: {
+                basic: {
+                    font_size: 10.0,
+                    padding: {left: 32.0, top: 0.0, right: 0.0, bottom: 0.0}
+                }
+            }
+        }
+    }
+
+    pub GSteps = <GStepsBase> {}
+
+    pub GContribution = <GContributionBase> {
+        theme: Success,
+        draw_tooltip: {
+            background_visible: 1.0,
+            border_radius: vec4(4.0, 4.0, 4.0, 4.0),
+        },
+        draw_cell: {
+            hover_color: #FFFFFF,
+        },
+        tooltip_text: <GLabel> {
+            style: {basic: {font_size: 9.0, color: #FFFFFF}}
+        },
+        label: <GLabel> {
+            style: {basic: {font_size: 9.0}}
+        },
+    }
+
+    pub GUpload = <GUploadBase> {
+        drop_zone = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Down,
+                    spacing: 8.0,
+                    align: {x: 0.5, y: 0.5},
+                    padding: {left: 16.0, top: 24.0, right: 16.0, bottom: 24.0},
+                    border_width: 1.0,
+                    border_radius: {left: 6.0, top: 6.0, right: 6.0, bottom: 6.0},
+                    background_visible: false,
+                }
+            }
+            pick = <GButton> {
+                slot: {text: "Select Files"}
+            }
+            hint = <GLabel> {
+                text: "or drop the files here",
+                style: {basic: {font_size: 9.0}}
+            }
+        }
+        list = <GView> {
+            visible: false,
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Down,
+                    spacing: 6.0,
+                    background_visible: false,
+                }
+            }
+        }
+        item: <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Right,
+                    spacing: 8.0,
+                    align: {y: 0.5},
+                    padding: {left: 8.0, top: 6.0, right: 8.0, bottom: 6.0},
+                    border_radius: {left: 4.0, top: 4.0, right: 4.0, bottom: 4.0},
+                }
+            }
+            thumbnail = <GImage> {
+                visible: false,
+                style: {basic: {height: 32.0, width: 32.0}}
+            }
+            <GView> {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 4.0,
+                        background_visible: false,
+                    }
+                }
+                <GView> {
+                    style: {
+                        basic: {
+                            height: Fit,
+                            width: Fill,
+                            flow: Right,
+                            spacing: 8.0,
+                            background_visible: false,
+                        }
+                    }
+                    name = <GLabel> {}
+                    size = <GLabel> {
+                        style: {basic: {font_size: 9.0}}
+                    }
+                }
+                progress = <GProgress> {
+                    visible: false,
+                    style: {basic: {theme: Primary, height: 4.0, width: Fill}}
+                }
+                message = <GLabel> {
+                    style: {basic: {font_size: 9.0}}
+                }
+            }
+            cancel = <GButton> {slot: {text: "Cancel"}}
+            retry = <GButton> {slot: {text: "Retry"}}
+            remove = <GButton> {slot: {text: "Remove"}}
+        }
+        card: <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: 120.0,
+                    flow: Down,
+                    spacing: 4.0,
+                    align: {x: 0.5},
+                    padding: {left: 6.0, top: 6.0, right: 6.0, bottom: 6.0},
+                    border_width: 1.0,
+                    border_radius: {left: 6.0, top: 6.0, right: 6.0, bottom: 6.0},
+                }
+            }
+            thumbnail = <GImage> {
+                visible: false,
+                style: {basic: {height: 96.0, width: 108.0}}
+            }
+            name = <GLabel> {
+                style: {basic: {font_size: 9.0}}
+            }
+            size = <GLabel> {
+                style: {basic: {font_size: 8.0}}
+            }
+            progress = <GProgress> {
+                visible: false,
+                style: {basic: {theme: Primary, height: 4.0, width: Fill}}
+            }
+            message = <GLabel> {
+                style: {basic: {font_size: 8.0}}
+            }
+            <GView> {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Right,
+                        spacing: 4.0,
+                        align: {x: 0.5},
+                        background_visible: false,
+                    }
+                }
+                cancel = <GButton> {slot: {text: "Cancel"}}
+                retry = <GButton> {slot: {text: "Retry"}}
+                remove = <GButton> {slot: {text: "Remove"}}
+            }
+        }
+    }
+
+    pub GWaveform = <GWaveformBase> {
+        theme: Primary,
+        draw_bar: {
+            background_visible: 1.0,
+            border_radius: vec4(1.5, 1.5, 1.5, 1.5),
+        },
+    }
+
+    pub GAudio = <GAudioBase> {
+        play = <GButton> {
+            slot: {text: "Play"}
+        }
+        wave = <GWaveform> {}
+        progress = <GSlider> {
+            visible: false,
+            style: {basic: {width: Fill}}
+        }
+        time = <GLabel> {
+            style: {basic: {font_size: 9.0, color: #ABABAB}}
+        }
+        volume = <GSlider> {
+            min: 0.0,
+            max: 1.0,
+            step: 0.01,
+            style: {basic: {width: 80.0}}
+        }
+    }
+
+    pub GVideo = <GVideoBase> {
+        draw_backdrop: {
+            background_visible: 1.0,
+            background_color: #000000,
+        },
+        screen = <GImage> {
+            style: {basic: {height: Fill, width: Fill}}
+        }
+        controls = <GView> {
+            style: {
+                basic: {
+                    height: Fit,
+                    width: Fill,
+                    flow: Right,
+                    spacing: 8.0,
+                    align: {y: 0.5},
+                    padding: {left: 8.0, top: 6.0, right: 8.0, bottom: 6.0},
+                    background_color: #1D2939,
+                }
+            }
+            play = <GButton> {
+                slot: {text: "Play"}
+            }
+            progress = <GSlider> {
+                style: {basic: {width: Fill}}
+            }
+            time = <GLabel> {
+                style: {basic: {font_size: 9.0, color: #ABABAB}}
+            }
+            volume = <GSlider> {
+                min: 0.0,
+                max: 1.0,
+                step: 0.01,
+                style: {basic: {width: 80.0}}
+            }
+            fullscreen = <GButton> {
+                slot: {text: "Fullscreen"}
+            }
+        }
     }
 
     pub GVerification = <GVerificationBase> {
@@ -682,6 +1556,10 @@ live_design! {
     }
 
     pub GLeaf = <GLeafBase> {
+        checkbox: <GCheckbox> {
+            visible: false,
+            extra: {visible: false}
+        }
         icon: <GSvg> {
             visible: false
         }
@@ -695,6 +1573,14 @@ live_design! {
         text: <GLabel> {
             text: "tree-branch"
         }
+        checkbox: <GCheckbox> {
+            visible: false,
+            extra: {visible: false}
+        }
+        loading: <GLoading> {
+            visible: false,
+            style: {basic: {height: 14.0, width: 14.0}}
+        }
         body: <GView> {
             style: {
                 basic: {
@@ -708,6 +1594,16 @@ live_design! {
 
     pub GTree = <GTreeBase> {
         body: <GView> {}
+        branch: <GBranch> {}
+        leaf: <GLeaf> {}
+        draw_drop: {
+            border_radius: vec4(2.0, 2.0, 2.0, 2.0),
+        }
+        draw_focus: {
+            background_visible: 0.0,
+            border_width: 1.0,
+            border_radius: vec4(2.0, 2.0, 2.0, 2.0),
+        }
     }
 
     pub GNumberCtr = <GNumberCtrBase> {
@@ -753,16 +1649,17 @@ pub fn components_register(cx: &mut Cx) {
     checkbox::checkbox_register(cx);
     switch::live_design(cx);
     divider::live_design(cx);
-    image::live_design(cx);
+    image::image_register(cx);
     popup::popup_register(cx);
     drop_down::live_design(cx);
+    dialog::live_design(cx);
     tabbar::tabbar_register(cx);
     tag::live_design(cx);
     link::live_design(cx);
     router::page::live_design(cx);
     router::live_design(cx);
     menu::menu_register(cx);
-    collapse::live_design(cx);
+    collapse::collapse_register(cx);
     color_picker::color_picker_register(cx);
     progress::live_design(cx);
     loading::live_design(cx);
@@ -773,6 +1670,14 @@ pub fn components_register(cx: &mut Cx) {
     input::input_register(cx);
     pagination::live_design(cx);
     verification::live_design(cx);
+    markdown::live_design(cx);
+    code::live_design(cx);
+    chat::chat_register(cx);
+    timeline::timeline_register(cx);
+    steps::steps_register(cx);
+    contribution::live_design(cx);
+    upload::live_design(cx);
+    media::media_register(cx);
     tree::tree_register(cx);
     number_input::number_input_register(cx);
 }
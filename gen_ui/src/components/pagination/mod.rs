@@ -1,19 +1,21 @@
 mod event;
+mod pages;
 mod prop;
 
 pub use event::*;
+pub use pages::*;
 pub use prop::*;
 
 use makepad_widgets::*;
 
 use crate::{
     components::{
-        BasicStyle, ButtonBasicStyle, ButtonState, Component, GButton, GLabelWidgetRefExt,
-        LifeCycle, SlotComponent, SlotStyle, Style, ViewBasicStyle,
-    }, error::Error, event_option, lifecycle, prop::{
+        BasicStyle, ButtonBasicStyle, ButtonState, Component, GButton, GLabel, GNumberInput,
+        GSelect, LifeCycle, NumberInputChanged, NumberInputEvent, SelectEvent, SlotComponent,
+        SlotStyle, Style, ViewBasicStyle,
+    }, error::Error, event_option, event_option_ref, lifecycle, prop::{
         ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, ApplyStateMap, ToStateMap,
         manuel::{BASIC, DISABLED},
-        traits::ToFloat,
     }, pure_after_apply, set_index, set_scope_path, shader::draw_view::DrawView, switch_state, sync, themes::conf::Conf, visible
 };
 
@@ -24,18 +26,34 @@ live_design! {
     pub GPaginationBase = {{GPagination}} {}
 }
 
+/// pages jumped by clicking an ellipsis
+const ELLIPSIS_JUMP: usize = 5;
+
 /// # Pagination
 ///
 /// ## Display
 /// ```md
-/// -------------------------------
-/// | < | item | item | item | > |  extra
-/// -------------------------------
+/// ----------------------------------------------------------------
+/// | total_text | < | item | ... | item | > | size | jumper |
+/// ----------------------------------------------------------------
+/// - total_text : `total_format` with `{}` replaced by `total_items`
 /// - < : prefix button
 /// - > : suffix button
-/// - item : page button
-/// - extra : can be used to show total pages info // TODO
+/// - item : page button, see `page_items` for the ellipsis
+/// - size : select of the page size, the values of the options are the sizes
+/// - jumper : go to the page when return is pressed
+///
+/// simple mode
+/// ------------------------------------
+/// | < | jumper | pages_text | > |
+/// ------------------------------------
 /// ```
+/// `total_text`, `size` and `jumper` are hidden in the `GPagination` template,
+/// set `visible: true` to show them.
+///
+/// ## Pages
+/// `total` is the count of pages, set `total_items` instead to count the pages by `page_size`
+/// (the `size` select and `total_text` need it).
 #[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
 pub struct GPagination {
     #[live]
@@ -49,8 +67,15 @@ pub struct GPagination {
     pub btn: Option<LivePtr>,
     #[rust]
     pub item: Vec<(LiveId, GButton)>,
-    // #[live] TODO
-    // pub extra: GView,
+    #[live]
+    pub total_text: GLabel,
+    #[live]
+    pub size: GSelect,
+    #[live]
+    pub jumper: GNumberInput,
+    /// `/ pages` after the jumper in simple mode
+    #[live]
+    pub pages_text: GLabel,
     #[rust]
     live_update_order: SmallVec<[LiveId; 1]>,
     #[live]
@@ -74,14 +99,30 @@ pub struct GPagination {
     #[rust]
     pub state: PaginationState,
     // --- pagination
+    /// count of pages, used when `total_items` is `0`
     #[live]
     pub total: usize,
+    /// count of items, the pages are `total_items / page_size` (rounded up) if it is not `0`
+    #[live]
+    pub total_items: usize,
     #[live]
     pub current: usize,
+    /// items of a page, the option of `size` which has the same value is selected
     #[live(5)]
     pub page_size: i32,
+    /// pages shown on each side of the current page
+    #[live(2)]
+    pub siblings: usize,
+    /// pages always shown at the start and the end
+    #[live(1)]
+    pub boundaries: usize,
+    #[live("Total {} items".to_string())]
+    pub total_format: String,
+    /// only `< jumper / pages >`
+    #[live(false)]
+    pub simple: bool,
     #[rust]
-    pub display_pages: Vec<String>,
+    pub display_pages: Vec<PageItem>,
     #[live(true)]
     pub event_open: bool,
     #[live(true)]
@@ -121,6 +162,17 @@ impl WidgetNode for GPagination {
                 item.redraw(cx);
             }
         }
+        for label in [&mut self.total_text, &mut self.pages_text] {
+            if label.visible {
+                label.redraw(cx);
+            }
+        }
+        if self.size.visible {
+            self.size.redraw(cx);
+        }
+        if self.jumper.visible || self.simple {
+            self.jumper.redraw(cx);
+        }
     }
 
     fn state(&self) -> String {
@@ -141,28 +193,48 @@ impl Widget for GPagination {
         }
         let style = self.style.get(self.state);
         self.draw_pagination.begin(cx, walk, style.layout());
+        if self.total_text.visible && !self.simple {
+            let walk = self.total_text.walk(cx);
+            let _ = self.total_text.draw_walk(cx, scope, walk);
+        }
         if self.prefix.visible {
             let walk = self.prefix.walk(cx);
             let _ = self.prefix.draw_walk(cx, scope, walk);
         }
-        for ((_id, btn), text) in self.item.iter_mut().zip(self.display_pages.iter()) {
-            let walk = btn.walk(cx);
-            btn.set_text(cx, &text);
-            btn.apply_state_map = self.apply_items_map.clone();
-            btn.focus_sync();
-            // 如果current等于按钮的页码，则设置为选中状态
-            if self.current.to_string().eq(text) {
-                btn.switch_state_with_animation(cx, ButtonState::Pressed);
-            } else {
-                btn.switch_state_with_animation(cx, ButtonState::Basic);
+        if self.simple {
+            let walk = self.jumper.walk(cx);
+            let _ = self.jumper.draw_walk(cx, scope, walk);
+            let walk = self.pages_text.walk(cx);
+            let _ = self.pages_text.draw_walk(cx, scope, walk);
+        } else {
+            for ((_id, btn), page) in self.item.iter_mut().zip(self.display_pages.iter()) {
+                let walk = btn.walk(cx);
+                btn.apply_state_map = self.apply_items_map.clone();
+                btn.focus_sync();
+                // 如果current等于按钮的页码，则设置为选中状态
+                if *page == PageItem::Page(self.current) {
+                    btn.switch_state_with_animation(cx, ButtonState::Pressed);
+                } else {
+                    btn.switch_state_with_animation(cx, ButtonState::Basic);
+                }
+                let _ = btn.draw_walk(cx, scope, walk);
             }
-            let _ = btn.draw_walk(cx, scope, walk);
         }
 
         if self.suffix.visible {
             let walk = self.suffix.walk(cx);
             let _ = self.suffix.draw_walk(cx, scope, walk);
         }
+        if !self.simple {
+            if self.size.visible {
+                let walk = self.size.walk(cx);
+                let _ = self.size.draw_walk(cx, scope, walk);
+            }
+            if self.jumper.visible {
+                let walk = self.jumper.walk(cx);
+                let _ = self.jumper.draw_walk(cx, scope, walk);
+            }
+        }
         self.draw_pagination.end(cx);
         self.set_scope_path(&scope.path);
         DrawStep::done()
@@ -183,64 +255,101 @@ impl Widget for GPagination {
         // 点击前缀按钮会让current - 1， 点击后缀按钮会让current + 1, 如果 current - 1 或 + 1 超过范围则直接设置为边界值
         self.prefix.handle_event(cx, event, scope);
         self.suffix.handle_event(cx, event, scope);
+        if self.simple {
+            self.jumper.handle_event(cx, event, scope);
+            return;
+        }
         // 点击页码按钮会让 current 变为对应的页码，页码中的省略号按钮，前省略号会让 current - 5，后省略号会让 current + 5，超过范围则设置为边界值
-        for (_id, item) in self.item.iter_mut() {
+        for ((_id, item), page) in self.item.iter_mut().zip(self.display_pages.iter()) {
             // 如果current等于按钮的页码，则跳过
-            if self.current.to_string() == item.slot.as_glabel().get_text() {
+            if *page == PageItem::Page(self.current) {
                 continue;
             }
             item.handle_event(cx, event, scope);
         }
+        if self.size.visible {
+            self.size.handle_event(cx, event, scope);
+        }
+        if self.jumper.visible {
+            self.jumper.handle_event(cx, event, scope);
+        }
     }
 }
 
 impl MatchEvent for GPagination {
     fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        let pages = self.pages();
+        let mut changed = false;
         let mut meta = None;
         if let Some(e) = self.prefix.clicked(actions) {
             if self.current > 1 {
                 self.current -= 1;
+                changed = true;
                 meta = Some(e.meta);
             }
         }
 
         if let Some(e) = self.suffix.clicked(actions) {
-            if self.current < self.total {
+            if self.current < pages {
                 self.current += 1;
+                changed = true;
                 meta = Some(e.meta);
             }
         }
 
-        for (id, item) in self.item.iter_mut() {
+        for ((_id, item), page) in self.item.iter_mut().zip(self.display_pages.iter()) {
             if let Some(e) = item.clicked(actions) {
-                let text = item.slot.as_glabel().get_text();
-                if *id == live_id!(prefix_ellipsis) {
-                    // 前省略号
-                    if self.current > 5 {
-                        self.current -= 5;
-                    } else {
-                        self.current = 1;
-                    }
-                } else if *id == live_id!(suffix_ellipsis) {
-                    // 后省略号
-                    if self.current + 5 < self.total {
-                        self.current += 5;
-                    } else {
-                        self.current = self.total;
-                    }
-                } else {
-                    // 普通页码按钮
-                    if let Ok(page) = text.parse::<usize>() {
-                        self.current = page;
-                    }
-                }
+                self.current = match page {
+                    PageItem::Page(page) => *page,
+                    PageItem::PrevEllipsis => self.current.saturating_sub(ELLIPSIS_JUMP).max(1),
+                    PageItem::NextEllipsis => (self.current + ELLIPSIS_JUMP).min(pages),
+                };
+                changed = true;
                 meta = Some(e.meta);
             }
         }
 
-        if let Some(meta) = meta {
-            self.active_changed(cx, Some(meta));
+        // the page count is recalculated by the new page size, keep the current page in range
+        if let SelectEvent::Changed(e) = actions.find_widget_action(self.size.widget_uid()).cast() {
+            match e.value.parse::<i32>() {
+                Ok(size) if size > 0 && size != self.page_size => {
+                    self.page_size = size;
+                    self.current = self.current.min(self.pages());
+                    changed = true;
+                }
+                Ok(_) => {}
+                Err(_) => {
+                    error!(
+                        "GPagination: page size option should be a number, got {}",
+                        e.value
+                    );
+                }
+            }
+        }
+
+        // typing in the jumper jumps when return is pressed, the up and down buttons jump at once
+        let jump = if let Some(e) = self.jumper.input.returned(actions) {
+            e.value.parse::<f32>().ok()
+        } else if let NumberInputEvent::Changed(NumberInputChanged {
+            meta: None, value, ..
+        }) = actions.find_widget_action(self.jumper.widget_uid()).cast()
+        {
+            Some(value)
+        } else {
+            None
+        };
+        if let Some(page) = jump {
+            let page = (page.round().max(1.0) as usize).min(pages);
+            if page != self.current {
+                self.current = page;
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.active_changed(cx, meta);
             self.apply_items(cx);
+            self.redraw(cx);
         }
     }
 }
@@ -426,81 +535,94 @@ impl GPagination {
     event_option! {
         changed: PaginationEvent::Changed => PaginationChanged
     }
-    /// count display pages by current page and page size
-    /// return (start_page, end_page, show_prefix_ellipsis, show_suffix_ellipsis)
-    /// - total <= 5: (1, total, false, false) `1 2 3 4 5`
-    /// - total > 5:
-    ///    - current < 5: (1, 6, false, true) `1 2 3 4 5 6 ... total`
-    ///    - current >= 5: (current - 2, current + 2, true, true) `1 ... 3 4 5 6 7 ... total`
-    ///    - current > 5: (total - 5, total, true, false) `1 ... total-5 total-4 total-3 total-2 total-1 total`
-    fn count_display_pages(&self) -> (usize, usize, bool, bool) {
-        let total = self.total;
-        let current = self.current;
-        if total <= 5 {
-            (1, total, false, false)
+    /// count of pages, at least 1
+    pub fn pages(&self) -> usize {
+        if self.total_items > 0 {
+            page_count(self.total_items, self.page_size.max(1) as usize)
         } else {
-            if current < 5 {
-                (1, 6, false, true)
-            } else if current >= 5 && current <= total - 4 {
-                (current - 2, current + 2, true, true)
-            } else {
-                (total - 5, total, true, false)
-            }
+            self.total.max(1)
         }
     }
+    pub fn set_current(&mut self, cx: &mut Cx, current: usize) -> () {
+        self.current = current;
+        self.apply_items(cx);
+        self.redraw(cx);
+    }
+    /// set the count of pages
+    pub fn set_total(&mut self, cx: &mut Cx, total: usize) -> () {
+        self.total = total;
+        self.apply_items(cx);
+        self.redraw(cx);
+    }
+    /// set the count of items, the pages are counted by `page_size`
+    pub fn set_total_items(&mut self, cx: &mut Cx, total_items: usize) -> () {
+        self.total_items = total_items;
+        self.apply_items(cx);
+        self.redraw(cx);
+    }
+    pub fn set_page_size(&mut self, cx: &mut Cx, page_size: usize) -> () {
+        self.page_size = page_size.max(1) as i32;
+        self.apply_items(cx);
+        self.redraw(cx);
+    }
 
     pub fn apply_items(&mut self, cx: &mut Cx) {
+        let pages = self.pages();
+        self.current = self.current.clamp(1, pages);
+        // 根据 current 和 siblings, boundaries 计算出需要显示的按钮
+        self.display_pages = page_items(self.current, pages, self.siblings, self.boundaries);
         self.item.clear();
-        self.display_pages.clear();
-        // 根据 current 和 page_size 计算出当前页的按钮范围
-        let (start_page, end_page, show_prefix_ellipsis, show_suffix_ellipsis) =
-            self.count_display_pages();
-        // 确定 item 数量
-        let display_count = end_page - start_page
-            + 1
-            + (show_prefix_ellipsis.to_f32() as usize)
-            + (show_suffix_ellipsis.to_f32() as usize);
-
-        for i in 0..display_count {
-            // 第一个按钮永远是1这个按钮
-            if i == 0 {
-                self.item
-                    .push((live_id!(page_1), GButton::new_from_ptr(cx, self.btn)));
-                self.display_pages.push("1".to_string());
-                continue;
-            } else if i == display_count - 1 {
-                // 最后一个按钮永远是total这个按钮
-                self.item
-                    .push((live_id!(page_total), GButton::new_from_ptr(cx, self.btn)));
-                self.display_pages.push(self.total.to_string());
-                continue;
-            }
-            // 显示前缀省略号
-            if show_prefix_ellipsis && i == 1 {
-                self.item.push((
-                    live_id!(prefix_ellipsis),
-                    GButton::new_from_ptr(cx, self.btn),
-                ));
-                self.display_pages.push("...".to_string());
-                continue;
-            }
-            if show_suffix_ellipsis && i == display_count - 2 {
-                // 显示后缀省略号
-                self.item.push((
-                    live_id!(suffix_ellipsis),
-                    GButton::new_from_ptr(cx, self.btn),
-                ));
-                self.display_pages.push("...".to_string());
-                continue;
-            }
-
-            // 中间的页码按钮
-            let page_number = start_page + i - (if show_prefix_ellipsis { 1 } else { 0 });
+        for page in self.display_pages.iter() {
+            let (id, text) = match page {
+                PageItem::Page(page) => (LiveId(*page as u64), page.to_string()),
+                PageItem::PrevEllipsis => (live_id!(prefix_ellipsis), "...".to_string()),
+                PageItem::NextEllipsis => (live_id!(suffix_ellipsis), "...".to_string()),
+            };
             let mut btn = GButton::new_from_ptr(cx, self.btn);
             btn.style.basic = self.style.basic.item;
             btn.style.disabled = self.style.disabled.item;
-            self.item.push((live_id!(page_number), btn));
-            self.display_pages.push(page_number.to_string());
+            btn.set_text(cx, &text);
+            self.item.push((id, btn));
+        }
+        let _ = self.total_text.set_text(
+            cx,
+            self.total_format
+                .replace("{}", &self.total_items.to_string()),
+        );
+        // keep the select in sync when the page size is set by code or live
+        if self.size.value != self.page_size.to_string() {
+            let _ = self.size.select_value(cx, &self.page_size.to_string());
         }
+        let _ = self.pages_text.set_text(cx, format!("/ {}", pages));
+        self.jumper.min = 1.0;
+        self.jumper.max = pages as f32;
+        self.jumper.value = self.current as f32;
+        self.jumper.apply_data(cx);
+    }
+}
+
+impl GPaginationRef {
+    pub fn pages(&self) -> usize {
+        self.borrow().map_or(1, |c| c.pages())
+    }
+    pub fn current(&self) -> usize {
+        self.borrow().map_or(1, |c| c.current)
+    }
+    pub fn set_current(&self, cx: &mut Cx, current: usize) -> () {
+        self.borrow_mut().map(|mut c| c.set_current(cx, current));
+    }
+    pub fn set_total(&self, cx: &mut Cx, total: usize) -> () {
+        self.borrow_mut().map(|mut c| c.set_total(cx, total));
+    }
+    pub fn set_total_items(&self, cx: &mut Cx, total_items: usize) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.set_total_items(cx, total_items));
+    }
+    pub fn set_page_size(&self, cx: &mut Cx, page_size: usize) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.set_page_size(cx, page_size));
+    }
+    event_option_ref! {
+        changed => PaginationChanged
     }
 }
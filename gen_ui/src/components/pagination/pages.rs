@@ -0,0 +1,139 @@
+/// a button in the page list of `GPagination`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageItem {
+    Page(usize),
+    /// collapsed pages before the current page
+    PrevEllipsis,
+    /// collapsed pages after the current page
+    NextEllipsis,
+}
+
+/// count of pages for `total` items, at least 1
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// pages to display, `siblings` pages are shown on each side of the current page and
+/// `boundaries` pages at the start and the end, the others are collapsed into ellipsis.
+/// The count of items is kept the same (`2 * (siblings + boundaries) + 3`) while paging,
+/// so the buttons do not move around.
+/// ```md
+/// siblings = 1, boundaries = 1, pages = 10
+/// - current = 1: 1 2 3 4 5 ... 10
+/// - current = 5: 1 ... 4 5 6 ... 10
+/// - current = 9: 1 ... 6 7 8 9 10
+/// ```
+pub fn page_items(
+    current: usize,
+    pages: usize,
+    siblings: usize,
+    boundaries: usize,
+) -> Vec<PageItem> {
+    // first, last, current and the two ellipsis
+    if pages <= 2 * (siblings + boundaries) + 3 {
+        return (1..=pages).map(PageItem::Page).collect();
+    }
+    let current = current.clamp(1, pages);
+    // the siblings window slides between the boundaries and keeps its width
+    let start = current
+        .saturating_sub(siblings)
+        .min(pages - boundaries - 2 * siblings - 1)
+        .max(boundaries + 2);
+    let end = (current + siblings)
+        .max(boundaries + 2 * siblings + 2)
+        .min(pages - boundaries - 1);
+
+    let mut items = (1..=boundaries).map(PageItem::Page).collect::<Vec<_>>();
+    // an ellipsis of one page is shown as the page
+    items.push(if start > boundaries + 2 {
+        PageItem::PrevEllipsis
+    } else {
+        PageItem::Page(boundaries + 1)
+    });
+    items.extend((start..=end).map(PageItem::Page));
+    items.push(if end < pages - boundaries - 1 {
+        PageItem::NextEllipsis
+    } else {
+        PageItem::Page(pages - boundaries)
+    });
+    items.extend((pages - boundaries + 1..=pages).map(PageItem::Page));
+    items
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use PageItem::*;
+
+    #[test]
+    fn count() {
+        assert_eq!(page_count(0, 10), 1);
+        assert_eq!(page_count(95, 10), 10);
+        assert_eq!(page_count(100, 10), 10);
+        assert_eq!(page_count(101, 10), 11);
+    }
+
+    #[test]
+    fn ellipsis() {
+        assert_eq!(
+            page_items(2, 4, 1, 1),
+            (1..=4).map(Page).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            page_items(1, 10, 1, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                NextEllipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            page_items(5, 10, 1, 1),
+            vec![
+                Page(1),
+                PrevEllipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                NextEllipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            page_items(10, 10, 1, 1),
+            vec![
+                Page(1),
+                PrevEllipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            page_items(7, 20, 2, 2),
+            vec![
+                Page(1),
+                Page(2),
+                PrevEllipsis,
+                Page(5),
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                NextEllipsis,
+                Page(19),
+                Page(20)
+            ]
+        );
+        assert_eq!(
+            page_items(3, 9, 1, 0),
+            vec![Page(1), Page(2), Page(3), Page(4), NextEllipsis]
+        );
+    }
+}
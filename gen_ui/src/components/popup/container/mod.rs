@@ -171,6 +171,9 @@ impl GPopupContainer {
         self.popup.handle_event_with(cx, event, scope, sweep_area);
     }
 
+    pub fn set_max_size(&mut self, max_size: Option<DVec2>) {
+        self.popup.set_max_size(max_size);
+    }
     pub fn menu_contains_pos(&self, cx: &mut Cx, pos: DVec2) -> bool {
         self.popup.menu_contains_pos(cx, pos)
     }
@@ -1,8 +1,10 @@
 mod container;
+mod placement;
 mod prop;
 mod register;
 
 pub use container::*;
+pub use placement::*;
 pub use prop::*;
 pub use register::register as popup_register;
 
@@ -76,6 +78,12 @@ pub struct GPopup {
     pub block_signal_event: bool,
     #[live]
     pub event_order: EventOrder,
+    /// the space available for the popup, see `set_max_size`
+    #[rust]
+    pub max_size: Option<DVec2>,
+    /// size of the popup when it is not constrained by `max_size`
+    #[rust]
+    natural_size: DVec2,
 }
 
 impl LiveHook for GPopup {
@@ -233,10 +241,15 @@ impl PopupComponent for GPopup {
     ) -> () {
         let _ = position.map(|position| {
             self.draw_popup.position = position;
+            self.draw_popup.arrow_side = position.arrow_side();
         });
         self.draw_popup.angle_offset = angle_offset;
         // draw the popup ------------------------------------------------------------------------
-        self.draw_walk(cx, scope, None);
+        let (walk, constrained) = self.constrained_walk();
+        self.draw_walk(cx, scope, Some(walk));
+        if !constrained {
+            self.natural_size = self.area.rect(cx).size;
+        }
         // ---------------------------------------------------------------------------------------
         if *redraw {
             self.draw_popup.redraw(cx);
@@ -357,7 +370,8 @@ impl GPopup {
                 self.scroll
             };
 
-            let layout = style.layout().with_scroll(scroll);
+            let mut layout = style.layout().with_scroll(scroll);
+            layout.padding = self.draw_popup.arrow_padding(layout.padding);
             let walk = walk.unwrap_or(style.walk());
             if style.background_visible {
                 self.draw_popup.begin(cx, walk, layout);
@@ -499,6 +513,27 @@ impl GPopup {
         }
     }
 
+    /// limit the size of the popup to the available space, `None` to remove the limit.
+    /// the content of a constrained popup scrolls if `scroll_bars` is set
+    pub fn set_max_size(&mut self, max_size: Option<DVec2>) {
+        self.max_size = max_size;
+    }
+    /// the walk fitted into `max_size`, the natural size is measured while it fits
+    fn constrained_walk(&self) -> (Walk, bool) {
+        let mut walk = self.walk();
+        let mut constrained = false;
+        if let Some(max_size) = self.max_size {
+            if self.natural_size.x > max_size.x {
+                walk.width = Size::Fixed(max_size.x);
+                constrained = true;
+            }
+            if self.natural_size.y > max_size.y {
+                walk.height = Size::Fixed(max_size.y);
+                constrained = true;
+            }
+        }
+        (walk, constrained)
+    }
     pub fn menu_contains_pos(&self, cx: &mut Cx, pos: DVec2) -> bool {
         self.draw_popup.area().clipped_rect(cx).contains(pos)
     }
@@ -0,0 +1,313 @@
+use makepad_widgets::*;
+
+use crate::prop::Position;
+
+/// where a popup is placed around its trigger
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Placement {
+    /// the final position, flipped if the preferred side has no room
+    pub position: Position,
+    /// shift from the top left corner of the trigger, use in `end_pass_sized_turtle_with_shift`
+    pub shift: DVec2,
+    /// distance from the start of the popup side facing the trigger to the trigger center,
+    /// the arrow of `DrawPopup` is drawn here
+    pub arrow_offset: f32,
+    /// the space left for the popup on the placed side
+    pub max_size: DVec2,
+}
+
+/// the window rect that popups should stay inside
+pub fn popup_viewport(cx: &Cx2d, padding: f64) -> Rect {
+    let size = cx.current_pass_size();
+    Rect {
+        pos: dvec2(padding, padding),
+        size: dvec2(
+            (size.x - padding * 2.0).max(0.0),
+            (size.y - padding * 2.0).max(0.0),
+        ),
+    }
+}
+
+/// place the popup at `position` of the trigger, if `auto`:
+/// - flip to the opposite side when the popup does not fit and the opposite side has more space
+/// - shift along the side to keep the popup inside the viewport
+///
+/// `nudge` is the extra `offset_x`/`offset_y` of the popup
+pub fn place_popup(
+    position: Position,
+    trigger: Rect,
+    size: DVec2,
+    viewport: Rect,
+    offset: f64,
+    nudge: DVec2,
+    auto: bool,
+) -> Placement {
+    let position = if auto {
+        flip_popup(position, trigger, size, viewport, offset)
+    } else {
+        position
+    };
+    shift_popup(position, trigger, size, viewport, offset, nudge, auto)
+}
+
+/// the side the popup should be placed, flipped to the opposite side when the popup does not
+/// fit and the opposite side has more space
+pub fn flip_popup(
+    position: Position,
+    trigger: Rect,
+    size: DVec2,
+    viewport: Rect,
+    offset: f64,
+) -> Position {
+    let main = if position.is_horizontal() {
+        size.x
+    } else {
+        size.y
+    };
+    let flipped = position.flip();
+    let space = side_space(position, trigger, viewport, offset);
+    if space < main && side_space(flipped, trigger, viewport, offset) > space {
+        flipped
+    } else {
+        position
+    }
+}
+
+/// place the popup at `position` of the trigger without flipping, if `auto` the popup is
+/// shifted along the side to keep it inside the viewport
+pub fn shift_popup(
+    position: Position,
+    trigger: Rect,
+    size: DVec2,
+    viewport: Rect,
+    offset: f64,
+    nudge: DVec2,
+    auto: bool,
+) -> Placement {
+    let mut shift = position.popup_shift(trigger.size, size, offset) + nudge;
+    let space = side_space(position, trigger, viewport, offset).max(0.0);
+    let mut max_size = viewport.size;
+    if position.is_horizontal() {
+        max_size.x = space;
+    } else {
+        max_size.y = space;
+    }
+
+    let pos = trigger.pos + shift;
+    let center = trigger.pos + trigger.size / 2.0;
+    let arrow_offset = if position.is_horizontal() {
+        if auto {
+            shift.y = clamp_axis(pos.y, size.y, viewport.pos.y, viewport.size.y) - trigger.pos.y;
+        }
+        (center.y - trigger.pos.y - shift.y).clamp(0.0, size.y)
+    } else {
+        if auto {
+            shift.x = clamp_axis(pos.x, size.x, viewport.pos.x, viewport.size.x) - trigger.pos.x;
+        }
+        (center.x - trigger.pos.x - shift.x).clamp(0.0, size.x)
+    };
+
+    Placement {
+        position,
+        shift,
+        arrow_offset: arrow_offset as f32,
+        max_size,
+    }
+}
+
+/// the space between the trigger and the viewport edge on the side of the position
+fn side_space(position: Position, trigger: Rect, viewport: Rect, offset: f64) -> f64 {
+    let space = match position {
+        Position::Bottom | Position::BottomLeft | Position::BottomRight => {
+            viewport.pos.y + viewport.size.y - trigger.pos.y - trigger.size.y
+        }
+        Position::Top | Position::TopLeft | Position::TopRight => trigger.pos.y - viewport.pos.y,
+        Position::Left | Position::LeftTop | Position::LeftBottom => trigger.pos.x - viewport.pos.x,
+        Position::Right | Position::RightTop | Position::RightBottom => {
+            viewport.pos.x + viewport.size.x - trigger.pos.x - trigger.size.x
+        }
+    };
+    space - offset
+}
+
+/// keep `[pos, pos + size]` inside `[start, start + len]`, the start wins if it is too big
+fn clamp_axis(pos: f64, size: f64, start: f64, len: f64) -> f64 {
+    pos.min(start + len - size).max(start)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect {
+        pos: DVec2 { x: 0.0, y: 0.0 },
+        size: DVec2 { x: 800.0, y: 600.0 },
+    };
+    const POPUP: DVec2 = DVec2 { x: 200.0, y: 100.0 };
+    const OFFSET: f64 = 4.0;
+
+    fn trigger(x: f64, y: f64) -> Rect {
+        Rect {
+            pos: dvec2(x, y),
+            size: dvec2(80.0, 30.0),
+        }
+    }
+
+    fn flip(position: Position, trigger: Rect) -> Position {
+        flip_popup(position, trigger, POPUP, VIEWPORT, OFFSET)
+    }
+
+    #[test]
+    fn position_flip() {
+        assert_eq!(Position::Top.flip(), Position::Bottom);
+        assert_eq!(Position::BottomRight.flip(), Position::TopRight);
+        assert_eq!(Position::LeftTop.flip(), Position::RightTop);
+        assert_eq!(Position::RightBottom.flip(), Position::LeftBottom);
+        for position in [Position::Left, Position::TopLeft, Position::BottomLeft] {
+            assert_eq!(position.flip().flip(), position);
+        }
+    }
+
+    #[test]
+    fn position_popup_shift() {
+        let (trigger, offset) = (dvec2(80.0, 30.0), OFFSET);
+        assert_eq!(
+            Position::Bottom.popup_shift(trigger, POPUP, offset),
+            dvec2(-60.0, 34.0)
+        );
+        assert_eq!(
+            Position::TopRight.popup_shift(trigger, POPUP, offset),
+            dvec2(-120.0, -104.0)
+        );
+        assert_eq!(
+            Position::Left.popup_shift(trigger, POPUP, offset),
+            dvec2(-204.0, -35.0)
+        );
+        assert_eq!(
+            Position::RightBottom.popup_shift(trigger, POPUP, offset),
+            dvec2(84.0, -70.0)
+        );
+    }
+
+    #[test]
+    fn clamp_axis_inside() {
+        assert_eq!(clamp_axis(10.0, 50.0, 0.0, 100.0), 10.0);
+        assert_eq!(clamp_axis(80.0, 50.0, 0.0, 100.0), 50.0);
+        assert_eq!(clamp_axis(-5.0, 50.0, 0.0, 100.0), 0.0);
+        // bigger than the range: the start wins
+        assert_eq!(clamp_axis(20.0, 150.0, 0.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn flip_at_each_edge() {
+        assert_eq!(flip(Position::Bottom, trigger(100.0, 550.0)), Position::Top);
+        assert_eq!(flip(Position::Top, trigger(100.0, 10.0)), Position::Bottom);
+        assert_eq!(
+            flip(Position::LeftTop, trigger(10.0, 300.0)),
+            Position::RightTop
+        );
+        assert_eq!(flip(Position::Right, trigger(700.0, 300.0)), Position::Left);
+        // enough space or the opposite side has less space
+        assert_eq!(
+            flip(Position::Bottom, trigger(300.0, 300.0)),
+            Position::Bottom
+        );
+        let tall = dvec2(200.0, 400.0);
+        assert_eq!(
+            flip_popup(
+                Position::Bottom,
+                trigger(100.0, 280.0),
+                tall,
+                VIEWPORT,
+                OFFSET
+            ),
+            Position::Bottom
+        );
+    }
+
+    #[test]
+    fn place_without_auto() {
+        let placement = place_popup(
+            Position::Bottom,
+            trigger(10.0, 550.0),
+            POPUP,
+            VIEWPORT,
+            OFFSET,
+            dvec2(0.0, 0.0),
+            false,
+        );
+        assert_eq!(placement.position, Position::Bottom);
+        assert_eq!(placement.shift, dvec2(-60.0, 34.0));
+    }
+
+    #[test]
+    fn shift_clamp() {
+        let shift = |trigger: Rect, auto: bool| {
+            shift_popup(
+                Position::Bottom,
+                trigger,
+                POPUP,
+                VIEWPORT,
+                OFFSET,
+                dvec2(0.0, 0.0),
+                auto,
+            )
+        };
+        // the left edge
+        let placement = shift(trigger(10.0, 100.0), true);
+        assert_eq!(placement.shift, dvec2(-10.0, 34.0));
+        // the arrow still points to the trigger center
+        assert_eq!(placement.arrow_offset, 50.0);
+        // the right edge
+        let placement = shift(trigger(700.0, 100.0), true);
+        assert_eq!(placement.shift, dvec2(-100.0, 34.0));
+        assert_eq!(placement.arrow_offset, 140.0);
+        // not shifted without auto
+        let placement = shift(trigger(700.0, 100.0), false);
+        assert_eq!(placement.shift, dvec2(-60.0, 34.0));
+        // the nudge is added
+        let placement = shift_popup(
+            Position::Bottom,
+            trigger(300.0, 100.0),
+            POPUP,
+            VIEWPORT,
+            OFFSET,
+            dvec2(5.0, 6.0),
+            true,
+        );
+        assert_eq!(placement.shift, dvec2(-55.0, 40.0));
+    }
+
+    #[test]
+    fn max_size() {
+        let max_size = |position: Position, trigger: Rect| {
+            shift_popup(
+                position,
+                trigger,
+                POPUP,
+                VIEWPORT,
+                OFFSET,
+                dvec2(0.0, 0.0),
+                true,
+            )
+            .max_size
+        };
+        assert_eq!(
+            max_size(Position::Bottom, trigger(100.0, 100.0)),
+            dvec2(800.0, 466.0)
+        );
+        assert_eq!(
+            max_size(Position::Top, trigger(100.0, 100.0)),
+            dvec2(800.0, 96.0)
+        );
+        assert_eq!(
+            max_size(Position::Right, trigger(100.0, 100.0)),
+            dvec2(616.0, 600.0)
+        );
+        // no space left
+        assert_eq!(
+            max_size(Position::Bottom, trigger(100.0, 590.0)),
+            dvec2(800.0, 0.0)
+        );
+    }
+}
@@ -4,7 +4,7 @@ use crate::components::router::schema::PageType;
 
 #[derive(Debug, Clone, DefaultNone)]
 pub enum RouterEvent {
-    NavTo(LiveId),
+    NavTo(Vec<LiveId>),
     NavBack(LiveId),
     // Init(RouterInit),
     None,
@@ -129,7 +129,7 @@ impl GRouter {
         for action in actions {
             if let Some(action) = action.as_widget_action() {
                 if let RouterEvent::NavTo(path) = action.cast() {
-                    self.nav_to(cx, path.as_slice());
+                    self.nav_to(cx, &path);
                     break;
                 }
             }
@@ -260,7 +260,18 @@ impl GRouter {
         }
     }
     pub fn nav_to_path(cx: &mut Cx, uid: WidgetUid, scope: &mut Scope, path: &[LiveId]) {
-        cx.widget_action(uid, &scope.path, RouterEvent::NavTo(path[0]));
+        Self::nav_to_scope_path(cx, uid, &scope.path, path);
+    }
+    /// same as `nav_to_path` but for widgets which only keep their scope path (such as `GLink`)
+    pub fn nav_to_scope_path(
+        cx: &mut Cx,
+        uid: WidgetUid,
+        scope_path: &HeapLiveIdPath,
+        path: &[LiveId],
+    ) {
+        if !path.is_empty() {
+            cx.widget_action(uid, scope_path, RouterEvent::NavTo(path.to_vec()));
+        }
     }
     pub fn nav_back_path(cx: &mut Cx, uid: WidgetUid, scope: &mut Scope) {
         let path = scope.path.clone();
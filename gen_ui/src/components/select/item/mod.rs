@@ -16,7 +16,7 @@ use crate::{
     prop::{
         ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, ApplySlotMergeImpl, DeferWalks, ToSlotMap,
         ToStateMap,
-        manuel::{ACTIVE, BASIC, DISABLED, HOVER},
+        manuel::{ACTIVE, BASIC, DISABLED, HOVER, SECONDARY},
         traits::ToFloat,
     },
     pure_after_apply, set_animation, set_index, set_scope_path,
@@ -82,6 +82,11 @@ pub struct GSelectItem {
     pub active: bool,
     #[live]
     pub value: String,
+    /// is this item a group header
+    /// a group header is not selectable, it only shows a title for the items below it
+    /// (until the next group header)
+    #[live]
+    pub group: bool,
     // --- visible -------------------
     #[live(true)]
     pub visible: bool,
@@ -104,6 +109,9 @@ pub struct GSelectItem {
     pub icon: GSvg,
     #[live]
     pub text: GLabel,
+    /// description under the text
+    #[live]
+    pub description: GLabel,
     /// suffix icon
     #[live]
     pub suffix: GSvg,
@@ -136,6 +144,9 @@ pub struct GSelectItem {
     /// ```
     #[rust]
     pub as_item: bool,
+    /// is this item hidden by the filter of GSelectOptions
+    #[rust]
+    pub filtered: bool,
 }
 
 impl WidgetNode for GSelectItem {
@@ -161,6 +172,7 @@ impl WidgetNode for GSelectItem {
         for mut slot in [
             GComponent::Svg(&mut self.icon),
             GComponent::Label(&mut self.text),
+            GComponent::Label(&mut self.description),
             GComponent::Svg(&mut self.suffix),
         ] {
             if slot.visible() {
@@ -207,6 +219,10 @@ impl Widget for GSelectItem {
                             continue;
                         }
                     }
+                    if *id == live_id!(text) && self.has_description() {
+                        draw_with_description(cx, scope, walk, component, &mut self.description);
+                        continue;
+                    }
                     let _ = component.draw_walk(cx, scope, walk);
                 }
             }
@@ -221,6 +237,10 @@ impl Widget for GSelectItem {
                             continue;
                         }
                     }
+                    if *id == live_id!(text) && self.has_description() {
+                        draw_with_description(cx, scope, res_walk, slot, &mut self.description);
+                        break;
+                    }
                     let _ = slot.draw_walk(cx, scope, res_walk);
                     break;
                 }
@@ -257,6 +277,7 @@ impl LiveHook for GSelectItem {
 
     fn after_apply(&mut self, _cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
         let svg_props = SvgBasicStyle::live_props();
+        let label_props = LabelBasicStyle::live_props();
         self.set_apply_slot_map(
             apply.from,
             nodes,
@@ -270,7 +291,8 @@ impl LiveHook for GSelectItem {
             [
                 (SelectItemPart::Icon, &svg_props),
                 (SelectItemPart::Container, &ViewBasicStyle::live_props()),
-                (SelectItemPart::Text, &LabelBasicStyle::live_props()),
+                (SelectItemPart::Text, &label_props),
+                (SelectItemPart::Description, &label_props),
                 (SelectItemPart::Suffix, &svg_props),
             ],
             |_| {},
@@ -307,6 +329,9 @@ impl SlotComponent<SelectState> for GSelectItem {
         self.text.style.basic = self.style.basic.text;
         self.text.style.disabled = self.style.disabled.text;
 
+        self.description.style.basic = self.style.basic.description;
+        self.description.style.disabled = self.style.disabled.description;
+
         self.suffix.style.basic = self.style.basic.suffix;
         self.suffix.style.hover = self.style.hover.suffix;
         self.suffix.style.pressed = self.style.active.suffix;
@@ -325,16 +350,20 @@ impl Component for GSelectItem {
         self.merge_prop_to_slot();
     }
 
-    fn render(&mut self, _cx: &mut Cx) -> Result<(), Self::Error> {
+    fn render(&mut self, cx: &mut Cx) -> Result<(), Self::Error> {
         if self.disabled {
             self.switch_state(SelectState::Disabled);
         } else {
-            if self.active {
+            if self.active && !self.group {
                 self.switch_state(SelectState::Active);
             } else {
                 self.switch_state(SelectState::Basic);
             }
         }
+        if self.group {
+            // group title is muted text of the current theme unless a color is set
+            self.text.set_default_font_color(cx, SECONDARY);
+        }
         let state = self.state;
         let style = self.style.get(state);
         self.draw_item.merge(&style.container);
@@ -360,6 +389,7 @@ impl Component for GSelectItem {
         self.state = state;
         self.icon.switch_state(state.into());
         self.text.switch_state(state.into());
+        self.description.switch_state(state.into());
         self.suffix.switch_state(state.into());
     }
 
@@ -384,6 +414,11 @@ impl Component for GSelectItem {
             self.text.focus_sync();
         });
 
+        crossed_map.remove(&SelectItemPart::Description).map(|map| {
+            self.description.apply_state_map.merge(map.to_state());
+            self.description.focus_sync();
+        });
+
         crossed_map.remove(&SelectItemPart::Suffix).map(|map| {
             self.suffix.apply_slot_map.merge_slot(map.to_slot());
             self.suffix.focus_sync();
@@ -595,6 +630,58 @@ impl GSelectItem {
         clicked: SelectItemEvent::Clicked => SelectItemClicked
     }
 
+    /// ## can this item be selected (or reached by keyboard navigation)
+    /// group headers, disabled and filtered items are skipped
+    pub fn is_selectable(&self) -> bool {
+        self.visible && !self.disabled && !self.group && !self.filtered
+    }
+
+    /// ## does this item draw a description under the text
+    pub fn has_description(&self) -> bool {
+        self.description.visible && !self.description.text.as_ref().is_empty()
+    }
+
+    /// ## height of this item in the options menu
+    /// the description is counted only when the item has one, see [`GSelectItem::has_description`]
+    pub fn count_real_height(&self, cx: &mut Cx) -> f64 {
+        let font_metrics = cx.global::<Conf>().theme.font.metrics;
+        let style = self.style.get(self.state);
+        let text_style = style.text;
+        let padding = text_style.padding.top
+            + text_style.padding.bottom
+            + style.container.padding.top
+            + style.container.padding.bottom;
+        let margin = text_style.margin.top
+            + text_style.margin.bottom
+            + style.container.margin.top
+            + style.container.margin.bottom;
+        // description is drawn under the text with 2.0 spacing
+        let description = if self.has_description() {
+            let description_style = style.description;
+            ((description_style.font_size * font_metrics) as f64)
+                + description_style.padding.top
+                + description_style.padding.bottom
+                + description_style.margin.top
+                + description_style.margin.bottom
+                + 2.0
+        } else {
+            0.0
+        };
+
+        ((text_style.font_size * font_metrics) as f64) + description + padding + margin + 0.8
+    }
+
+    /// ## hover this item without selecting it, used by keyboard navigation in GSelectOptions
+    pub fn hover(&mut self, cx: &mut Cx, hover: bool) -> () {
+        let state = match (hover, self.active) {
+            (true, _) => SelectState::Hover,
+            (false, true) => SelectState::Active,
+            (false, false) => SelectState::Basic,
+        };
+        self.switch_state_with_animation(cx, state);
+        self.redraw(cx);
+    }
+
     pub fn toggle(&mut self, cx: &mut Cx, active: bool, init: bool) -> () {
         self.active = active;
 
@@ -620,6 +707,9 @@ impl GSelectItem {
         dispatch_action: &mut dyn FnMut(&mut Cx, SelectItemEvent),
     ) {
         animation_open_then_redraw!(self, cx, event);
+        if !self.is_selectable() {
+            return;
+        }
         if !self.active {
             match event.hits_with_options(
                 cx,
@@ -671,8 +761,35 @@ impl GSelectItem {
         self.animation_spread = ptr.animation_spread;
         self.text.clone_from_ptr(cx, &ptr.text);
         self.icon.clone_from_ptr(cx, &ptr.icon);
+        self.description.clone_from_ptr(cx, &ptr.description);
         self.as_item = true;
         self.style.basic.container.background_visible = false;
         // self.suffix.clone_from_ptr(cx, &ptr.suffix);
     }
 }
+
+/// draw text and description in a vertical block which takes the walk of the text slot
+fn draw_with_description(
+    cx: &mut Cx2d,
+    scope: &mut Scope,
+    walk: Walk,
+    text: &mut GComponent,
+    description: &mut GLabel,
+) -> () {
+    cx.begin_turtle(
+        Walk {
+            height: Size::Fit,
+            ..walk
+        },
+        Layout {
+            flow: Flow::Down,
+            spacing: 2.0,
+            ..Default::default()
+        },
+    );
+    let text_walk = text.walk(cx);
+    let _ = text.draw_walk(cx, scope, text_walk);
+    let description_walk = description.walk(cx);
+    let _ = description.draw_walk(cx, scope, description_walk);
+    cx.end_turtle();
+}
@@ -4,15 +4,15 @@ use toml_edit::{InlineTable, Item, Value};
 use crate::{
     component_part,
     components::{
-        live_props::LiveProps, traits::{BasicStyle, Style}, view::ViewBasicStyle, LabelBasicStyle, LabelState, SelectState, SlotBasicStyle, SlotStyle, SvgBasicStyle, SvgState, ViewColors, ViewState
+        live_props::LiveProps, traits::{BasicStyle, ComponentState, Style}, view::ViewBasicStyle, LabelBasicStyle, LabelState, SelectState, SlotBasicStyle, SlotStyle, SvgBasicStyle, SvgState, ViewColors, ViewState
     },
     error::Error,
     from_prop_to_toml, get_get_mut,
     prop::{
-        manuel::{ACTIVE, BASIC, CONTAINER, DISABLED, HOVER, ICON, SUFFIX, TEXT}, traits::NewFrom, ApplySlotMapImpl, ApplyStateMapImpl, Radius
+        manuel::{ACTIVE, BASIC, CONTAINER, DESCRIPTION, DISABLED, HOVER, ICON, SECONDARY, SUFFIX, TEXT}, traits::NewFrom, ApplySlotMapImpl, ApplyStateMapImpl, Radius
     },
     prop_interconvert,
-    themes::Theme,
+    themes::{ColorFontConf, Theme},
     utils::get_from_itable,
 };
 
@@ -42,6 +42,7 @@ impl SlotStyle for SelectItemStyle {
                 SelectItemPart::Container,
                 SelectItemPart::Icon,
                 SelectItemPart::Text,
+                SelectItemPart::Description,
                 SelectItemPart::Suffix,
             ],
         );
@@ -89,6 +90,8 @@ pub struct SelectItemBasicStyle {
     pub icon: SvgBasicStyle,
     #[live(SelectItemBasicStyle::default_text(Theme::default(), SelectState::Basic))]
     pub text: LabelBasicStyle,
+    #[live(SelectItemBasicStyle::default_description(Theme::default(), SelectState::Basic))]
+    pub description: LabelBasicStyle,
     #[live(SelectItemBasicStyle::default_suffix(Theme::default(), SelectState::Basic))]
     pub suffix: SvgBasicStyle,
 }
@@ -103,6 +106,7 @@ impl BasicStyle for SelectItemBasicStyle {
             container: Self::default_container(theme, state),
             icon: Self::default_icon(theme, state),
             text: Self::default_text(theme, state),
+            description: Self::default_description(theme, state),
             suffix: Self::default_suffix(theme, state),
         }
     }
@@ -112,7 +116,7 @@ impl BasicStyle for SelectItemBasicStyle {
     }
 
     fn len() -> usize {
-        4 * (2 * SvgBasicStyle::len() + LabelBasicStyle::len() + ViewBasicStyle::len())
+        4 * (2 * SvgBasicStyle::len() + 2 * LabelBasicStyle::len() + ViewBasicStyle::len())
     }
 
     fn set_from_str(&mut self, _key: &str, _value: &LiveValue, _state: Self::State) -> () {
@@ -122,6 +126,7 @@ impl BasicStyle for SelectItemBasicStyle {
     fn sync(&mut self, state: Self::State) -> () {
         self.icon.sync(state.into());
         self.text.sync(state.into());
+        self.description.sync(state.into());
         self.suffix.sync(state.into());
     }
 
@@ -130,6 +135,7 @@ impl BasicStyle for SelectItemBasicStyle {
             (live_id!(container), ViewBasicStyle::live_props().into()),
             (live_id!(icon), SvgBasicStyle::live_props().into()),
             (live_id!(text), LabelBasicStyle::live_props().into()),
+            (live_id!(description), LabelBasicStyle::live_props().into()),
             (live_id!(suffix), SvgBasicStyle::live_props().into()),
         ]
     }
@@ -159,6 +165,10 @@ impl SlotBasicStyle for SelectItemBasicStyle {
             }
             SelectItemPart::Icon => self.icon.set_from_str(key, &value.into(), state.into()),
             SelectItemPart::Text => self.text.set_from_str(key, &value.into(), state.into()),
+            SelectItemPart::Description => {
+                self.description
+                    .set_from_str(key, &value.into(), state.into())
+            }
             SelectItemPart::Suffix => self.suffix.set_from_str(key, &value.into(), state.into()),
         }
     }
@@ -168,6 +178,7 @@ impl SlotBasicStyle for SelectItemBasicStyle {
             SelectItemPart::Container => self.container.sync(state.into()),
             SelectItemPart::Icon => self.icon.sync(state.into()),
             SelectItemPart::Text => self.text.sync(state.into()),
+            SelectItemPart::Description => self.description.sync(state.into()),
             SelectItemPart::Suffix => self.suffix.sync(state.into()),
         }
     }
@@ -184,6 +195,7 @@ from_prop_to_toml! {
         container => CONTAINER,
         icon => ICON,
         text => TEXT,
+        description => DESCRIPTION,
         suffix => SUFFIX
     }
 }
@@ -218,6 +230,18 @@ impl TryFrom<(&InlineTable, SelectState)> for SelectItemBasicStyle {
             |v| (v, LabelState::from(state)).try_into(),
         )?;
 
+        let description = get_from_itable(
+            inline_table,
+            DESCRIPTION,
+            || {
+                Ok(SelectItemBasicStyle::default_description(
+                    Theme::default(),
+                    state,
+                ))
+            },
+            |v| (v, LabelState::from(state)).try_into(),
+        )?;
+
         let suffix = get_from_itable(
             inline_table,
             SUFFIX,
@@ -234,6 +258,7 @@ impl TryFrom<(&InlineTable, SelectState)> for SelectItemBasicStyle {
             container,
             icon,
             text,
+            description,
             suffix,
         })
     }
@@ -284,6 +309,15 @@ impl SelectItemBasicStyle {
         label.width = Size::Fill;
         label
     }
+    pub fn default_description(theme: Theme, state: SelectState) -> LabelBasicStyle {
+        let mut label = LabelBasicStyle::from_state(theme, state.into());
+        label.width = Size::Fill;
+        label.set_font_size(10.0);
+        if !state.is_disabled() {
+            label.set_color(ColorFontConf::from_key(SECONDARY).into());
+        }
+        label
+    }
     pub fn default_suffix(theme: Theme, state: SelectState) -> SvgBasicStyle {
         SvgBasicStyle::from_state(theme, state.into())
     }
@@ -294,6 +328,7 @@ component_part! {
         Container => container => CONTAINER,
         Icon => icon => ICON,
         Text => text => TEXT,
+        Description => description => DESCRIPTION,
         Suffix => suffix => SUFFIX
     }, SelectState
 }
@@ -19,11 +19,12 @@ use crate::{
         SlotStyle, Style, ViewBasicStyle,
         item::{GSelectItem, SelectItemBasicStyle},
         options::GSelectOptions,
+        place_popup, popup_viewport,
     },
     error::Error,
     lifecycle, play_animation,
     prop::{
-        ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, DeferWalks, ToStateMap,
+        ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, DeferWalks, Position, ToStateMap,
         manuel::{ACTIVE, BASIC, DISABLED, HOVER},
         traits::ToFloat,
     },
@@ -138,6 +139,9 @@ pub struct GSelect {
     defer_walks: DeferWalks,
     #[rust(true)]
     pub redraw_flag: bool,
+    /// natural size of the options in the last draw
+    #[rust]
+    options_size: DVec2,
 }
 
 #[derive(Default, Clone)]
@@ -238,17 +242,45 @@ impl Widget for GSelect {
             let global = cx.global::<SelectOptionsGlobal>().clone();
             let mut map = global.map.borrow_mut();
             let options_menu = map.get_mut(&self.select_options.unwrap()).unwrap();
+            let area = self.area().rect(cx);
+            let viewport = popup_viewport(cx, 8.0);
+            // options have not been measured yet, place them by the counted height
+            if self.options_size.y == 0.0 {
+                self.options_size = dvec2(area.size.x, options_menu.count_real_height(cx));
+            }
+            // flip above the select if there is no room below, options taller than the space are clipped
+            let placement = place_popup(
+                Position::BottomLeft,
+                area,
+                self.options_size,
+                viewport,
+                2.0,
+                DVec2::default(),
+                true,
+            );
             let mut options_walk = options_menu.walk();
-            options_walk.width = Size::Fixed(self.area().rect(cx).size.x);
+            options_walk.width = Size::Fixed(area.size.x);
+            if self.options_size.y > placement.max_size.y {
+                options_walk.height = Size::Fixed(placement.max_size.y);
+            }
             options_menu.begin(cx, options_walk);
             options_menu.draw_popup(cx, scope, None, 0.0, &mut self.redraw_flag);
             // options_menu.item = self.option;
-            let area = self.area().rect(cx);
-            let shift = DVec2 {
-                x: 0.0,
-                y: area.size.y + 2.0,
-            };
-            options_menu.end(cx, scope, self.area(), shift);
+            let size = options_menu.area().rect(cx).size;
+            if !matches!(options_walk.height, Size::Fixed(_)) && size != self.options_size {
+                self.options_size = size;
+                options_menu.redraw(cx);
+            }
+            let placement = place_popup(
+                Position::BottomLeft,
+                area,
+                size,
+                viewport,
+                2.0,
+                DVec2::default(),
+                true,
+            );
+            options_menu.end(cx, scope, self.area(), placement.shift);
         }
 
         self.set_scope_path(&scope.path);
@@ -292,6 +324,27 @@ impl Widget for GSelect {
                     },
                 );
 
+                if let Event::KeyDown(e) = event {
+                    match e.key_code {
+                        KeyCode::ArrowDown => select_options.hover_step(cx, 1),
+                        KeyCode::ArrowUp => select_options.hover_step(cx, -1),
+                        KeyCode::ReturnKey => {
+                            if let Some(e) = select_options.select_hovered(cx) {
+                                self.value = e.value.to_string();
+                                active_index = Some(e.index);
+                                cx.widget_action(uid, &scope.path, SelectEvent::Changed(e));
+                            }
+                        }
+                        KeyCode::Escape => {
+                            select_options.clear_filter(cx);
+                            self.switch_state_with_animation(cx, SelectState::Basic);
+                            self.close_inner(cx, false);
+                            return;
+                        }
+                        _ => {}
+                    }
+                }
+
                 if let Some(index) = active_index {
                     self.selected = index as u32;
                     self.item
@@ -301,6 +354,9 @@ impl Widget for GSelect {
 
                 if let Event::MouseDown(e) = event {
                     let is_in = select_options.menu_contains_pos(cx, e.abs);
+                    if !is_in {
+                        select_options.clear_filter(cx);
+                    }
                     self.switch_state_with_animation(cx, SelectState::Basic);
                     self.close_inner(cx, is_in);
                     return;
@@ -671,21 +727,38 @@ impl GSelect {
         cx.sweep_lock(self.area());
     }
 
+    /// select the option which has the value without emitting `SelectEvent::Changed`,
+    /// `false` if there is no such option
+    pub fn select_value(&mut self, cx: &mut Cx, value: &str) -> bool {
+        let Some(menu) = self.select_options else {
+            return false;
+        };
+        let global = cx.global::<SelectOptionsGlobal>().clone();
+        let mut map = global.map.borrow_mut();
+        let Some(options) = map.get_mut(&menu) else {
+            return false;
+        };
+        let Some(index) = options
+            .children
+            .iter()
+            .position(|(_, child)| child.value == value)
+        else {
+            return false;
+        };
+        for (i, (_, child)) in options.children.iter_mut().enumerate() {
+            child.toggle(cx, i == index, false);
+        }
+        self.value = value.to_string();
+        self.selected = index as u32;
+        self.item.clone_from_ptr(cx, &options.children[index].1);
+        self.redraw(cx);
+        true
+    }
     pub fn count_real_height(&self, cx: &mut Cx) -> f64 {
-        let font_metrics = cx.global::<Conf>().theme.font.metrics;
+        // the item style and state are synced from the select
         let style = self.style.get(self.state);
-        let text_style = style.item.text;
-        let padding = text_style.padding.top
-            + text_style.padding.bottom
-            + style.item.container.padding.top
-            + style.item.container.padding.bottom
+        self.item.count_real_height(cx)
             + style.container.padding.top
-            + style.container.padding.bottom;
-        let margin = text_style.margin.top
-            + text_style.margin.bottom
-            + style.item.container.margin.top
-            + style.item.container.margin.bottom;
-
-        ((text_style.font_size * font_metrics) as f64) + padding + margin + 0.8
+            + style.container.padding.bottom
     }
 }
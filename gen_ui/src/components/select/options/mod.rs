@@ -5,7 +5,7 @@ pub use prop::*;
 
 use crate::{
     components::{
-        PopupContainerBasicStyle,
+        GInput, PopupContainerBasicStyle,
         item::GSelectItem,
         lifecycle::LifeCycle,
         popup::PopupState,
@@ -32,6 +32,15 @@ pub struct GSelectOptions {
     pub style: SelectOptionsStyle,
     #[rust]
     pub children: Vec<(LiveId, GSelectItem)>,
+    /// filter input on the top of options
+    #[live]
+    pub search: GInput,
+    /// show the filter input and narrow options as the user types
+    #[live]
+    pub searchable: bool,
+    /// index of the item hovered by keyboard navigation
+    #[rust]
+    pub hover_index: Option<usize>,
     #[rust]
     live_update_order: SmallVec<[LiveId; 1]>,
     #[live]
@@ -214,7 +223,14 @@ impl PopupComponent for GSelectOptions {
         _angle_offset: f32,
         _redraw: &mut bool,
     ) -> () {
+        if self.searchable {
+            let walk = self.search.walk(cx);
+            let _ = self.search.draw_walk(cx, scope, walk);
+        }
         for (_id, child) in self.children.iter_mut() {
+            if child.filtered {
+                continue;
+            }
             let walk = child.walk(cx);
             let _ = child.draw_walk(cx, scope, walk);
         }
@@ -250,6 +266,15 @@ impl GSelectOptions {
         sweep_area: Area,
         dispatch_action: &mut dyn FnMut(&mut Cx, SelectOptionsEvent),
     ) {
+        if self.searchable {
+            let actions = cx.capture_actions(|cx| {
+                self.search.handle_event(cx, event, &mut Scope::empty());
+            });
+            if let Some(e) = self.search.input.changed(&actions) {
+                self.filter(cx, &e.value);
+            }
+        }
+
         let mut action = None;
         for (index, (_, child)) in self.children.iter_mut().enumerate() {
             if action.is_some() {
@@ -290,4 +315,128 @@ impl GSelectOptions {
     pub fn menu_contains_pos(&self, cx: &mut Cx, pos: DVec2) -> bool {
         self.draw_options.area().clipped_rect(cx).contains(pos)
     }
+
+    /// ## height of the options menu
+    /// counts the search input (if searchable) and every item which is not hidden or filtered
+    pub fn count_real_height(&self, cx: &mut Cx) -> f64 {
+        let style = self.style.get(PopupState::Basic);
+        let mut heights = Vec::with_capacity(self.children.len() + 1);
+        if self.searchable {
+            let search_style = self.search.style.get(self.search.state);
+            heights.push(
+                self.search.count_real_height(cx)
+                    + search_style.container.margin.top
+                    + search_style.container.margin.bottom,
+            );
+        }
+        for (_, child) in self.children.iter() {
+            if child.visible && !child.filtered {
+                heights.push(child.count_real_height(cx));
+            }
+        }
+        let spacing = style.spacing * (heights.len().saturating_sub(1) as f64);
+
+        heights.iter().sum::<f64>() + spacing + style.padding.top + style.padding.bottom
+    }
+
+    /// ## filter options by keyword
+    /// items whose text or value contains the keyword (case insensitive) stay visible,
+    /// a group header stays visible if any item in its group is visible.
+    /// an empty keyword shows all items.
+    pub fn filter(&mut self, cx: &mut Cx, keyword: &str) -> () {
+        let keyword = keyword.trim().to_lowercase();
+        for (_, child) in self.children.iter_mut() {
+            child.filtered = !keyword.is_empty()
+                && !child.group
+                && !child.text.text.as_ref().to_lowercase().contains(&keyword)
+                && !child.value.to_lowercase().contains(&keyword);
+        }
+        // hide group headers which have no visible items
+        let mut header: Option<usize> = None;
+        let mut header_visible = false;
+        for index in 0..self.children.len() {
+            if self.children[index].1.group {
+                if let Some(header) = header {
+                    self.children[header].1.filtered = !header_visible;
+                }
+                header = Some(index);
+                header_visible = keyword.is_empty();
+            } else if !self.children[index].1.filtered {
+                header_visible = true;
+            }
+        }
+        if let Some(header) = header {
+            self.children[header].1.filtered = !header_visible;
+        }
+
+        self.set_hover_index(cx, None);
+        self.redraw(cx);
+    }
+
+    /// ## clear filter input and show all options
+    pub fn clear_filter(&mut self, cx: &mut Cx) -> () {
+        if !self.searchable {
+            return;
+        }
+        self.search.input.set_text(cx, "");
+        self.filter(cx, "");
+    }
+
+    /// ## move keyboard hover to next (`step > 0`) or previous (`step < 0`) selectable item
+    /// group headers, disabled and filtered items are skipped
+    pub fn hover_step(&mut self, cx: &mut Cx, step: isize) -> () {
+        let len = self.children.len() as isize;
+        if len == 0 || step == 0 {
+            return;
+        }
+        let start = match self.hover_index {
+            Some(index) => index as isize,
+            None => {
+                // start from current active item or from the edge
+                match self.children.iter().position(|(_, child)| child.active) {
+                    Some(index) => index as isize,
+                    None if step > 0 => -1,
+                    None => len,
+                }
+            }
+        };
+        let direction = step.signum();
+        let mut index = start + direction;
+        while index >= 0 && index < len {
+            if self.children[index as usize].1.is_selectable() {
+                self.set_hover_index(cx, Some(index as usize));
+                return;
+            }
+            index += direction;
+        }
+    }
+
+    fn set_hover_index(&mut self, cx: &mut Cx, index: Option<usize>) -> () {
+        if let Some(last) = self.hover_index.take() {
+            if let Some((_, child)) = self.children.get_mut(last) {
+                child.hover(cx, false);
+            }
+        }
+        if let Some(index) = index {
+            if let Some((_, child)) = self.children.get_mut(index) {
+                child.hover(cx, true);
+            }
+        }
+        self.hover_index = index;
+    }
+
+    /// ## select the item hovered by keyboard navigation
+    /// return the changed event if an item is selected
+    pub fn select_hovered(&mut self, cx: &mut Cx) -> Option<SelectChangedEvent> {
+        let index = self.hover_index.take()?;
+        let value = self.children.get(index)?.1.value.to_string();
+        for (i, (_, child)) in self.children.iter_mut().enumerate() {
+            child.toggle(cx, i == index, false);
+        }
+        Some(SelectChangedEvent {
+            meta: None,
+            value,
+            index,
+        })
+    }
 }
@@ -185,6 +185,7 @@ impl SlotBasicStyle for SelectBasicStyle {
             SelectPart::Select => {
                 self.item.sync_slot(state, SelectItemPart::Container);
                 self.item.sync_slot(state, SelectItemPart::Text);
+                self.item.sync_slot(state, SelectItemPart::Description);
                 self.item.sync_slot(state, SelectItemPart::Icon);
                 self.item.sync_slot(state, SelectItemPart::Suffix);
             }
@@ -28,6 +28,8 @@ pub struct SliderChanged {
     pub value: f64,
     pub step: f64,
     pub range: [f64; 2],
+    /// `(start, end)` in range mode, `end` is the same as `value`
+    pub range_value: Option<(f64, f64)>,
 }
 
 #[derive(Debug, Clone)]
@@ -8,7 +8,7 @@ pub use prop::*;
 
 use crate::{
     ComponentAnInit, active_event, animation_open_then_redraw,
-    components::{BasicStyle, Component, LifeCycle, Style},
+    components::{BasicStyle, Component, GLabel, LifeCycle, Style},
     error::Error,
     lifecycle, play_animation,
     prop::{
@@ -17,7 +17,7 @@ use crate::{
         traits::ToFloat,
     },
     pure_after_apply, set_animation, set_index, set_scope_path,
-    shader::draw_slider::DrawSlider as DrawGSlider,
+    shader::{draw_slider::DrawSlider as DrawGSlider, draw_view::DrawView},
     switch_state, sync,
     themes::conf::Conf,
     utils::{normalization, round_2_decimals_f32, round_step},
@@ -125,6 +125,43 @@ pub struct GSlider {
     pub step: f32,
     #[live(1.0)]
     pub proportion: f32,
+    // --- range ----------------------
+    /// range mode, two draggers select `[start, value]`, only horizontal and vertical mode
+    #[live]
+    pub range: bool,
+    /// start of the range (only range mode), `value` is the end
+    #[live(0.0)]
+    pub start: f32,
+    // --- marks ----------------------
+    /// tick marks at these values, labels are drawn under the track (horizontal)
+    /// or on the right of the track (vertical), leave space for them with margin
+    #[live]
+    pub marks: Vec<f32>,
+    /// label of each mark, use the mark value if not set
+    #[live]
+    pub mark_labels: Vec<String>,
+    /// only stop on marks when dragging
+    #[live]
+    pub snap_to_marks: bool,
+    #[live]
+    pub mark: Option<LivePtr>,
+    #[live]
+    pub draw_mark: DrawView,
+    #[rust]
+    pub mark_items: Vec<GLabel>,
+    // --- tooltip --------------------
+    /// show current value above the dragger while dragging
+    #[live(true)]
+    pub tooltip: bool,
+    #[live]
+    pub draw_tooltip: DrawView,
+    #[live]
+    pub tooltip_text: GLabel,
+    #[rust]
+    dragging: bool,
+    /// the start dragger is active (only range mode)
+    #[rust]
+    start_active: bool,
 }
 
 impl WidgetNode for GSlider {
@@ -170,6 +207,11 @@ impl Widget for GSlider {
         let style = self.style.get(self.state);
         let _ = self.draw_slider.begin(cx, walk, style.layout());
         let _ = self.draw_slider.end(cx);
+        let rect = self.draw_slider.area.rect(cx);
+        self.draw_marks(cx, scope, rect);
+        if self.tooltip && self.dragging {
+            self.draw_tooltip(cx, scope, rect);
+        }
         self.set_scope_path(&scope.path);
         return DrawStep::done();
     }
@@ -231,6 +273,12 @@ impl LiveHook for GSlider {
                 _ => {}
             },
         );
+        if self.range && matches!(self.mode, ProgressMode::Circle) {
+            // the circle track has no room for two draggers
+            error!("GSlider: {}", Error::ConflictRangeMode);
+            self.range = false;
+        }
+        self.start = self.start.clamp(self.min, self.max);
     }
 }
 
@@ -254,6 +302,10 @@ impl Component for GSlider {
         self.draw_slider.value = normalization(self.value, self.min, self.max);
         self.draw_slider.step = self.step;
         self.draw_slider.proportion = self.proportion.clamp(0.0, 1.0);
+        self.draw_slider.range = self.range.to_f32();
+        self.draw_slider.start = normalization(self.start.min(self.value), self.min, self.max);
+        self.draw_mark.background_color = style.color;
+        self.draw_tooltip.background_color = style.color;
         Ok(())
     }
 
@@ -266,6 +318,13 @@ impl Component for GSlider {
                 if self.grab_key_focus {
                     cx.set_key_focus(area);
                 }
+                if self.range {
+                    // drag the nearest dragger
+                    let v = self.value_from_finger(e.abs, e.rect);
+                    self.start_active = (v - self.start).abs() < (v - self.value).abs()
+                        || (v < self.start && self.start == self.value);
+                }
+                self.dragging = true;
                 self.play_animation(cx, id!(dragging.on));
                 self.active_finger_down(cx, e);
             }
@@ -281,6 +340,7 @@ impl Component for GSlider {
                 self.active_hover_out(cx, e);
             }
             Hit::FingerUp(e) => {
+                self.dragging = false;
                 if e.is_over {
                     if e.has_hovers() {
                         self.switch_state_with_animation(cx, SliderState::Hover);
@@ -298,30 +358,28 @@ impl Component for GSlider {
                 }
             }
             Hit::FingerMove(e) => {
-                match self.mode {
-                    ProgressMode::Horizontal | ProgressMode::Circle => {
-                        let real_len = e.abs.x - e.rect.pos.x;
-                        // percentage
-                        let v = (real_len / e.rect.size.x).clamp(0.0, 1.0);
-                        self.value = round_step(
-                            round_2_decimals_f32((v as f32) * (self.max - self.min) + self.min),
-                            self.step,
-                        );
-                    }
-                    ProgressMode::Vertical => {
-                        // For vertical mode, we need to invert the calculation
-                        // because y increases downward, but we want progress to increase upward
-                        let real_len = e.rect.pos.y + e.rect.size.y - e.abs.y;
-                        // percentage
-                        let v = (real_len / e.rect.size.y).clamp(0.0, 1.0);
-                        self.value = round_step(
-                            round_2_decimals_f32((v as f32) * (self.max - self.min) + self.min),
-                            self.step,
-                        );
-                    }
-                }
+                let v = self.snap(self.value_from_finger(e.abs, e.rect));
+                self.set_active_value(v);
                 self.switch_state_with_animation(cx, SliderState::Dragging);
             }
+            Hit::KeyDown(e) => {
+                let delta = match e.key_code {
+                    KeyCode::ArrowRight | KeyCode::ArrowUp => self.step,
+                    KeyCode::ArrowLeft | KeyCode::ArrowDown => -self.step,
+                    _ => return,
+                };
+                let current = if self.range && self.start_active {
+                    self.start
+                } else {
+                    self.value
+                };
+                let v = round_2_decimals_f32(current + delta).clamp(self.min, self.max);
+                if v != current {
+                    self.set_active_value(v);
+                    self.redraw(cx);
+                    self.active_changed(cx, None);
+                }
+            }
             _ => {}
         };
     }
@@ -554,9 +612,186 @@ impl GSlider {
                         value: self.value as f64,
                         step: self.step as f64,
                         range: [self.min as f64, self.max as f64],
+                        range_value: self.range.then(|| (self.start as f64, self.value as f64)),
                     }),
                 );
             });
         }
     }
+    /// `(start, end)` of the range, `end` is the same as `value`
+    pub fn range_value(&self) -> (f32, f32) {
+        (self.start, self.value)
+    }
+    pub fn set_range_value(&mut self, cx: &mut Cx, start: f32, end: f32) {
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        self.start = start.clamp(self.min, self.max);
+        self.value = end.clamp(self.min, self.max);
+        self.redraw(cx);
+    }
+    /// value under the finger, not rounded
+    fn value_from_finger(&self, abs: DVec2, rect: Rect) -> f32 {
+        let v = match self.mode {
+            ProgressMode::Horizontal | ProgressMode::Circle => (abs.x - rect.pos.x) / rect.size.x,
+            // y increases downward, but we want progress to increase upward
+            ProgressMode::Vertical => (rect.pos.y + rect.size.y - abs.y) / rect.size.y,
+        };
+        (v.clamp(0.0, 1.0) as f32) * (self.max - self.min) + self.min
+    }
+    /// snap to the nearest mark if `snap_to_marks`, otherwise round by step
+    fn snap(&self, v: f32) -> f32 {
+        if self.snap_to_marks && !self.marks.is_empty() {
+            self.marks
+                .iter()
+                .copied()
+                .min_by(|a, b| (a - v).abs().total_cmp(&(b - v).abs()))
+                .unwrap_or(v)
+        } else {
+            round_step(round_2_decimals_f32(v), self.step)
+        }
+    }
+    /// set the value of the active dragger, start never passes end in range mode
+    fn set_active_value(&mut self, v: f32) {
+        if !self.range {
+            self.value = v;
+        } else if self.start_active {
+            self.start = v.min(self.value);
+        } else {
+            self.value = v.max(self.start);
+        }
+    }
+    /// center of the dragger at `value` on the track
+    fn value_pos(&self, rect: Rect, value: f32) -> DVec2 {
+        let v = normalization(value, self.min, self.max) as f64;
+        match self.mode {
+            ProgressMode::Vertical => dvec2(
+                rect.pos.x + rect.size.x * 0.5,
+                rect.pos.y + rect.size.y * (1.0 - v),
+            ),
+            _ => dvec2(rect.pos.x + rect.size.x * v, rect.pos.y + rect.size.y * 0.5),
+        }
+    }
+    fn draw_marks(&mut self, cx: &mut Cx2d, scope: &mut Scope, rect: Rect) {
+        if self.marks.is_empty() || matches!(self.mode, ProgressMode::Circle) {
+            return;
+        }
+        if self.mark_items.len() != self.marks.len() {
+            self.mark_items = self
+                .marks
+                .iter()
+                .map(|_| GLabel::new_from_ptr(cx, self.mark))
+                .collect();
+        }
+        let marks = self
+            .marks
+            .iter()
+            .enumerate()
+            .map(|(index, mark)| {
+                let text = self
+                    .mark_labels
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| mark.to_string());
+                (self.value_pos(rect, *mark), text)
+            })
+            .collect::<Vec<_>>();
+
+        for ((pos, text), label) in marks.into_iter().zip(self.mark_items.iter_mut()) {
+            self.draw_mark.draw_abs(
+                cx,
+                Rect {
+                    pos: pos - dvec2(MARK_SIZE * 0.5, MARK_SIZE * 0.5),
+                    size: dvec2(MARK_SIZE, MARK_SIZE),
+                },
+            );
+            let _ = label.set_text(cx, text);
+            let (walk, align) = match self.mode {
+                ProgressMode::Vertical => (
+                    Walk {
+                        abs_pos: Some(dvec2(
+                            rect.pos.x + rect.size.x + MARK_GAP,
+                            pos.y - MARK_LABEL_HEIGHT * 0.5,
+                        )),
+                        margin: Margin::default(),
+                        width: Size::Fit,
+                        height: Size::Fixed(MARK_LABEL_HEIGHT),
+                    },
+                    Align { x: 0.0, y: 0.5 },
+                ),
+                _ => (
+                    Walk {
+                        abs_pos: Some(dvec2(
+                            pos.x - MARK_LABEL_WIDTH * 0.5,
+                            rect.pos.y + rect.size.y + MARK_GAP,
+                        )),
+                        margin: Margin::default(),
+                        width: Size::Fixed(MARK_LABEL_WIDTH),
+                        height: Size::Fit,
+                    },
+                    Align { x: 0.5, y: 0.0 },
+                ),
+            };
+            cx.begin_turtle(
+                walk,
+                Layout {
+                    align,
+                    ..Default::default()
+                },
+            );
+            let label_walk = label.walk(cx);
+            let _ = label.draw_walk(cx, scope, label_walk);
+            cx.end_turtle();
+        }
+    }
+    fn draw_tooltip(&mut self, cx: &mut Cx2d, scope: &mut Scope, rect: Rect) {
+        if matches!(self.mode, ProgressMode::Circle) {
+            return;
+        }
+        let value = if self.range && self.start_active {
+            self.start
+        } else {
+            self.value
+        };
+        let pos = self.value_pos(rect, value);
+        let _ = self
+            .tooltip_text
+            .set_text(cx, round_2_decimals_f32(value).to_string());
+        // above the dragger (horizontal) or on the left of the dragger (vertical)
+        let abs_pos = match self.mode {
+            ProgressMode::Vertical => dvec2(
+                rect.pos.x - TOOLTIP_WIDTH - MARK_GAP,
+                pos.y - TOOLTIP_HEIGHT * 0.5,
+            ),
+            _ => dvec2(
+                pos.x - TOOLTIP_WIDTH * 0.5,
+                rect.pos.y - TOOLTIP_HEIGHT - MARK_GAP,
+            ),
+        };
+        self.draw_tooltip.begin(
+            cx,
+            Walk {
+                abs_pos: Some(abs_pos),
+                margin: Margin::default(),
+                width: Size::Fixed(TOOLTIP_WIDTH),
+                height: Size::Fixed(TOOLTIP_HEIGHT),
+            },
+            Layout {
+                align: Align { x: 0.5, y: 0.5 },
+                ..Default::default()
+            },
+        );
+        let text_walk = self.tooltip_text.walk(cx);
+        let _ = self.tooltip_text.draw_walk(cx, scope, text_walk);
+        self.draw_tooltip.end(cx);
+    }
 }
+
+const MARK_SIZE: f64 = 4.0;
+const MARK_GAP: f64 = 6.0;
+const MARK_LABEL_WIDTH: f64 = 48.0;
+const MARK_LABEL_HEIGHT: f64 = 16.0;
+const TOOLTIP_WIDTH: f64 = 48.0;
+const TOOLTIP_HEIGHT: f64 = 24.0;
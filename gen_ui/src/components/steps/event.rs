@@ -0,0 +1,15 @@
+use makepad_widgets::*;
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum StepsEvent {
+    Changed(StepsChanged),
+    None,
+}
+
+/// a step is clicked and becomes the current one
+#[derive(Debug, Clone)]
+pub struct StepsChanged {
+    pub meta: FingerUpEvent,
+    pub current: usize,
+    pub last: usize,
+}
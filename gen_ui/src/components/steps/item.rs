@@ -0,0 +1,139 @@
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        GDividerWidgetExt, badge::dot::GBadgeDotWidgetExt, label::GLabelWidgetExt, view::GView,
+    },
+    inherits_view_livehook, inherits_view_widget_node,
+    prop::{Direction, manuel::SECONDARY},
+    themes::Theme,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GStepBase = {{GStep}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Down,
+                spacing: 4.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// state of a step, decided by the `current` of `GSteps`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StepStatus {
+    Finished,
+    Current,
+    /// the current step is failed (`GSteps::error`)
+    Error,
+    #[default]
+    Waiting,
+}
+
+impl StepStatus {
+    pub fn theme(&self) -> Theme {
+        match self {
+            StepStatus::Finished => Theme::Success,
+            StepStatus::Current => Theme::Primary,
+            StepStatus::Error => Theme::Error,
+            StepStatus::Waiting => Theme::Dark,
+        }
+    }
+}
+
+/// a step of `GSteps`, the template contains:
+/// - `header`: `indicator` (`GBadgeDot` shows the number), `title` label and `line` (`GDivider`)
+/// - `description` label
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GStep {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub title: String,
+    #[live]
+    pub description: String,
+    /// set by `GSteps`
+    #[rust]
+    pub status: StepStatus,
+    #[rust]
+    pub index: usize,
+    #[rust]
+    pub direction: Direction,
+    /// the last step hides the line
+    #[rust]
+    pub last: bool,
+}
+
+inherits_view_widget_node!(GStep);
+
+impl Widget for GStep {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        self.deref_widget.handle_event(cx, event, scope);
+    }
+}
+
+impl LiveHook for GStep {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GStep {
+    pub fn render(&mut self, cx: &mut Cx) -> () {
+        let horizontal = self.direction == Direction::Horizontal;
+        // the last horizontal step does not need the space of the line
+        self.deref_widget.style.basic.width = if horizontal && self.last {
+            Size::Fit
+        } else {
+            Size::Fill
+        };
+        let theme = self.status.theme();
+        if let Some(mut indicator) = self.gbadge_dot(id!(indicator)).borrow_mut() {
+            indicator.style.basic.container.background_color = theme.color(500).into();
+            let text = match self.status {
+                StepStatus::Error => "!".to_string(),
+                _ => (self.index + 1).to_string(),
+            };
+            let _ = indicator.text.set_text(cx, text);
+            indicator.redraw(cx);
+        }
+        let title = self.glabel(id!(title));
+        let _ = title.set_text(cx, self.title.to_string());
+        if let Some(mut title) = title.borrow_mut() {
+            title.style.basic.color = match self.status {
+                StepStatus::Waiting => Theme::Dark.color(300).into(),
+                StepStatus::Error => Theme::Error.color(400).into(),
+                _ => Theme::Dark.color(50).into(),
+            };
+        }
+        let description = self.glabel(id!(description));
+        let _ = description.set_text(cx, self.description.to_string());
+        let _ = description.set_visible(cx, !self.description.is_empty());
+        description.set_default_font_color(cx, SECONDARY);
+        if let Some(mut line) = self.gdivider(id!(line)).borrow_mut() {
+            // the vertical steps are separated by the spacing
+            line.visible = horizontal && !self.last;
+            line.style.basic.background_color = if self.status == StepStatus::Finished {
+                theme.color(500).into()
+            } else {
+                Theme::Dark.color(400).into()
+            };
+            line.redraw(cx);
+        }
+        self.redraw(cx);
+    }
+}
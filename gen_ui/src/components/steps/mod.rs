@@ -0,0 +1,184 @@
+mod event;
+pub mod item;
+mod register;
+
+pub use event::*;
+pub use item::*;
+pub use register::register as steps_register;
+
+use makepad_widgets::*;
+
+use crate::{
+    components::view::{GView, GViewWidgetExt},
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+    prop::Direction,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GStepsBase = {{GSteps}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                spacing: 8.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// the indicator of a wizard, the children are `GStep`
+///
+/// the steps before `current` are finished, the steps after it are waiting
+///
+/// ```rust,ignore
+/// <GSteps> {
+///     current: 1,
+///     <GStep> {title: "Login"}
+///     <GStep> {title: "Verify", description: "check the email"}
+///     <GStep> {title: "Done"}
+/// }
+/// ```
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GSteps {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub direction: Direction,
+    /// index of the current step, `len` means all the steps are finished
+    #[live]
+    pub current: usize,
+    /// the current step is failed
+    #[live]
+    pub error: bool,
+    /// click a step to make it current
+    #[live(true)]
+    pub clickable: bool,
+    #[live(true)]
+    pub event_open: bool,
+}
+
+inherits_view_widget_node!(GSteps);
+
+impl Widget for GSteps {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if !self.clickable {
+            return;
+        }
+        let clicked =
+            self.deref_widget
+                .children
+                .iter()
+                .enumerate()
+                .find_map(|(index, (_, step))| {
+                    step.gview(id!(header))
+                        .clicked(&actions)
+                        .map(|e| (index, e.meta))
+                });
+        if let Some((index, meta)) = clicked {
+            if index != self.current {
+                let last = self.current;
+                self.set_current(cx, index);
+                if self.event_open {
+                    cx.widget_action(
+                        self.widget_uid(),
+                        &scope.path,
+                        StepsEvent::Changed(StepsChanged {
+                            meta,
+                            current: index,
+                            last,
+                        }),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl LiveHook for GSteps {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GSteps {
+    fn render(&mut self, cx: &mut Cx) -> () {
+        self.deref_widget.style.basic.flow = match self.direction {
+            Direction::Horizontal => Flow::Right,
+            Direction::Vertical => Flow::Down,
+        };
+        let len = self.deref_widget.children.len();
+        for (index, (_, child)) in self.deref_widget.children.iter().enumerate() {
+            if let Some(mut step) = child.as_gstep().borrow_mut() {
+                step.index = index;
+                step.last = index + 1 == len;
+                step.direction = self.direction;
+                step.status = if index < self.current {
+                    StepStatus::Finished
+                } else if index == self.current {
+                    if self.error {
+                        StepStatus::Error
+                    } else {
+                        StepStatus::Current
+                    }
+                } else {
+                    StepStatus::Waiting
+                };
+                step.render(cx);
+            }
+        }
+        self.redraw(cx);
+    }
+    pub fn set_current(&mut self, cx: &mut Cx, current: usize) -> () {
+        self.current = current;
+        self.error = false;
+        self.render(cx);
+    }
+    pub fn set_error(&mut self, cx: &mut Cx, error: bool) -> () {
+        self.error = error;
+        self.render(cx);
+    }
+    /// finish the current step and move to the next one
+    pub fn next(&mut self, cx: &mut Cx) -> () {
+        let len = self.deref_widget.children.len();
+        self.set_current(cx, (self.current + 1).min(len));
+    }
+    pub fn prev(&mut self, cx: &mut Cx) -> () {
+        self.set_current(cx, self.current.saturating_sub(1));
+    }
+    event_option! {
+        changed: StepsEvent::Changed => StepsChanged
+    }
+}
+
+impl GStepsRef {
+    pub fn set_current(&self, cx: &mut Cx, current: usize) -> () {
+        self.borrow_mut().map(|mut c| c.set_current(cx, current));
+    }
+    pub fn set_error(&self, cx: &mut Cx, error: bool) -> () {
+        self.borrow_mut().map(|mut c| c.set_error(cx, error));
+    }
+    pub fn next(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.next(cx));
+    }
+    pub fn prev(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.prev(cx));
+    }
+    pub fn current(&self) -> usize {
+        self.borrow().map_or(0, |c| c.current)
+    }
+    event_option_ref! {
+        changed => StepsChanged
+    }
+}
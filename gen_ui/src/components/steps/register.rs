@@ -0,0 +1,6 @@
+use makepad_widgets::Cx;
+
+pub fn register(cx: &mut Cx) {
+    crate::components::steps::item::live_design(cx);
+    crate::components::steps::live_design(cx);
+}
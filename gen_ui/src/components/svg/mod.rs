@@ -6,6 +6,8 @@ pub use event::*;
 pub use prop::*;
 pub use schema::*;
 
+use std::rc::Rc;
+
 use crate::{
     active_event, animation_open_then_redraw,
     components::{
@@ -85,6 +87,9 @@ live_design! {
     }
 }
 
+/// prefix of the dependencies registered by `GSvg::load_data`
+const SVG_DATA_SCHEME: &str = "genui-svg://";
+
 #[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
 pub struct GSvg {
     #[live]
@@ -540,6 +545,24 @@ impl GSvg {
         self.src = other.src.clone();
         self.state = other.state;
     }
+    /// draw svg data which is not a live dependency (such as a file, url or base64 `GImage` src),
+    /// the data is registered as a dependency named `key` so the svg is parsed once for each key
+    pub fn load_data(&mut self, cx: &mut Cx, key: &str, data: Rc<Vec<u8>>) -> () {
+        let key = format!("{}{}", SVG_DATA_SCHEME, key);
+        cx.dependencies.insert(
+            key.to_string(),
+            CxDependency {
+                data: Some(Ok(data)),
+            },
+        );
+        self.src.apply(
+            cx,
+            &mut ApplyFrom::Over.into(),
+            0,
+            &[LiveNode::from_value(LiveValue::Dependency(Rc::new(key)))],
+        );
+        let _ = self.render(cx);
+    }
     active_event! {
         active_hover_in: SvgEvent::HoverIn |meta: FingerHoverEvent| => SvgHoverIn { meta },
         active_hover_out: SvgEvent::HoverOut |meta: FingerHoverEvent| => SvgHoverOut { meta },
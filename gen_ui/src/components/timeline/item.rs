@@ -0,0 +1,167 @@
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        GDividerWidgetExt,
+        badge::dot::GBadgeDotWidgetExt,
+        label::GLabelWidgetExt,
+        loading::GLoadingWidgetExt,
+        view::{GView, GViewWidgetExt},
+    },
+    inherits_view_livehook, inherits_view_widget_node,
+    prop::{Direction, manuel::SECONDARY},
+    themes::Theme,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GTimelineItemBase = {{GTimelineItem}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                spacing: 12.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// an item of `GTimeline`, the template contains:
+/// - `axis`: `dot` (`GBadgeDot`), `loading` (`GLoading`, shown when pending) and `line` (`GDivider`)
+/// - `body`: `time` label and `content` view, put the children of the item into `content`
+///
+/// ```rust,ignore
+/// <GTimelineItem> {
+///     time: "2025-01-01",
+///     theme: Success,
+///     content = {
+///         <GLabel> {text: "Released"}
+///     }
+/// }
+/// ```
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GTimelineItem {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub time: String,
+    /// color of the dot and the line
+    #[live]
+    pub theme: Theme,
+    /// the item is not finished, shows the loading instead of the dot
+    #[live]
+    pub pending: bool,
+    /// set by `GTimeline`
+    #[rust]
+    pub direction: Direction,
+    /// the last item of `GTimeline` hides the line
+    #[rust]
+    pub last: bool,
+}
+
+inherits_view_widget_node!(GTimelineItem);
+
+impl Widget for GTimelineItem {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+        if self.direction == Direction::Vertical {
+            self.sync_line_height(cx);
+        }
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        self.deref_widget.handle_event(cx, event, scope);
+    }
+}
+
+impl LiveHook for GTimelineItem {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GTimelineItem {
+    pub fn render(&mut self, cx: &mut Cx) -> () {
+        let vertical = self.direction == Direction::Vertical;
+        // vertical: the axis on the left of the body, horizontal: the axis above the body
+        self.deref_widget.style.basic.flow = if vertical { Flow::Right } else { Flow::Down };
+        if let Some(mut axis) = self.gview(id!(axis)).borrow_mut() {
+            let style = &mut axis.style.basic;
+            if vertical {
+                style.flow = Flow::Down;
+                style.height = Size::Fit;
+                style.width = Size::Fit;
+                style.align = Align { x: 0.5, y: 0.0 };
+            } else {
+                style.flow = Flow::Right;
+                style.height = Size::Fit;
+                style.width = Size::Fill;
+                style.align = Align { x: 0.0, y: 0.5 };
+            }
+        }
+        let color: Vec4 = self.theme.color(500).into();
+        if let Some(mut dot) = self.gbadge_dot(id!(dot)).borrow_mut() {
+            dot.visible = !self.pending;
+            dot.style.basic.container.background_color = color;
+            dot.redraw(cx);
+        }
+        if let Some(mut loading) = self.gloading(id!(loading)).borrow_mut() {
+            loading.visible = self.pending;
+            loading.set_loading(cx, self.pending);
+        }
+        if let Some(mut line) = self.gdivider(id!(line)).borrow_mut() {
+            line.visible = !self.last;
+            line.style.basic.background_color = color;
+            if vertical {
+                line.style.basic.width = Size::Fixed(1.2);
+            } else {
+                line.style.basic.height = Size::Fixed(1.2);
+                line.style.basic.width = Size::Fill;
+            }
+            line.redraw(cx);
+        }
+        let time = self.glabel(id!(time));
+        let _ = time.set_text(cx, self.time.to_string());
+        let _ = time.set_visible(cx, !self.time.is_empty());
+        time.set_default_font_color(cx, SECONDARY);
+        self.redraw(cx);
+    }
+    /// the height of the body is known after drawing, the vertical line follows it
+    fn sync_line_height(&mut self, cx: &mut Cx2d) -> () {
+        let body = self.gview(id!(body)).area().rect(cx).size.y;
+        let axis = self.gview(id!(axis)).area().rect(cx).size.y;
+        if let Some(mut line) = self.gdivider(id!(line)).borrow_mut() {
+            if !line.visible {
+                return;
+            }
+            let current = match line.style.basic.height {
+                Size::Fixed(height) => height,
+                _ => 0.0,
+            };
+            // the axis without the line
+            let rest = axis - current;
+            let height = (body - rest).max(0.0);
+            if (height - current).abs() > 0.5 {
+                line.style.basic.height = Size::Fixed(height);
+                line.redraw(cx);
+            }
+        }
+    }
+    pub fn set_pending(&mut self, cx: &mut Cx, pending: bool) -> () {
+        self.pending = pending;
+        self.render(cx);
+    }
+}
+
+impl GTimelineItemRef {
+    pub fn set_pending(&self, cx: &mut Cx, pending: bool) -> () {
+        self.borrow_mut().map(|mut c| c.set_pending(cx, pending));
+    }
+}
@@ -0,0 +1,87 @@
+pub mod item;
+mod register;
+
+pub use item::*;
+pub use register::register as timeline_register;
+
+use makepad_widgets::*;
+
+use crate::{
+    components::view::GView, inherits_view_livehook, inherits_view_widget_node, prop::Direction,
+};
+
+live_design! {
+    link genui_basic;
+
+    pub GTimelineBase = {{GTimeline}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// a list of `GTimelineItem`, `Vertical` or `Horizontal`
+///
+/// the line of the last item is hidden, mark the tail item as `pending` to show the loading
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GTimeline {
+    #[deref]
+    pub deref_widget: GView,
+    #[live]
+    pub direction: Direction,
+}
+
+inherits_view_widget_node!(GTimeline);
+
+impl Widget for GTimeline {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        self.deref_widget.handle_event(cx, event, scope);
+    }
+}
+
+impl LiveHook for GTimeline {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GTimeline {
+    fn render(&mut self, cx: &mut Cx) -> () {
+        self.deref_widget.style.basic.flow = match self.direction {
+            Direction::Horizontal => Flow::Right,
+            Direction::Vertical => Flow::Down,
+        };
+        let len = self.deref_widget.children.len();
+        for (index, (_, child)) in self.deref_widget.children.iter().enumerate() {
+            if let Some(mut item) = child.as_gtimeline_item().borrow_mut() {
+                item.direction = self.direction;
+                item.last = index + 1 == len;
+                item.render(cx);
+            }
+        }
+        self.redraw(cx);
+    }
+    pub fn set_direction(&mut self, cx: &mut Cx, direction: Direction) -> () {
+        self.direction = direction;
+        self.render(cx);
+    }
+}
+
+impl GTimelineRef {
+    pub fn set_direction(&self, cx: &mut Cx, direction: Direction) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.set_direction(cx, direction));
+    }
+}
@@ -0,0 +1,6 @@
+use makepad_widgets::Cx;
+
+pub fn register(cx: &mut Cx) {
+    crate::components::timeline::item::live_design(cx);
+    crate::components::timeline::live_design(cx);
+}
@@ -7,7 +7,7 @@ use super::event::*;
 use crate::{
     ComponentAnInit, area,
     components::{
-        BasicStyle, Component, GLabel, GSvg, GView, LabelBasicStyle, LifeCycle, SlotComponent,
+        BasicStyle, Component, GCheckbox, GLabel, GLoading, GSvg, GView, LabelBasicStyle, LifeCycle, SlotComponent,
         SlotStyle, Style, SvgBasicStyle, ViewBasicStyle,
     },
     error::Error,
@@ -104,6 +104,9 @@ pub struct GBranch {
     pub icon: GSvg,
     #[live]
     pub text: GLabel,
+    /// tri-state checkbox, visible when `GTree.checkable`
+    #[live]
+    pub checkbox: GCheckbox,
     #[live]
     pub body: GView,
     #[live]
@@ -112,6 +115,15 @@ pub struct GBranch {
     pub active: bool,
     #[live]
     pub value: String,
+    /// children are loaded by `GTree` when the branch is expanded the first time
+    #[live]
+    pub lazy: bool,
+    /// children of a lazy branch have been loaded
+    #[rust]
+    pub loaded: bool,
+    /// displayed in header while loading children
+    #[live]
+    pub loading: GLoading,
     #[live]
     pub fold: f64,
     #[live(true)]
@@ -186,10 +198,20 @@ impl Widget for GBranch {
                 let _ = self.icon.draw_walk(cx, scope, icon_walk);
             }
 
+            if self.checkbox.visible {
+                let checkbox_walk = self.checkbox.walk(cx);
+                let _ = self.checkbox.draw_walk(cx, scope, checkbox_walk);
+            }
+
             if self.text.visible {
                 let _ = self.text.draw_walk(cx, scope, text_walk);
             }
 
+            if self.loading.visible {
+                let loading_walk = self.loading.walk(cx);
+                let _ = self.loading.draw_walk(cx, scope, loading_walk);
+            }
+
             cx.end_turtle_with_area(&mut self.area_header);
 
             self.draw_state.set(DrawBranchState::DrawBody);
@@ -234,8 +256,15 @@ impl Widget for GBranch {
         }
         self.set_animation(cx);
         cx.global::<ComponentAnInit>().branch = true;
+        // checkbox first, clicking it should not fold the branch
+        if self.checkbox.visible {
+            self.checkbox.handle_event(cx, event, scope);
+        }
         let area = self.area_header;
         let hit = event.hits(cx, area);
+        if self.loading.visible {
+            self.loading.handle_event(cx, event, scope);
+        }
         if self.disabled {
             self.handle_when_disabled(cx, event, hit);
         } else {
@@ -647,9 +676,14 @@ impl GBranch {
     }
     setter! {
         GBranch {
-            set_active(active: bool) {|c, cx| {c.active = active; c.redraw(cx); Ok(())}}
+            set_active(active: bool) {|c, cx| {c.active = active; c.redraw(cx); Ok(())}},
+            set_loading(loading: bool) {|c, cx| {c.loading.visible = loading; c.redraw(cx); Ok(())}}
         }
     }
+    /// lazy branch which children have not been loaded (or loading)
+    pub fn need_load(&self) -> bool {
+        self.lazy && !self.loaded && !self.loading.visible
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -0,0 +1,285 @@
+use makepad_widgets::*;
+
+use super::{GBranchWidgetRefExt, GLeafWidgetRefExt, TreeDropPosition, filter::FilterNode};
+
+/// finger must move further than this (px) before a drag starts
+pub(crate) const DRAG_THRESHOLD: f64 = 4.0;
+/// hover on a collapsed branch for this long (s) to expand it
+pub(crate) const AUTO_EXPAND_DELAY: f64 = 0.6;
+/// distance (px) to the top/bottom edge of the tree to start auto scroll
+pub(crate) const AUTO_SCROLL_EDGE: f64 = 24.0;
+/// max auto scroll speed (px per frame)
+pub(crate) const AUTO_SCROLL_SPEED: f64 = 12.0;
+
+pub(crate) struct TreeDrag {
+    pub source: String,
+    pub start: DVec2,
+    pub dragging: bool,
+    pub target: Option<(String, TreeDropPosition)>,
+    /// rect of the drop indicator (abs)
+    pub indicator: Option<Rect>,
+    /// auto scroll speed, `0.0` is not scrolling
+    pub scroll: f64,
+}
+
+impl TreeDrag {
+    pub fn new(source: String, start: DVec2) -> Self {
+        Self {
+            source,
+            start,
+            dragging: false,
+            target: None,
+            indicator: None,
+            scroll: 0.0,
+        }
+    }
+}
+
+/// header rect of a visible (not folded) node
+pub(crate) struct NodeRect {
+    pub value: String,
+    /// value of the parent branch, `None` is the root
+    pub parent: Option<String>,
+    pub rect: Rect,
+    pub is_branch: bool,
+    pub expanded: bool,
+    pub disabled: bool,
+}
+
+impl NodeRect {
+    /// branch: top quarter -> before, bottom quarter -> after, others -> inside
+    /// leaf: top half -> before, bottom half -> after
+    pub fn drop_position(&self, y: f64) -> TreeDropPosition {
+        let ratio = (y - self.rect.pos.y) / self.rect.size.y.max(1.0);
+        if self.is_branch {
+            if ratio < 0.25 {
+                TreeDropPosition::Before
+            } else if ratio > 0.75 {
+                TreeDropPosition::After
+            } else {
+                TreeDropPosition::Inside
+            }
+        } else if ratio < 0.5 {
+            TreeDropPosition::Before
+        } else {
+            TreeDropPosition::After
+        }
+    }
+    pub fn indicator(&self, position: TreeDropPosition) -> Rect {
+        let rect = self.rect;
+        match position {
+            TreeDropPosition::Before => Rect {
+                pos: dvec2(rect.pos.x, rect.pos.y - 1.0),
+                size: dvec2(rect.size.x, 2.0),
+            },
+            TreeDropPosition::After => Rect {
+                pos: dvec2(rect.pos.x, rect.pos.y + rect.size.y - 1.0),
+                size: dvec2(rect.size.x, 2.0),
+            },
+            TreeDropPosition::Inside => rect,
+        }
+    }
+}
+
+/// rects of visible nodes, in display order
+pub(crate) fn node_rects(
+    cx: &Cx,
+    children: &[(LiveId, WidgetRef)],
+    parent: Option<&str>,
+    rects: &mut Vec<NodeRect>,
+) {
+    for (_, child) in children {
+        if let Some(branch) = child.as_gbranch().borrow() {
+            if !branch.visible {
+                continue;
+            }
+            rects.push(NodeRect {
+                value: branch.value.to_string(),
+                parent: parent.map(ToString::to_string),
+                rect: branch.area_header.rect(cx),
+                is_branch: true,
+                expanded: branch.active,
+                disabled: branch.disabled,
+            });
+            if branch.active {
+                node_rects(cx, &branch.body.children, Some(&branch.value), rects);
+            }
+        } else if let Some(leaf) = child.as_gleaf().borrow() {
+            if !leaf.visible {
+                continue;
+            }
+            rects.push(NodeRect {
+                value: leaf.value.to_string(),
+                parent: parent.map(ToString::to_string),
+                rect: leaf.area().rect(cx),
+                is_branch: false,
+                expanded: false,
+                disabled: leaf.disabled,
+            });
+        }
+    }
+}
+
+/// is `value` the node itself or one of its descendants
+pub(crate) fn contains_node<N>(node: &N, value: &str) -> bool
+where
+    N: FilterNode,
+{
+    node.value() == value
+        || node.children().map_or(false, |children| {
+            children.iter().any(|child| contains_node(child, value))
+        })
+}
+
+fn node_value(child: &WidgetRef) -> Option<String> {
+    if let Some(branch) = child.as_gbranch().borrow() {
+        Some(branch.value.to_string())
+    } else {
+        child.as_gleaf().borrow().map(|leaf| leaf.value.to_string())
+    }
+}
+
+/// detach the node (keeping the widget and its state) from the tree
+pub(crate) fn take_nested(
+    children: &mut SmallVec<[(LiveId, WidgetRef); 2]>,
+    value: &str,
+) -> Option<(LiveId, WidgetRef)> {
+    if let Some(position) = children
+        .iter()
+        .position(|(_, child)| node_value(child).as_deref() == Some(value))
+    {
+        return Some(children.remove(position));
+    }
+    for (_, child) in children.iter() {
+        if let Some(mut branch) = child.as_gbranch().borrow_mut() {
+            if let Some(item) = take_nested(&mut branch.body.children, value) {
+                return Some(item);
+            }
+        }
+    }
+    None
+}
+
+/// insert the node before/after/inside the target, give the node back if target is not found
+pub(crate) fn insert_relative(
+    children: &mut SmallVec<[(LiveId, WidgetRef); 2]>,
+    target: &str,
+    position: TreeDropPosition,
+    item: (LiveId, WidgetRef),
+) -> Result<(), (LiveId, WidgetRef)> {
+    if let Some(index) = children
+        .iter()
+        .position(|(_, child)| node_value(child).as_deref() == Some(target))
+    {
+        match position {
+            TreeDropPosition::Before => children.insert(index, item),
+            TreeDropPosition::After => children.insert(index + 1, item),
+            TreeDropPosition::Inside => {
+                let target = children[index].1.clone();
+                return match target.as_gbranch().borrow_mut() {
+                    Some(mut branch) => {
+                        branch.body.children.push(item);
+                        Ok(())
+                    }
+                    None => Err(item),
+                };
+            }
+        }
+        return Ok(());
+    }
+    let mut item = item;
+    for (_, child) in children.iter() {
+        if let Some(mut branch) = child.as_gbranch().borrow_mut() {
+            match insert_relative(&mut branch.body.children, target, position, item) {
+                Ok(_) => return Ok(()),
+                Err(back) => item = back,
+            }
+        }
+    }
+    Err(item)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Node {
+        value: &'static str,
+        children: Option<Vec<Node>>,
+    }
+
+    impl FilterNode for Node {
+        fn value(&self) -> String {
+            self.value.to_string()
+        }
+        fn text(&self) -> String {
+            self.value.to_string()
+        }
+        fn children(&self) -> Option<Vec<Self>> {
+            self.children.clone()
+        }
+    }
+
+    fn node_rect(is_branch: bool) -> NodeRect {
+        NodeRect {
+            value: "a".to_string(),
+            parent: None,
+            rect: Rect {
+                pos: dvec2(0.0, 100.0),
+                size: dvec2(200.0, 40.0),
+            },
+            is_branch,
+            expanded: false,
+            disabled: false,
+        }
+    }
+
+    #[test]
+    fn drop_position_branch() {
+        let branch = node_rect(true);
+        assert_eq!(branch.drop_position(105.0), TreeDropPosition::Before);
+        assert_eq!(branch.drop_position(120.0), TreeDropPosition::Inside);
+        assert_eq!(branch.drop_position(135.0), TreeDropPosition::After);
+    }
+
+    #[test]
+    fn drop_position_leaf() {
+        let leaf = node_rect(false);
+        assert_eq!(leaf.drop_position(105.0), TreeDropPosition::Before);
+        assert_eq!(leaf.drop_position(119.0), TreeDropPosition::Before);
+        assert_eq!(leaf.drop_position(121.0), TreeDropPosition::After);
+    }
+
+    #[test]
+    fn drop_indicator() {
+        let node = node_rect(true);
+        assert_eq!(
+            node.indicator(TreeDropPosition::Before).pos,
+            dvec2(0.0, 99.0)
+        );
+        assert_eq!(
+            node.indicator(TreeDropPosition::After).pos,
+            dvec2(0.0, 139.0)
+        );
+        assert_eq!(node.indicator(TreeDropPosition::Inside), node.rect);
+    }
+
+    #[test]
+    fn refuse_drop_on_descendant() {
+        let tree = Node {
+            value: "src",
+            children: Some(vec![Node {
+                value: "src/tree",
+                children: Some(vec![Node {
+                    value: "src/tree/mod.rs",
+                    children: None,
+                }]),
+            }]),
+        };
+        assert!(contains_node(&tree, "src"));
+        assert!(contains_node(&tree, "src/tree"));
+        assert!(contains_node(&tree, "src/tree/mod.rs"));
+        assert!(!contains_node(&tree, "Cargo.toml"));
+    }
+}
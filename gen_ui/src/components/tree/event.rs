@@ -49,6 +49,10 @@ pub enum TreeEvent {
     HoverIn(TreeHoverIn),
     HoverOut(TreeHoverOut),
     Changed(TreeChanged),
+    LoadChildren(TreeLoadChildren),
+    Checked(TreeChecked),
+    Dropped(TreeDropped),
+    LoadFailed(TreeLoadFailed),
     None,
 }
 
@@ -68,3 +72,46 @@ pub struct TreeChanged {
     /// The value of the active
     pub actives: Vec<String>,
 }
+
+/// a lazy branch is expanded the first time and needs children
+#[derive(Clone, Debug)]
+pub struct TreeLoadChildren {
+    /// value of the lazy branch
+    pub value: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct TreeLoadFailed {
+    pub value: String,
+    pub error: String,
+}
+
+/// checkbox of a node is clicked (only `checkable` tree)
+#[derive(Clone, Debug)]
+pub struct TreeChecked {
+    pub meta: Option<FingerUpEvent>,
+    /// values of checked leaves, branches are derived from their leaves
+    pub checked: Vec<String>,
+    /// values of indeterminate (partially checked) branches
+    pub indeterminate: Vec<String>,
+}
+
+/// where the dragged node is dropped, relative to the target node
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeDropPosition {
+    Before,
+    After,
+    /// into the target branch (as the last child)
+    Inside,
+}
+
+/// a dragged node is dropped and moved (only `draggable` tree)
+#[derive(Clone, Debug)]
+pub struct TreeDropped {
+    pub meta: FingerUpEvent,
+    /// value of the dragged node
+    pub source: String,
+    /// value of the node where the source is dropped
+    pub target: String,
+    pub position: TreeDropPosition,
+}
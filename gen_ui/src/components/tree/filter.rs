@@ -0,0 +1,264 @@
+use makepad_widgets::*;
+
+use super::{GBranchWidgetRefExt, GLeafWidgetRefExt};
+use crate::utils::find_ignore_case;
+
+/// custom filter of `GTree::filter`: `(keyword, value, text) -> matched`
+pub type TreeFilterMethod = Box<dyn Fn(&str, &str, &str) -> bool>;
+
+/// default filter: text contains the keyword (ignore case)
+pub(crate) fn default_filter(keyword: &str, _value: &str, text: &str) -> bool {
+    find_ignore_case(text, keyword).is_some()
+}
+
+/// a node seen by the filter (and the drag), the tree widgets (`GBranch`/`GLeaf`) are `WidgetRef`
+pub(crate) trait FilterNode: Sized {
+    fn value(&self) -> String;
+    fn text(&self) -> String;
+    /// children of a branch, `None` for a leaf
+    fn children(&self) -> Option<Vec<Self>>;
+}
+
+impl FilterNode for WidgetRef {
+    fn value(&self) -> String {
+        if let Some(branch) = self.as_gbranch().borrow() {
+            branch.value.to_string()
+        } else {
+            self.as_gleaf()
+                .borrow()
+                .map_or(String::new(), |leaf| leaf.value.to_string())
+        }
+    }
+    fn text(&self) -> String {
+        if let Some(branch) = self.as_gbranch().borrow() {
+            branch.text.get_text()
+        } else {
+            self.as_gleaf()
+                .borrow()
+                .map_or(String::new(), |leaf| leaf.text.get_text())
+        }
+    }
+    fn children(&self) -> Option<Vec<Self>> {
+        self.as_gbranch().borrow().map(|branch| {
+            branch
+                .body
+                .children
+                .iter()
+                .map(|(_, child)| child.clone())
+                .collect()
+        })
+    }
+}
+
+/// values of the nodes after filtering
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Filtered {
+    pub matched: Vec<String>,
+    pub visible: Vec<String>,
+    /// branches with matched descendants
+    pub expand: Vec<String>,
+}
+
+/// apply the keyword to the nodes:
+/// - matched node: visible (highlighted by the tree)
+/// - branch with matched descendants: visible and expanded
+/// - descendants of a matched branch: visible
+pub(crate) fn filter_nodes<N>(
+    nodes: &[N],
+    keyword: &str,
+    method: &dyn Fn(&str, &str, &str) -> bool,
+) -> Filtered
+where
+    N: FilterNode,
+{
+    let mut filtered = Filtered::default();
+    for node in nodes {
+        let _ = nested_filter(node, keyword, method, false, &mut filtered);
+    }
+    filtered
+}
+
+/// return `(visible, has match)` of the node
+fn nested_filter<N>(
+    node: &N,
+    keyword: &str,
+    method: &dyn Fn(&str, &str, &str) -> bool,
+    parent_matched: bool,
+    filtered: &mut Filtered,
+) -> (bool, bool)
+where
+    N: FilterNode,
+{
+    let value = node.value();
+    let is_match = method(keyword, &value, &node.text());
+    if is_match {
+        filtered.matched.push(value.to_string());
+    }
+    let (mut sub_visible, mut sub_match) = (false, false);
+    if let Some(children) = node.children() {
+        for child in children.iter() {
+            let (visible, has_match) =
+                nested_filter(child, keyword, method, parent_matched || is_match, filtered);
+            sub_visible |= visible;
+            sub_match |= has_match;
+        }
+        if sub_match {
+            filtered.expand.push(value.to_string());
+        }
+    }
+    let visible = parent_matched || is_match || sub_visible;
+    if visible {
+        filtered.visible.push(value);
+    }
+    (visible, is_match || sub_match)
+}
+
+/// show the visible nodes of the filter and highlight the keyword in the matched ones,
+/// an empty keyword with no `filtered` shows all nodes and removes the highlights
+pub(crate) fn nested_show(
+    cx: &mut Cx,
+    child: &WidgetRef,
+    keyword: &str,
+    filtered: Option<&Filtered>,
+) {
+    let visible = |value: &str| filtered.map_or(true, |f| f.visible.iter().any(|v| v == value));
+    let highlight = |value: &str| match filtered {
+        Some(f) if f.matched.iter().any(|v| v == value) => keyword.to_string(),
+        _ => String::new(),
+    };
+    if let Some(mut branch) = child.as_gbranch().borrow_mut() {
+        for (_, sub_child) in branch.body.children.iter() {
+            nested_show(cx, sub_child, keyword, filtered);
+        }
+        let (text, is_visible) = (highlight(&branch.value), visible(&branch.value));
+        let _ = branch.text.set_highlight(cx, text);
+        branch.visible = is_visible;
+    } else if let Some(mut leaf) = child.as_gleaf().borrow_mut() {
+        let (text, is_visible) = (highlight(&leaf.value), visible(&leaf.value));
+        let _ = leaf.text.set_highlight(cx, text);
+        leaf.visible = is_visible;
+    }
+}
+
+/// values of all branches
+pub(crate) fn nested_branches(child: &WidgetRef, branches: &mut Vec<String>) {
+    if let Some(branch) = child.as_gbranch().borrow() {
+        branches.push(branch.value.to_string());
+        for (_, sub_child) in branch.body.children.iter() {
+            nested_branches(sub_child, branches);
+        }
+    }
+}
+
+/// values of expanded branches
+pub(crate) fn nested_expanded(child: &WidgetRef, expanded: &mut Vec<String>) {
+    if let Some(branch) = child.as_gbranch().borrow() {
+        if branch.active {
+            expanded.push(branch.value.to_string());
+        }
+        for (_, sub_child) in branch.body.children.iter() {
+            nested_expanded(sub_child, expanded);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Node {
+        value: &'static str,
+        children: Option<Vec<Node>>,
+    }
+
+    impl FilterNode for Node {
+        fn value(&self) -> String {
+            self.value.to_string()
+        }
+        fn text(&self) -> String {
+            // text is the file name of the value
+            self.value.rsplit('/').next().unwrap().to_string()
+        }
+        fn children(&self) -> Option<Vec<Self>> {
+            self.children.clone()
+        }
+    }
+
+    fn leaf(value: &'static str) -> Node {
+        Node {
+            value,
+            children: None,
+        }
+    }
+
+    fn branch(value: &'static str, children: Vec<Node>) -> Node {
+        Node {
+            value,
+            children: Some(children),
+        }
+    }
+
+    fn tree() -> Vec<Node> {
+        vec![
+            branch(
+                "src",
+                vec![
+                    leaf("src/main.rs"),
+                    branch(
+                        "src/tree",
+                        vec![leaf("src/tree/mod.rs"), leaf("src/tree/node.rs")],
+                    ),
+                ],
+            ),
+            leaf("Cargo.toml"),
+        ]
+    }
+
+    #[test]
+    fn default_filter_ignore_case() {
+        assert!(default_filter("TOML", "Cargo.toml", "Cargo.toml"));
+        assert!(!default_filter("lib", "src", "src"));
+    }
+
+    #[test]
+    fn match_expands_ancestors() {
+        let filtered = filter_nodes(&tree(), "node", &default_filter);
+        assert_eq!(filtered.matched, vec!["src/tree/node.rs"]);
+        assert_eq!(filtered.expand, vec!["src/tree", "src"]);
+        assert_eq!(
+            filtered.visible,
+            vec!["src/tree/node.rs", "src/tree", "src"]
+        );
+    }
+
+    #[test]
+    fn matched_branch_shows_descendants() {
+        let filtered = filter_nodes(&tree(), "TREE", &default_filter);
+        assert_eq!(filtered.matched, vec!["src/tree"]);
+        // the matched branch itself is not expanded, its ancestors are
+        assert_eq!(filtered.expand, vec!["src"]);
+        assert_eq!(
+            filtered.visible,
+            vec!["src/tree/mod.rs", "src/tree/node.rs", "src/tree", "src"]
+        );
+    }
+
+    #[test]
+    fn no_match() {
+        let filtered = filter_nodes(&tree(), "lib", &default_filter);
+        assert_eq!(filtered, Filtered::default());
+    }
+
+    #[test]
+    fn custom_method() {
+        let by_value = |keyword: &str, value: &str, _text: &str| value.starts_with(keyword);
+        let filtered = filter_nodes(&tree(), "src/tree/", &by_value);
+        assert_eq!(
+            filtered.matched,
+            vec!["src/tree/mod.rs", "src/tree/node.rs"]
+        );
+        assert_eq!(filtered.expand, vec!["src/tree", "src"]);
+        assert!(!filtered.visible.contains(&"src/main.rs".to_string()));
+    }
+}
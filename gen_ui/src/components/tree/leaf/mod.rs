@@ -6,7 +6,7 @@ use super::event::*;
 use crate::{
     ComponentAnInit, active_event, animation_open_then_redraw,
     components::{
-        BasicStyle, Component, GLabel, GSvg, LabelBasicStyle, LifeCycle, SlotComponent, SlotStyle,
+        BasicStyle, Component, GCheckbox, GLabel, GSvg, LabelBasicStyle, LifeCycle, SlotComponent, SlotStyle,
         Style, SvgBasicStyle, ViewBasicStyle,
     },
     error::Error,
@@ -96,6 +96,9 @@ pub struct GLeaf {
     pub icon: GSvg,
     #[live]
     pub text: GLabel,
+    /// checkbox, visible when `GTree.checkable`
+    #[live]
+    pub checkbox: GCheckbox,
     #[live]
     pub draw_leaf: DrawView,
     // --- animator ----------------
@@ -175,6 +178,7 @@ impl Widget for GLeaf {
 
         let _ = SlotDrawer::new(
             [
+                (live_id!(checkbox), (&mut self.checkbox).into()),
                 (live_id!(icon), (&mut self.icon).into()),
                 (live_id!(text), (&mut self.text).into()),
             ],
@@ -197,6 +201,9 @@ impl Widget for GLeaf {
 
         // handle slot events
         let is_slot_hover = false;
+        if self.checkbox.visible {
+            self.checkbox.handle_event(cx, event, scope);
+        }
         self.icon.handle_event(cx, event, scope);
         self.text.handle_event(cx, event, scope);
         // let super_state: LeafState = self.icon.state.into();
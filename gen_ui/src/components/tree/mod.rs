@@ -1,29 +1,43 @@
 mod branch;
+mod drag;
 mod event;
+mod filter;
 mod leaf;
+mod node;
 mod prop;
 mod register;
 
 pub use branch::*;
 pub use event::*;
+pub use filter::TreeFilterMethod;
 pub use leaf::*;
+pub use node::*;
 pub use prop::*;
 pub use register::register as tree_register;
 
+use std::sync::Arc;
+
+use drag::*;
+use filter::*;
+
 use crate::{
     ComponentAnInit, active_event, area, area_ref,
     components::{
+        checkbox::{CheckState, CheckboxClicked},
         lifecycle::LifeCycle,
         traits::{BasicStyle, Component, SlotComponent, SlotStyle, Style},
         view::{GView, ViewBasicStyle},
     },
     error::Error,
     event_option, event_option_ref, getter_setter_ref, lifecycle,
-    prop::{ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, ToStateMap, TreeItemMode, manuel::BASIC},
+    prop::{
+        ApplyMapImpl, ApplySlotMap, ApplySlotMapImpl, ToStateMap, TreeItemMode, manuel::BASIC,
+        traits::ToFloat,
+    },
     pure_after_apply, set_index, set_scope_path,
     shader::draw_view::DrawView,
     sync,
-    themes::conf::Conf,
+    themes::{Theme, conf::Conf},
     visible,
 };
 use makepad_widgets::*;
@@ -65,6 +79,8 @@ pub struct GTree {
     pub actives: Vec<String>,
     #[rust]
     pub item_modes: Vec<TreeItemMode>,
+    /// keyboard navigation: Up/Down move, Left/Right collapse/expand, Space/Enter activate,
+    /// the tree grabs the key focus (and the finger) when it is set
     #[live(true)]
     pub event_key: bool,
     #[rust]
@@ -77,6 +93,60 @@ pub struct GTree {
     pub state: TreeState,
     #[live(true)]
     pub animation_spread: bool,
+    // --- data ----------------------
+    /// template of branch created from `TreeNode`
+    #[live]
+    pub branch: Option<LivePtr>,
+    /// template of leaf created from `TreeNode`
+    #[live]
+    pub leaf: Option<LivePtr>,
+    #[rust]
+    pub loader: Option<Arc<dyn TreeLoader>>,
+    /// threads running `loader`, created at the first load
+    #[rust]
+    load_pool: Option<TagThreadPool<String>>,
+    // --- check ---------------------
+    /// show tri-state checkboxes on nodes, checking a branch checks all its leaves
+    #[live]
+    pub checkable: bool,
+    /// checked leaf values (only `checkable`)
+    #[live]
+    pub checked: Vec<String>,
+    // --- drag ----------------------
+    /// drag nodes to reorder them or move them into other branches
+    #[live]
+    pub draggable: bool,
+    /// drop position indicator, a line (before/after) or a box (inside)
+    #[live]
+    pub draw_drop: DrawView,
+    #[rust]
+    drag: Option<TreeDrag>,
+    /// veto callback: `(source, target, position) -> can drop`
+    #[rust]
+    can_drop: Option<Box<dyn Fn(&str, &str, TreeDropPosition) -> bool>>,
+    #[rust]
+    expand_timer: Timer,
+    #[rust]
+    scroll_frame: NextFrame,
+    // --- filter --------------------
+    /// keyword of the current filter, empty is not filtering
+    #[rust]
+    keyword: String,
+    #[rust]
+    filter_method: Option<TreeFilterMethod>,
+    /// expanded branches before filtering, restored when the filter is cleared
+    #[rust]
+    filter_expanded: Option<Vec<String>>,
+    // --- keyboard ------------------
+    /// focus ring of the node focused by keyboard
+    #[live]
+    pub draw_focus: DrawView,
+    /// node focused by keyboard (or the last clicked node)
+    #[rust]
+    focus: Option<String>,
+    /// node to scroll into view after drawing
+    #[rust]
+    scroll_target: Option<String>,
 }
 
 impl WidgetNode for GTree {
@@ -156,6 +226,31 @@ impl Widget for GTree {
             let _ = self.body.draw_walk(cx, scope, walk);
         }
 
+        if let Some(drag) = self.drag.as_ref().filter(|drag| drag.dragging) {
+            if let (Some(rect), Some((_, position))) = (drag.indicator, drag.target.as_ref()) {
+                let inside = *position == TreeDropPosition::Inside;
+                self.draw_drop.background_visible = (!inside).to_f32();
+                self.draw_drop.border_width = if inside { 1.0 } else { 0.0 };
+                self.draw_drop.draw_abs(cx, rect);
+            }
+        }
+
+        if let Some(target) = self.scroll_target.take() {
+            self.scroll_into_view(cx, &target);
+        }
+
+        if self.event_key && cx.has_key_focus(self.draw_menu.area()) {
+            let mut nodes = vec![];
+            node_rects(cx, &self.body.children, None, &mut nodes);
+            if let Some(node) = self
+                .focus
+                .as_ref()
+                .and_then(|focus| nodes.iter().find(|node| &node.value == focus))
+            {
+                self.draw_focus.draw_abs(cx, node.rect);
+            }
+        }
+
         self.draw_menu.end(cx);
         self.set_scope_path(&scope.path);
         DrawStep::done()
@@ -169,8 +264,26 @@ impl Widget for GTree {
         self.set_animation(cx);
         cx.global::<ComponentAnInit>().menu = true;
 
+        if let Event::Actions(actions) = event {
+            for action in actions {
+                if let Some(TreeChildrenLoaded {
+                    tree,
+                    value,
+                    result,
+                }) = action.downcast_ref()
+                {
+                    if *tree == self.widget_uid() {
+                        self.handle_children_loaded(cx, value, result);
+                    }
+                }
+            }
+        }
+
         if self.body.visible {
             let actions = cx.capture_actions(|cx| self.body.handle_event(cx, event, scope));
+            if self.checkable && !actions.is_empty() {
+                self.handle_check_actions(cx, &actions);
+            }
             // 在body的children中如果激活了某个菜单项，需要给出事件
             if !actions.is_empty() {
                 let mut active = None;
@@ -188,6 +301,14 @@ impl Widget for GTree {
                 }
 
                 if let Some(e_type) = active {
+                    if let TreeActionType::Branch(BranchChanged {
+                        value,
+                        active: true,
+                        ..
+                    }) = &e_type
+                    {
+                        self.load_children(cx, value);
+                    }
                     let (meta, value, active) = match e_type {
                         TreeActionType::Branch(BranchChanged {
                             value,
@@ -201,26 +322,15 @@ impl Widget for GTree {
                             active,
                         }) => (meta, value, active),
                     };
-                    // 更新actives，如果是branch被激活，则需要将branch下的所有leaf加入actives，如果是leaf被激活，则只加入leaf
-                    if active {
-                        // 添加value到self.actives
-                        if !self.actives.contains(&value) {
-                            self.actives.push(value.clone());
-                        }
-                    } else {
-                        // 从self.actives中移除value
-                        self.actives.retain(|v| v != &value);
-                    }
-
-                    self.active_changed(cx, meta);
-                    self.set_target_active(cx);
-                    // self.set_actives(cx, self.actives.clone());
+                    self.focus.replace(value.clone());
+                    self.node_changed(cx, meta, value, active);
                 }
             }
         }
 
         let area = self.area();
-        let hit = event.hits(cx, area);
+        // nodes capture the finger, overload it to drag them or grab the key focus
+        let hit = event.hits_with_capture_overload(cx, area, self.draggable || self.event_key);
         self.handle_widget_event(cx, event, hit, area);
     }
 }
@@ -230,6 +340,11 @@ impl LiveHook for GTree {
 
     fn after_new_before_apply(&mut self, cx: &mut Cx) {
         self.merge_conf_prop(cx);
+        // the drop indicator and the focus ring use the primary color by default
+        let primary: Vec4 = Theme::Primary.color(500).into();
+        self.draw_drop.background_color = primary;
+        self.draw_drop.border_color = primary;
+        self.draw_focus.border_color = primary;
     }
 
     fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
@@ -257,6 +372,7 @@ impl LiveHook for GTree {
         } else {
             self.set_actives(cx, self.actives.clone());
         }
+        self.sync_checked(cx);
     }
 }
 
@@ -278,8 +394,45 @@ impl Component for GTree {
         Ok(())
     }
 
-    fn handle_widget_event(&mut self, _cx: &mut Cx, _event: &Event, _hit: Hit, _area: Area) {
-        ()
+    fn handle_widget_event(&mut self, cx: &mut Cx, event: &Event, hit: Hit, area: Area) {
+        if self.draggable {
+            if self.expand_timer.is_event(event).is_some() {
+                self.auto_expand(cx);
+            }
+            if self.scroll_frame.is_event(event).is_some() {
+                self.auto_scroll(cx);
+            }
+        }
+        match hit {
+            Hit::FingerDown(e) => {
+                if self.event_key {
+                    cx.set_key_focus(area);
+                }
+                if self.draggable {
+                    let mut nodes = vec![];
+                    node_rects(cx, &self.body.children, None, &mut nodes);
+                    self.drag = nodes
+                        .into_iter()
+                        .find(|node| node.rect.contains(e.abs))
+                        .map(|node| TreeDrag::new(node.value, e.abs));
+                }
+            }
+            Hit::FingerMove(e) => {
+                self.drag_move(cx, e.abs);
+            }
+            Hit::FingerUp(e) => {
+                self.drop(cx, e);
+            }
+            Hit::KeyDown(e) => {
+                if self.event_key {
+                    self.handle_key_down(cx, &e);
+                }
+            }
+            Hit::KeyFocusLost(_) => {
+                self.redraw(cx);
+            }
+            _ => {}
+        }
     }
 
     fn switch_state(&mut self, state: Self::State) -> () {
@@ -344,10 +497,41 @@ impl GTree {
             });
         }
     }
+    fn active_load_children(&mut self, cx: &mut Cx, value: &str) {
+        if self.event_open {
+            self.scope_path.as_ref().map(|path| {
+                cx.widget_action(
+                    self.widget_uid(),
+                    path,
+                    TreeEvent::LoadChildren(TreeLoadChildren {
+                        value: value.to_string(),
+                    }),
+                );
+            });
+        }
+    }
+    fn active_load_failed(&mut self, cx: &mut Cx, value: &str, error: &str) {
+        if self.event_open {
+            self.scope_path.as_ref().map(|path| {
+                cx.widget_action(
+                    self.widget_uid(),
+                    path,
+                    TreeEvent::LoadFailed(TreeLoadFailed {
+                        value: value.to_string(),
+                        error: error.to_string(),
+                    }),
+                );
+            });
+        }
+    }
     event_option! {
         hover_in: TreeEvent::HoverIn => TreeHoverIn,
         hover_out: TreeEvent::HoverOut => TreeHoverOut,
-        changed: TreeEvent::Changed => TreeChanged
+        changed: TreeEvent::Changed => TreeChanged,
+        load_children: TreeEvent::LoadChildren => TreeLoadChildren,
+        load_failed: TreeEvent::LoadFailed => TreeLoadFailed,
+        checked: TreeEvent::Checked => TreeChecked,
+        dropped: TreeEvent::Dropped => TreeDropped
     }
     area! {
         area_body, body
@@ -439,18 +623,887 @@ impl GTree {
             handle_nested(cx, child, &self.actives, &index_chain, &mut vec![], true);
         }
     }
+    // --- data ----------------------------------------------------------------------------------
+    /// build the tree from data nodes, all declared children in body are replaced
+    pub fn set_nodes(&mut self, cx: &mut Cx, nodes: Vec<TreeNode>) {
+        self.body.children.clear();
+        for node in nodes.iter() {
+            let child = self.create_node(cx, node);
+            self.body.children.push(child);
+        }
+        let mut actives = vec![];
+        nested_node_actives(&nodes, &mut actives);
+        self.set_actives(cx, actives);
+        self.sync_checked(cx);
+        self.redraw(cx);
+    }
+    /// insert a node into the branch (`None` is the root), only the target branch is changed
+    pub fn insert_node(
+        &mut self,
+        cx: &mut Cx,
+        parent: Option<&str>,
+        index: usize,
+        node: TreeNode,
+    ) -> bool {
+        let child = self.create_node(cx, &node);
+        let inserted = match parent {
+            None => {
+                let index = index.min(self.body.children.len());
+                self.body.children.insert(index, child);
+                true
+            }
+            Some(parent) => find_branch(&self.body.children, parent)
+                .and_then(|branch| {
+                    branch.as_gbranch().borrow_mut().map(|mut branch| {
+                        let index = index.min(branch.body.children.len());
+                        branch.body.children.insert(index, child);
+                    })
+                })
+                .is_some(),
+        };
+        if inserted {
+            nested_node_actives(std::slice::from_ref(&node), &mut self.actives);
+            self.after_nodes_changed(cx);
+        }
+        inserted
+    }
+    /// remove the node (and its children) by value
+    pub fn remove_node(&mut self, cx: &mut Cx, value: &str) -> bool {
+        let Some(target) = find_nested(&self.body.children, value) else {
+            return false;
+        };
+        let mut values = vec![];
+        nested_values(&target, &mut values);
+        let removed = remove_nested(&mut self.body.children, value);
+        if removed {
+            self.actives.retain(|v| !values.contains(v));
+            self.checked.retain(|v| !values.contains(v));
+            self.after_nodes_changed(cx);
+        }
+        removed
+    }
+    /// update text, disabled and active of the node, children of a branch are updated only if
+    /// `node.kind` is `TreeNodeKind::Branch`
+    pub fn update_node(&mut self, cx: &mut Cx, node: TreeNode) -> bool {
+        let Some(target) = find_nested(&self.body.children, &node.value) else {
+            return false;
+        };
+        if let Some(mut branch) = target.as_gbranch().borrow_mut() {
+            let _ = branch.text.set_text(cx, node.text.to_string());
+            branch.disabled = node.disabled;
+            branch.active = node.active;
+            if let TreeNodeKind::Branch(children) = &node.kind {
+                // replaced children take their own actives
+                let mut values = vec![];
+                for (_, child) in branch.body.children.iter() {
+                    nested_values(child, &mut values);
+                }
+                self.actives.retain(|v| !values.contains(v));
+                nested_node_actives(children, &mut self.actives);
+                let children = children
+                    .iter()
+                    .map(|child| self.create_node(cx, child))
+                    .collect();
+                branch.body.children = children;
+            }
+        } else if let Some(mut leaf) = target.as_gleaf().borrow_mut() {
+            let _ = leaf.text.set_text(cx, node.text.to_string());
+            leaf.disabled = node.disabled;
+            leaf.active = node.active;
+        }
+        self.actives.retain(|v| v != &node.value);
+        if node.active {
+            self.actives.push(node.value.to_string());
+        }
+        self.after_nodes_changed(cx);
+        true
+    }
+    /// set children of a (lazy) branch, usually call after handling `TreeEvent::LoadChildren`
+    pub fn set_children(&mut self, cx: &mut Cx, value: &str, children: Vec<TreeNode>) -> bool {
+        let Some(target) = find_branch(&self.body.children, value) else {
+            return false;
+        };
+        let widgets = children
+            .iter()
+            .map(|child| self.create_node(cx, child))
+            .collect();
+        if let Some(mut branch) = target.as_gbranch().borrow_mut() {
+            let mut values = vec![];
+            for (_, child) in branch.body.children.iter() {
+                nested_values(child, &mut values);
+            }
+            self.actives.retain(|v| !values.contains(v));
+            branch.body.children = widgets;
+            branch.loaded = true;
+            let _ = branch.set_loading(cx, false);
+        }
+        nested_node_actives(&children, &mut self.actives);
+        // a lazy branch checked before loading, check all loaded leaves
+        if self.checked.iter().any(|v| v == value) {
+            self.checked.retain(|v| v != value);
+            let mut leaves = vec![];
+            nested_leaves(&target, &mut leaves);
+            self.checked.extend(leaves);
+        }
+        self.after_nodes_changed(cx);
+        true
+    }
+    /// set the loader of lazy branches, see `TreeLoader`
+    pub fn set_loader<L>(&mut self, loader: L)
+    where
+        L: TreeLoader,
+    {
+        self.loader.replace(Arc::new(loader));
+    }
+    /// start loading children of a lazy branch, do nothing if the branch is not lazy or is loaded
+    pub fn load_children(&mut self, cx: &mut Cx, value: &str) {
+        let Some(target) = find_branch(&self.body.children, value) else {
+            return;
+        };
+        let need_load = target
+            .as_gbranch()
+            .borrow()
+            .map_or(false, |branch| branch.need_load());
+        if !need_load {
+            return;
+        }
+        if let Some(mut branch) = target.as_gbranch().borrow_mut() {
+            let _ = branch.set_loading(cx, true);
+        }
+        if let Some(loader) = self.loader.clone() {
+            let uid = self.widget_uid();
+            spawn_load(cx, &mut self.load_pool, loader, uid, value.to_string());
+        }
+        self.active_load_children(cx, value);
+    }
+    fn handle_children_loaded(
+        &mut self,
+        cx: &mut Cx,
+        value: &str,
+        result: &Result<Vec<TreeNode>, String>,
+    ) {
+        match result {
+            Ok(children) => {
+                let _ = self.set_children(cx, value, children.clone());
+            }
+            Err(e) => {
+                // keep the branch lazy, expand it again to retry
+                if let Some(branch) = find_branch(&self.body.children, value) {
+                    if let Some(mut branch) = branch.as_gbranch().borrow_mut() {
+                        let _ = branch.set_loading(cx, false);
+                        let _ = branch.set_active(cx, false);
+                    }
+                }
+                self.active_load_failed(cx, value, e);
+            }
+        }
+    }
+    fn create_node(&self, cx: &mut Cx, node: &TreeNode) -> (LiveId, WidgetRef) {
+        let id = LiveId::from_str(&node.value);
+        match &node.kind {
+            TreeNodeKind::Leaf => {
+                let widget = WidgetRef::new_from_ptr(cx, self.leaf);
+                if let Some(mut leaf) = widget.as_gleaf().borrow_mut() {
+                    leaf.value = node.value.to_string();
+                    leaf.active = node.active;
+                    leaf.disabled = node.disabled;
+                    let _ = leaf.text.set_text(cx, node.text.to_string());
+                }
+                (id, widget)
+            }
+            TreeNodeKind::Branch(_) | TreeNodeKind::LazyBranch => {
+                let widget = WidgetRef::new_from_ptr(cx, self.branch);
+                if let Some(mut branch) = widget.as_gbranch().borrow_mut() {
+                    branch.value = node.value.to_string();
+                    branch.active = node.active;
+                    branch.disabled = node.disabled;
+                    let _ = branch.text.set_text(cx, node.text.to_string());
+                    branch.body.children.clear();
+                    if let TreeNodeKind::Branch(children) = &node.kind {
+                        for child in children {
+                            let child = self.create_node(cx, child);
+                            branch.body.children.push(child);
+                        }
+                    } else {
+                        branch.lazy = true;
+                    }
+                }
+                (id, widget)
+            }
+        }
+    }
+    /// rebuild `item_modes`, widgets and their fold state are kept
+    fn after_nodes_changed(&mut self, cx: &mut Cx) {
+        self.find_actives();
+        self.sync_checked(cx);
+        if !self.keyword.is_empty() {
+            let _ = self.apply_filter(cx);
+        }
+        self.redraw(cx);
+    }
+    /// update actives after a node is clicked (or activated by keyboard) and sync them to nodes
+    fn node_changed(
+        &mut self,
+        cx: &mut Cx,
+        meta: Option<FingerUpEvent>,
+        value: String,
+        active: bool,
+    ) {
+        // 更新actives，如果是branch被激活，则需要将branch下的所有leaf加入actives，如果是leaf被激活，则只加入leaf
+        if active {
+            // 添加value到self.actives
+            if !self.actives.contains(&value) {
+                self.actives.push(value);
+            }
+        } else {
+            // 从self.actives中移除value
+            self.actives.retain(|v| v != &value);
+        }
+
+        self.active_changed(cx, meta);
+        self.set_target_active(cx);
+    }
+    // --- filter --------------------------------------------------------------------------------
+    /// show nodes matching the keyword (text contains it, ignore case, see `set_filter_method`),
+    /// ancestors of matches are kept visible and expanded, descendants of a matched branch are
+    /// kept, matches are highlighted. An empty keyword clears the filter.
+    /// Return values of matched nodes
+    pub fn filter(&mut self, cx: &mut Cx, keyword: &str) -> Vec<String> {
+        if keyword.is_empty() {
+            self.clear_filter(cx);
+            return vec![];
+        }
+        if self.filter_expanded.is_none() {
+            let mut expanded = vec![];
+            for (_, child) in self.body.children.iter() {
+                nested_expanded(child, &mut expanded);
+            }
+            self.filter_expanded.replace(expanded);
+        }
+        self.keyword = keyword.to_string();
+        let matched = self.apply_filter(cx);
+        self.redraw(cx);
+        matched
+    }
+    /// show all nodes and restore the expanded branches before filtering
+    pub fn clear_filter(&mut self, cx: &mut Cx) {
+        self.keyword.clear();
+        let mut branches = vec![];
+        for (_, child) in self.body.children.iter() {
+            nested_show(cx, child, "", None);
+            nested_branches(child, &mut branches);
+        }
+        if let Some(expanded) = self.filter_expanded.take() {
+            for branch in branches {
+                let expand = expanded.contains(&branch);
+                self.set_expanded(cx, &branch, expand);
+            }
+        }
+        self.redraw(cx);
+    }
+    /// set the filter method: `(keyword, value, text) -> matched`
+    pub fn set_filter_method<F>(&mut self, method: F)
+    where
+        F: Fn(&str, &str, &str) -> bool + 'static,
+    {
+        self.filter_method.replace(Box::new(method));
+    }
+    fn apply_filter(&mut self, cx: &mut Cx) -> Vec<String> {
+        let method: &dyn Fn(&str, &str, &str) -> bool = match self.filter_method.as_ref() {
+            Some(method) => method.as_ref(),
+            None => &default_filter,
+        };
+        let nodes = self
+            .body
+            .children
+            .iter()
+            .map(|(_, child)| child.clone())
+            .collect::<Vec<_>>();
+        let filtered = filter_nodes(&nodes, &self.keyword, method);
+        for child in nodes.iter() {
+            nested_show(cx, child, &self.keyword, Some(&filtered));
+        }
+        for branch in filtered.expand.iter() {
+            self.set_expanded(cx, branch, true);
+        }
+        filtered.matched
+    }
+    // --- keyboard ------------------------------------------------------------------------------
+    /// expand the ancestors of the node and scroll the tree to make it visible
+    pub fn scroll_to(&mut self, cx: &mut Cx, value: &str) -> bool {
+        let mut ancestors = vec![];
+        if !find_ancestors(&self.body.children, value, &mut ancestors) {
+            return false;
+        }
+        for ancestor in ancestors {
+            let value = ancestor
+                .as_gbranch()
+                .borrow()
+                .map(|branch| branch.value.to_string());
+            if let Some(value) = value {
+                self.set_expanded(cx, &value, true);
+            }
+        }
+        self.scroll_target.replace(value.to_string());
+        self.redraw(cx);
+        true
+    }
+    /// scroll the body (after drawing, rects are up to date) to make the node visible
+    fn scroll_into_view(&mut self, cx: &mut Cx, value: &str) {
+        let mut nodes = vec![];
+        node_rects(cx, &self.body.children, None, &mut nodes);
+        let Some(node) = nodes.iter().find(|node| node.value == value) else {
+            return;
+        };
+        let view = self.body.area().rect(cx);
+        let top = node.rect.pos.y - view.pos.y;
+        let bottom = node.rect.pos.y + node.rect.size.y - (view.pos.y + view.size.y);
+        let delta = if top < 0.0 {
+            top
+        } else if bottom > 0.0 {
+            bottom
+        } else {
+            0.0
+        };
+        if delta != 0.0 && self.body.scroll_by(cx, dvec2(0.0, delta)) {
+            self.body.redraw(cx);
+        }
+    }
+    fn handle_key_down(&mut self, cx: &mut Cx, e: &KeyEvent) {
+        let mut nodes = vec![];
+        node_rects(cx, &self.body.children, None, &mut nodes);
+        nodes.retain(|node| !node.disabled);
+        if nodes.is_empty() {
+            return;
+        }
+        let current = self
+            .focus
+            .as_ref()
+            .and_then(|focus| nodes.iter().position(|node| &node.value == focus));
+        let next = match (e.key_code, current) {
+            (KeyCode::ArrowUp, None) => Some(nodes.len() - 1),
+            (KeyCode::ArrowUp, Some(index)) => Some(index.saturating_sub(1)),
+            (KeyCode::ArrowDown, None) => Some(0),
+            (KeyCode::ArrowDown, Some(index)) => Some((index + 1).min(nodes.len() - 1)),
+            // collapsed branch: expand, expanded branch: move to the first child
+            (KeyCode::ArrowRight, Some(index)) => {
+                let node = &nodes[index];
+                if !node.is_branch {
+                    None
+                } else if !node.expanded {
+                    self.expand_branch(cx, &node.value, true);
+                    None
+                } else {
+                    nodes
+                        .get(index + 1)
+                        .filter(|child| child.parent.as_ref() == Some(&node.value))
+                        .map(|_| index + 1)
+                }
+            }
+            // expanded branch: collapse, others: move to the parent
+            (KeyCode::ArrowLeft, Some(index)) => {
+                let node = &nodes[index];
+                if node.is_branch && node.expanded {
+                    self.expand_branch(cx, &node.value, false);
+                    None
+                } else {
+                    node.parent
+                        .as_ref()
+                        .and_then(|parent| nodes.iter().position(|node| &node.value == parent))
+                }
+            }
+            (KeyCode::Space | KeyCode::ReturnKey, Some(index)) => {
+                let node = &nodes[index];
+                if node.is_branch {
+                    self.expand_branch(cx, &node.value, !node.expanded);
+                } else {
+                    self.node_changed(cx, None, node.value.to_string(), true);
+                }
+                None
+            }
+            _ => return,
+        };
+        if let Some(next) = next {
+            self.focus.replace(nodes[next].value.to_string());
+            self.scroll_target = self.focus.clone();
+        }
+        self.redraw(cx);
+    }
+    /// expand/collapse the branch like clicking its header
+    fn expand_branch(&mut self, cx: &mut Cx, value: &str, expand: bool) {
+        self.set_expanded(cx, value, expand);
+        self.active_changed(cx, None);
+    }
+    /// expand/collapse the branch and keep `actives` in sync (so the next click does not fold
+    /// it back), a lazy branch starts loading when it is expanded
+    fn set_expanded(&mut self, cx: &mut Cx, value: &str, expand: bool) {
+        if let Some(branch) = find_branch(&self.body.children, value) {
+            if let Some(mut branch) = branch.as_gbranch().borrow_mut() {
+                let _ = branch.set_active(cx, expand);
+            }
+        }
+        if expand {
+            if !self.actives.iter().any(|v| v == value) {
+                self.actives.push(value.to_string());
+            }
+            self.load_children(cx, value);
+        } else {
+            self.actives.retain(|v| v != value);
+        }
+    }
+    // --- drag ----------------------------------------------------------------------------------
+    /// set the veto callback of drag and drop, return false to forbid dropping `source` on `target`
+    pub fn set_can_drop<F>(&mut self, can_drop: F)
+    where
+        F: Fn(&str, &str, TreeDropPosition) -> bool + 'static,
+    {
+        self.can_drop.replace(Box::new(can_drop));
+    }
+    /// move the node (with its children and state) before/after/inside the target
+    pub fn move_node(
+        &mut self,
+        cx: &mut Cx,
+        source: &str,
+        target: &str,
+        position: TreeDropPosition,
+    ) -> bool {
+        if !self.can_move(source, target) {
+            return false;
+        }
+        let Some(item) = take_nested(&mut self.body.children, source) else {
+            return false;
+        };
+        if let Err(item) = insert_relative(&mut self.body.children, target, position, item) {
+            // target disappeared, keep the node at root
+            self.body.children.push(item);
+        }
+        self.after_nodes_changed(cx);
+        true
+    }
+    /// a node can not be moved into itself or its descendants
+    fn can_move(&self, source: &str, target: &str) -> bool {
+        source != target
+            && find_nested(&self.body.children, source)
+                .map_or(false, |source| !contains_node(&source, target))
+    }
+    fn drag_move(&mut self, cx: &mut Cx, abs: DVec2) {
+        let Some(drag) = self.drag.as_mut() else {
+            return;
+        };
+        if !drag.dragging {
+            if (abs - drag.start).length() < DRAG_THRESHOLD {
+                return;
+            }
+            drag.dragging = true;
+        }
+        cx.set_cursor(MouseCursor::Grabbing);
+        let source = drag.source.to_string();
+        let mut nodes = vec![];
+        node_rects(cx, &self.body.children, None, &mut nodes);
+        let target = nodes
+            .iter()
+            .find(|node| abs.y >= node.rect.pos.y && abs.y < node.rect.pos.y + node.rect.size.y)
+            .and_then(|node| {
+                let position = node.drop_position(abs.y);
+                let allowed = self.can_move(&source, &node.value)
+                    && self
+                        .can_drop
+                        .as_ref()
+                        .map_or(true, |can_drop| can_drop(&source, &node.value, position));
+                allowed.then(|| (node, position))
+            });
+        let (target, indicator, expand) = match target {
+            Some((node, position)) => (
+                Some((node.value.to_string(), position)),
+                Some(node.indicator(position)),
+                position == TreeDropPosition::Inside && !node.expanded,
+            ),
+            None => (None, None, false),
+        };
+        // auto scroll when the finger is close to the top/bottom edge
+        let rect = self.area().rect(cx);
+        let edge = if abs.y < rect.pos.y + AUTO_SCROLL_EDGE {
+            -(rect.pos.y + AUTO_SCROLL_EDGE - abs.y).min(AUTO_SCROLL_EDGE) / AUTO_SCROLL_EDGE
+        } else if abs.y > rect.pos.y + rect.size.y - AUTO_SCROLL_EDGE {
+            (abs.y - (rect.pos.y + rect.size.y - AUTO_SCROLL_EDGE)).min(AUTO_SCROLL_EDGE)
+                / AUTO_SCROLL_EDGE
+        } else {
+            0.0
+        };
+        let scroll = edge * AUTO_SCROLL_SPEED;
+
+        let drag = self.drag.as_mut().unwrap();
+        if drag.target != target {
+            cx.stop_timer(self.expand_timer);
+            if expand {
+                self.expand_timer = cx.start_timeout(AUTO_EXPAND_DELAY);
+            }
+        }
+        if drag.scroll == 0.0 && scroll != 0.0 {
+            self.scroll_frame = cx.new_next_frame();
+        }
+        drag.target = target;
+        drag.indicator = indicator;
+        drag.scroll = scroll;
+        self.redraw(cx);
+    }
+    fn drop(&mut self, cx: &mut Cx, e: FingerUpEvent) {
+        cx.stop_timer(self.expand_timer);
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        if !drag.dragging {
+            return;
+        }
+        self.redraw(cx);
+        let Some((target, position)) = drag.target else {
+            return;
+        };
+        if self.move_node(cx, &drag.source, &target, position) && self.event_open {
+            self.scope_path.as_ref().map(|path| {
+                cx.widget_action(
+                    self.widget_uid(),
+                    path,
+                    TreeEvent::Dropped(TreeDropped {
+                        meta: e,
+                        source: drag.source,
+                        target,
+                        position,
+                    }),
+                );
+            });
+        }
+    }
+    /// hovering on a collapsed branch (inside) expands it
+    fn auto_expand(&mut self, cx: &mut Cx) {
+        let Some((target, TreeDropPosition::Inside)) =
+            self.drag.as_ref().and_then(|drag| drag.target.clone())
+        else {
+            return;
+        };
+        if let Some(branch) = find_branch(&self.body.children, &target) {
+            if let Some(mut branch) = branch.as_gbranch().borrow_mut() {
+                let _ = branch.set_active(cx, true);
+            }
+        }
+        self.load_children(cx, &target);
+    }
+    fn auto_scroll(&mut self, cx: &mut Cx) {
+        let Some(scroll) = self.drag.as_ref().map(|drag| drag.scroll) else {
+            return;
+        };
+        if scroll != 0.0 && self.body.scroll_by(cx, dvec2(0.0, scroll)) {
+            self.body.redraw(cx);
+            self.scroll_frame = cx.new_next_frame();
+        } else if let Some(drag) = self.drag.as_mut() {
+            // reached the edge, restart when the finger moves again
+            drag.scroll = 0.0;
+        }
+    }
+    // --- check ---------------------------------------------------------------------------------
+    /// set checked leaves, branches become checked/indeterminate by their leaves
+    pub fn set_checked(&mut self, cx: &mut Cx, checked: Vec<String>) {
+        self.checked = checked;
+        self.sync_checked(cx);
+        self.redraw(cx);
+    }
+    /// values of indeterminate (partially checked) branches
+    pub fn indeterminate(&self) -> Vec<String> {
+        let mut indeterminate = vec![];
+        for (_, child) in self.body.children.iter() {
+            nested_indeterminate(child, &mut indeterminate);
+        }
+        indeterminate
+    }
+    /// apply `checked` and `checkable` to checkboxes of all nodes
+    fn sync_checked(&mut self, cx: &mut Cx) {
+        for (_, child) in self.body.children.iter() {
+            let _ = nested_check(cx, child, &self.checked, self.checkable);
+        }
+    }
+    fn handle_check_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        let mut clicked = None;
+        for (_, child) in self.body.children.iter() {
+            if nested_check_clicked(child, actions, &mut clicked) {
+                break;
+            }
+        }
+        let Some((leaves, CheckboxClicked { meta, active, .. })) = clicked else {
+            return;
+        };
+        // branch: cascade to all leaves under it
+        self.checked.retain(|v| !leaves.contains(v));
+        if active {
+            self.checked.extend(leaves);
+        }
+        self.sync_checked(cx);
+        if self.event_open {
+            self.scope_path.as_ref().map(|path| {
+                cx.widget_action(
+                    self.widget_uid(),
+                    path,
+                    TreeEvent::Checked(TreeChecked {
+                        meta,
+                        checked: self.checked.clone(),
+                        indeterminate: self.indeterminate(),
+                    }),
+                );
+            });
+        }
+    }
 }
 
 impl GTreeRef {
     event_option_ref! {
         hover_in => TreeHoverIn,
         hover_out => TreeHoverOut,
-        changed => TreeChanged
+        changed => TreeChanged,
+        load_children => TreeLoadChildren,
+        load_failed => TreeLoadFailed,
+        checked => TreeChecked,
+        dropped => TreeDropped
     }
     area_ref! {
         area_body
     }
     getter_setter_ref! {}
+    pub fn set_nodes(&self, cx: &mut Cx, nodes: Vec<TreeNode>) {
+        self.borrow_mut().map(|mut c| c.set_nodes(cx, nodes));
+    }
+    pub fn insert_node(
+        &self,
+        cx: &mut Cx,
+        parent: Option<&str>,
+        index: usize,
+        node: TreeNode,
+    ) -> bool {
+        self.borrow_mut()
+            .map_or(false, |mut c| c.insert_node(cx, parent, index, node))
+    }
+    pub fn remove_node(&self, cx: &mut Cx, value: &str) -> bool {
+        self.borrow_mut()
+            .map_or(false, |mut c| c.remove_node(cx, value))
+    }
+    pub fn update_node(&self, cx: &mut Cx, node: TreeNode) -> bool {
+        self.borrow_mut()
+            .map_or(false, |mut c| c.update_node(cx, node))
+    }
+    pub fn set_children(&self, cx: &mut Cx, value: &str, children: Vec<TreeNode>) -> bool {
+        self.borrow_mut()
+            .map_or(false, |mut c| c.set_children(cx, value, children))
+    }
+    pub fn set_loader<L>(&self, loader: L)
+    where
+        L: TreeLoader,
+    {
+        self.borrow_mut().map(|mut c| c.set_loader(loader));
+    }
+    pub fn filter(&self, cx: &mut Cx, keyword: &str) -> Vec<String> {
+        self.borrow_mut()
+            .map_or(vec![], |mut c| c.filter(cx, keyword))
+    }
+    pub fn clear_filter(&self, cx: &mut Cx) {
+        self.borrow_mut().map(|mut c| c.clear_filter(cx));
+    }
+    pub fn set_filter_method<F>(&self, method: F)
+    where
+        F: Fn(&str, &str, &str) -> bool + 'static,
+    {
+        self.borrow_mut().map(|mut c| c.set_filter_method(method));
+    }
+    pub fn scroll_to(&self, cx: &mut Cx, value: &str) -> bool {
+        self.borrow_mut()
+            .map_or(false, |mut c| c.scroll_to(cx, value))
+    }
+}
+
+/// set checkbox state of the node, return `(checked leaves, leaves)` under it
+fn nested_check(
+    cx: &mut Cx,
+    child: &WidgetRef,
+    checked: &[String],
+    checkable: bool,
+) -> (usize, usize) {
+    if let Some(mut branch) = child.as_gbranch().borrow_mut() {
+        let (mut checked_count, mut total) = (0, 0);
+        for (_, sub_child) in branch.body.children.iter() {
+            let (c, t) = nested_check(cx, sub_child, checked, checkable);
+            checked_count += c;
+            total += t;
+        }
+        // unloaded lazy branch has no leaves, use its own value
+        if total == 0 && checked.contains(&branch.value) {
+            checked_count = 1;
+            total = 1;
+        }
+        branch.checkbox.visible = checkable;
+        branch
+            .checkbox
+            .set_check_state(cx, CheckState::from_count(checked_count, total));
+        (checked_count, total)
+    } else if let Some(mut leaf) = child.as_gleaf().borrow_mut() {
+        let is_checked = checked.contains(&leaf.value);
+        leaf.checkbox.visible = checkable;
+        leaf.checkbox.set_check_state(
+            cx,
+            if is_checked {
+                CheckState::Checked
+            } else {
+                CheckState::Unchecked
+            },
+        );
+        (is_checked as usize, 1)
+    } else {
+        (0, 0)
+    }
+}
+
+/// find the clicked checkbox, return leaves affected by it
+fn nested_check_clicked(
+    child: &WidgetRef,
+    actions: &Actions,
+    clicked: &mut Option<(Vec<String>, CheckboxClicked)>,
+) -> bool {
+    if let Some(branch) = child.as_gbranch().borrow() {
+        if let Some(e) = branch.checkbox.clicked(actions) {
+            let mut leaves = vec![];
+            nested_leaves(child, &mut leaves);
+            if leaves.is_empty() {
+                // unloaded lazy branch
+                leaves.push(branch.value.to_string());
+            }
+            clicked.replace((leaves, e));
+            return true;
+        }
+        for (_, sub_child) in branch.body.children.iter() {
+            if nested_check_clicked(sub_child, actions, clicked) {
+                return true;
+            }
+        }
+    } else if let Some(leaf) = child.as_gleaf().borrow() {
+        if let Some(e) = leaf.checkbox.clicked(actions) {
+            clicked.replace((vec![leaf.value.to_string()], e));
+            return true;
+        }
+    }
+    false
+}
+
+/// values of enabled leaves under the node
+fn nested_leaves(child: &WidgetRef, leaves: &mut Vec<String>) {
+    if let Some(branch) = child.as_gbranch().borrow() {
+        for (_, sub_child) in branch.body.children.iter() {
+            nested_leaves(sub_child, leaves);
+        }
+    } else if let Some(leaf) = child.as_gleaf().borrow() {
+        if !leaf.disabled {
+            leaves.push(leaf.value.to_string());
+        }
+    }
+}
+
+/// collect values of the node and all its descendants
+fn nested_values(child: &WidgetRef, values: &mut Vec<String>) {
+    if let Some(branch) = child.as_gbranch().borrow() {
+        values.push(branch.value.to_string());
+        for (_, sub_child) in branch.body.children.iter() {
+            nested_values(sub_child, values);
+        }
+    } else if let Some(leaf) = child.as_gleaf().borrow() {
+        values.push(leaf.value.to_string());
+    }
+}
+
+fn nested_indeterminate(child: &WidgetRef, indeterminate: &mut Vec<String>) {
+    if let Some(branch) = child.as_gbranch().borrow() {
+        if branch.checkbox.check_state() == CheckState::Indeterminate {
+            indeterminate.push(branch.value.to_string());
+        }
+        for (_, sub_child) in branch.body.children.iter() {
+            nested_indeterminate(sub_child, indeterminate);
+        }
+    }
+}
+
+fn nested_node_actives(nodes: &[TreeNode], actives: &mut Vec<String>) {
+    for node in nodes {
+        if node.active {
+            actives.push(node.value.to_string());
+        }
+        if let TreeNodeKind::Branch(children) = &node.kind {
+            nested_node_actives(children, actives);
+        }
+    }
+}
+
+/// find branch or leaf by value
+fn find_nested(children: &[(LiveId, WidgetRef)], value: &str) -> Option<WidgetRef> {
+    for (_, child) in children {
+        if let Some(branch) = child.as_gbranch().borrow() {
+            if branch.value == value {
+                return Some(child.clone());
+            }
+            if let Some(target) = find_nested(&branch.body.children, value) {
+                return Some(target);
+            }
+        } else if let Some(leaf) = child.as_gleaf().borrow() {
+            if leaf.value == value {
+                return Some(child.clone());
+            }
+        }
+    }
+    None
+}
+
+/// collect branches from the root to the parent of the node, return false if it is not found
+fn find_ancestors(
+    children: &[(LiveId, WidgetRef)],
+    value: &str,
+    ancestors: &mut Vec<WidgetRef>,
+) -> bool {
+    for (_, child) in children {
+        if let Some(branch) = child.as_gbranch().borrow() {
+            if branch.value == value {
+                return true;
+            }
+            ancestors.push(child.clone());
+            if find_ancestors(&branch.body.children, value, ancestors) {
+                return true;
+            }
+            ancestors.pop();
+        } else if let Some(leaf) = child.as_gleaf().borrow() {
+            if leaf.value == value {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn find_branch(children: &[(LiveId, WidgetRef)], value: &str) -> Option<WidgetRef> {
+    find_nested(children, value).filter(|target| target.as_gbranch().borrow().is_some())
+}
+
+fn remove_nested(children: &mut SmallVec<[(LiveId, WidgetRef); 2]>, value: &str) -> bool {
+    let position = children.iter().position(|(_, child)| {
+        child
+            .as_gbranch()
+            .borrow()
+            .map_or(false, |b| b.value == value)
+            || child
+                .as_gleaf()
+                .borrow()
+                .map_or(false, |l| l.value == value)
+    });
+    if let Some(position) = position {
+        children.remove(position);
+        return true;
+    }
+    children.iter().any(|(_, child)| {
+        child.as_gbranch().borrow_mut().map_or(false, |mut branch| {
+            remove_nested(&mut branch.body.children, value)
+        })
+    })
 }
 
 fn handle_nested(
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use makepad_widgets::{Cx, TagThreadPool, WidgetUid};
+
+/// # Tree Node
+/// Data model of `GTree`, use `GTree::set_nodes` to build the tree widgets from nodes
+/// instead of declaring `GBranch`/`GLeaf` in the live DSL.
+/// ## Example
+/// ```ignore
+/// tree.set_nodes(cx, vec![
+///     TreeNode::branch("src", "src", vec![TreeNode::leaf("main", "main.rs")]),
+///     TreeNode::lazy("target", "target"),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeNode {
+    /// unique value of the node, same as `GBranch.value`/`GLeaf.value`
+    pub value: String,
+    pub text: String,
+    pub active: bool,
+    pub disabled: bool,
+    pub kind: TreeNodeKind,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TreeNodeKind {
+    #[default]
+    Leaf,
+    Branch(Vec<TreeNode>),
+    /// children are loaded when the branch is expanded the first time, see `TreeLoader`
+    LazyBranch,
+}
+
+impl TreeNode {
+    pub fn leaf<V, T>(value: V, text: T) -> Self
+    where
+        V: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            value: value.into(),
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+    pub fn branch<V, T>(value: V, text: T, children: Vec<TreeNode>) -> Self
+    where
+        V: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            value: value.into(),
+            text: text.into(),
+            kind: TreeNodeKind::Branch(children),
+            ..Default::default()
+        }
+    }
+    pub fn lazy<V, T>(value: V, text: T) -> Self
+    where
+        V: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            value: value.into(),
+            text: text.into(),
+            kind: TreeNodeKind::LazyBranch,
+            ..Default::default()
+        }
+    }
+    pub fn is_leaf(&self) -> bool {
+        matches!(self.kind, TreeNodeKind::Leaf)
+    }
+}
+
+/// # Tree Loader
+/// Load children of a lazy branch, `load` runs in the thread pool of the tree so it can block
+/// (read dir, http request, ...). Set it by `GTree::set_loader`, if no loader is set,
+/// handle `TreeEvent::LoadChildren` and call `GTree::set_children` yourself.
+pub trait TreeLoader: Send + Sync + 'static {
+    fn load(&self, value: &str) -> Result<Vec<TreeNode>, String>;
+}
+
+impl<F> TreeLoader for F
+where
+    F: Fn(&str) -> Result<Vec<TreeNode>, String> + Send + Sync + 'static,
+{
+    fn load(&self, value: &str) -> Result<Vec<TreeNode>, String> {
+        self(value)
+    }
+}
+
+/// posted from the loader thread back to the `GTree` which started the load
+#[derive(Debug)]
+pub struct TreeChildrenLoaded {
+    pub tree: WidgetUid,
+    pub value: String,
+    pub result: Result<Vec<TreeNode>, String>,
+}
+
+/// load children in the thread pool of the tree, loads of the same branch which are still
+/// waiting are merged (tagged by the branch value)
+pub(crate) fn spawn_load(
+    cx: &mut Cx,
+    pool: &mut Option<TagThreadPool<String>>,
+    loader: Arc<dyn TreeLoader>,
+    tree: WidgetUid,
+    value: String,
+) {
+    pool.get_or_insert_with(|| TagThreadPool::new(cx, cx.cpu_cores().max(3) - 2))
+        .execute_rev(value, move |value| {
+            let result = loader.load(&value);
+            Cx::post_action(TreeChildrenLoaded {
+                tree,
+                value,
+                result,
+            });
+        });
+}
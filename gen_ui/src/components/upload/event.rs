@@ -0,0 +1,54 @@
+use makepad_widgets::*;
+
+use super::file::{UploadFile, UploadReject};
+
+#[derive(Clone, Debug, DefaultNone)]
+pub enum UploadEvent {
+    Pick(UploadPick),
+    Added(UploadAdded),
+    Rejected(UploadRejected),
+    Done(UploadDone),
+    Failed(UploadFailed),
+    Removed(UploadRemoved),
+    None,
+}
+
+/// the pick button is clicked, open the file dialog and add the files by `add_files`
+#[derive(Debug, Clone)]
+pub struct UploadPick {
+    pub meta: FingerUpEvent,
+}
+
+/// the files passed the validation, `index` is the index of the first one
+#[derive(Debug, Clone)]
+pub struct UploadAdded {
+    pub index: usize,
+    pub files: Vec<UploadFile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadRejected {
+    pub path: String,
+    pub reason: UploadReject,
+}
+
+/// `response` is returned by the uploader
+#[derive(Debug, Clone)]
+pub struct UploadDone {
+    pub index: usize,
+    pub file: UploadFile,
+    pub response: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadFailed {
+    pub index: usize,
+    pub file: UploadFile,
+    pub error: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadRemoved {
+    pub index: usize,
+    pub file: UploadFile,
+}
@@ -0,0 +1,187 @@
+use std::{fmt::Display, path::PathBuf};
+
+/// a file picked or dropped into `GUpload`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadFile {
+    pub name: String,
+    pub path: PathBuf,
+    /// bytes
+    pub size: u64,
+}
+
+impl UploadFile {
+    /// read the size from the metadata of the file
+    pub fn from_path<P>(path: P) -> std::io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let metadata = std::fs::metadata(&path)?;
+        if metadata.is_dir() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "directory can not be uploaded",
+            ));
+        }
+        let name = path
+            .file_name()
+            .map_or(String::new(), |name| name.to_string_lossy().to_string());
+        Ok(Self {
+            name,
+            path,
+            size: metadata.len(),
+        })
+    }
+    /// the lowercase extension without the dot
+    pub fn extension(&self) -> String {
+        self.name
+            .rsplit_once('.')
+            .map_or(String::new(), |(_, ext)| ext.to_lowercase())
+    }
+    pub fn mime(&self) -> Option<&'static str> {
+        mime(&self.extension())
+    }
+    pub fn is_image(&self) -> bool {
+        self.mime().map_or(false, |mime| mime.starts_with("image/"))
+    }
+    /// check the file by `accept` (see `accepts`) and `max_size` (0 means no limit)
+    pub fn validate(&self, accept: &str, max_size: u64) -> Result<(), UploadReject> {
+        if !accepts(accept, self) {
+            return Err(UploadReject::Type);
+        }
+        if max_size > 0 && self.size > max_size {
+            return Err(UploadReject::Size(max_size));
+        }
+        Ok(())
+    }
+}
+
+/// why a file is not added
+#[derive(Debug, Clone, PartialEq)]
+pub enum UploadReject {
+    /// not matched by `accept`
+    Type,
+    /// larger than the max size
+    Size(u64),
+    /// more than the max count
+    Count(usize),
+    /// the file can not be read
+    Io(String),
+}
+
+impl Display for UploadReject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UploadReject::Type => f.write_str("file type is not accepted"),
+            UploadReject::Size(max) => write!(f, "file is larger than {}", format_size(*max)),
+            UploadReject::Count(max) => write!(f, "at most {} files", max),
+            UploadReject::Io(e) => f.write_str(e),
+        }
+    }
+}
+
+/// `accept` is a comma separated list like the `accept` of html input:
+/// - extension: `.png`
+/// - mime: `application/pdf`
+/// - mime with wildcard: `image/*`
+///
+/// empty accepts all files
+pub fn accepts(accept: &str, file: &UploadFile) -> bool {
+    if accept.trim().is_empty() {
+        return true;
+    }
+    let ext = file.extension();
+    let file_mime = file.mime().unwrap_or_default();
+    accept
+        .split(',')
+        .map(|item| item.trim().to_lowercase())
+        .filter(|item| !item.is_empty())
+        .any(|item| {
+            if let Some(item_ext) = item.strip_prefix('.') {
+                item_ext == ext
+            } else if let Some(kind) = item.strip_suffix("/*") {
+                file_mime
+                    .split_once('/')
+                    .map_or(false, |(file_kind, _)| file_kind == kind)
+            } else {
+                item == file_mime
+            }
+        })
+}
+
+/// `1.5 MB`
+pub fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if size < 1024 {
+        return format!("{} B", size);
+    }
+    let mut size = size as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+fn mime(ext: &str) -> Option<&'static str> {
+    let mime = match ext {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "bmp" => "image/bmp",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn file(name: &str, size: u64) -> UploadFile {
+        UploadFile {
+            name: name.to_string(),
+            path: PathBuf::from(name),
+            size,
+        }
+    }
+
+    #[test]
+    fn accept() {
+        let png = file("Logo.PNG", 10);
+        assert!(accepts("", &png));
+        assert!(accepts(".png", &png));
+        assert!(accepts("image/*", &png));
+        assert!(accepts(".pdf, image/png", &png));
+        assert!(!accepts("video/*, .jpg", &png));
+        assert!(!accepts("image/*", &file("README", 10)));
+        assert!(png.is_image());
+    }
+
+    #[test]
+    fn validate() {
+        let pdf = file("a.pdf", 2048);
+        assert_eq!(pdf.validate("application/pdf", 0), Ok(()));
+        assert_eq!(pdf.validate("image/*", 0), Err(UploadReject::Type));
+        assert_eq!(pdf.validate("", 1024), Err(UploadReject::Size(1024)));
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MB");
+    }
+}
@@ -0,0 +1,537 @@
+mod event;
+pub mod file;
+pub mod uploader;
+
+pub use event::*;
+pub use file::{UploadFile, UploadReject, format_size};
+pub use uploader::{LocalUploader, UploadReporter, Uploader};
+
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use makepad_widgets::*;
+
+use crate::{
+    components::{
+        button::GButtonWidgetExt,
+        image::GImageWidgetExt,
+        label::GLabelWidgetExt,
+        progress::GProgressWidgetExt,
+        view::{GView, GViewWidgetExt},
+    },
+    event_option, event_option_ref, inherits_view_livehook, inherits_view_widget_node,
+    prop::{UploadMode, manuel::SECONDARY},
+};
+
+use uploader::{UploadMessage, UploadMessageKind, spawn_upload};
+
+live_design! {
+    link genui_basic;
+
+    pub GUploadBase = {{GUpload}} {
+        style: {
+            basic: {
+                height: Fit,
+                width: Fill,
+                flow: Down,
+                spacing: 8.0,
+                background_visible: false,
+            }
+        }
+    }
+}
+
+/// upload files picked by the `pick` button or dropped from the system, the template contains:
+/// - `drop_zone`: the view accepts the dropped files, holds the `pick` button
+/// - `list`: the files, each one is created from `item` (`List` mode) or `card` (`Card` mode)
+///
+/// the `item` and `card` templates contain `thumbnail` (`GImage`, only for images in `Card` mode),
+/// `name`, `size`, `message`, `progress` (`GProgress`) and `cancel`, `retry`, `remove` buttons
+///
+/// the transfer is done by the `Uploader` set by `set_uploader`
+///
+/// ```rust,ignore
+/// let upload = self.gupload(id!(upload));
+/// upload.set_uploader(LocalUploader::new("/tmp/uploads"));
+/// if upload.pick(&actions).is_some() {
+///     // open the file dialog
+///     upload.add_files(cx, paths);
+/// }
+/// ```
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct GUpload {
+    #[deref]
+    pub deref_widget: GView,
+    /// comma separated extensions and mime types, such as `.pdf, image/*`, empty accepts all
+    #[live]
+    pub accept: String,
+    /// max bytes of a file, `0` is no limit
+    #[live]
+    pub max_size: u64,
+    /// max count of the files, `0` is no limit
+    #[live]
+    pub max_count: usize,
+    /// accept more than one file at once
+    #[live(true)]
+    pub multiple: bool,
+    /// start the upload once the file is added
+    #[live(true)]
+    pub auto_upload: bool,
+    #[live]
+    pub mode: UploadMode,
+    #[live]
+    item: Option<LivePtr>,
+    #[live]
+    card: Option<LivePtr>,
+    #[live(true)]
+    pub event_open: bool,
+    #[rust]
+    pub uploader: Option<Arc<dyn Uploader>>,
+    #[rust]
+    files: Vec<UploadItem>,
+    /// id of the next upload task
+    #[rust(1)]
+    next_task: u64,
+    #[rust]
+    drag_over: bool,
+}
+
+/// status of a file in `GUpload`
+#[derive(Debug, Clone, PartialEq)]
+pub enum UploadStatus {
+    Waiting,
+    /// progress in `0.0..=1.0`
+    Uploading(f32),
+    /// the response of the uploader
+    Done(String),
+    Failed(String),
+    Canceled,
+}
+
+struct UploadItem {
+    file: UploadFile,
+    status: UploadStatus,
+    /// id of the running task, `0` is not running
+    task: u64,
+    canceled: Option<Arc<AtomicBool>>,
+    widget: WidgetRef,
+}
+
+inherits_view_widget_node!(GUpload);
+
+impl Widget for GUpload {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        self.deref_widget.draw_walk(cx, scope, walk)
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        if !self.visible() {
+            return;
+        }
+        if let Event::Actions(actions) = event {
+            for action in actions {
+                if let Some(UploadMessage { upload, id, kind }) = action.downcast_ref() {
+                    if *upload == self.widget_uid() {
+                        self.handle_message(cx, *id, kind);
+                    }
+                }
+            }
+        }
+        self.handle_drop(cx, event);
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        if let Some(e) = self.gbutton(id!(pick)).clicked(&actions) {
+            self.active(cx, UploadEvent::Pick(UploadPick { meta: e.meta }));
+        }
+        let clicked = |id: &[LiveId; 1]| {
+            self.files
+                .iter()
+                .position(|item| item.widget.gbutton(id).clicked(&actions).is_some())
+        };
+        if let Some(index) = clicked(id!(cancel)) {
+            self.cancel(cx, index);
+        } else if let Some(index) = clicked(id!(retry)) {
+            self.start(cx, index);
+        } else if let Some(index) = clicked(id!(remove)) {
+            let _ = self.remove(cx, index);
+        }
+    }
+}
+
+impl LiveHook for GUpload {
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.render(cx);
+    }
+    inherits_view_livehook!();
+}
+
+impl GUpload {
+    /// rebuild the widgets of the files when the mode changes
+    fn render(&mut self, cx: &mut Cx) -> () {
+        self.glabel(id!(hint)).set_default_font_color(cx, SECONDARY);
+        if let Some(mut list) = self.gview(id!(list)).borrow_mut() {
+            list.style.basic.flow = if self.mode.is_card() {
+                Flow::RightWrap
+            } else {
+                Flow::Down
+            };
+        }
+        for index in 0..self.files.len() {
+            self.files[index].widget = self.item_widget(cx, &self.files[index].file);
+            self.sync_item(cx, index);
+        }
+        self.sync_list(cx);
+    }
+    fn template(&self) -> Option<LivePtr> {
+        if self.mode.is_card() {
+            self.card
+        } else {
+            self.item
+        }
+    }
+    fn handle_drop(&mut self, cx: &mut Cx, event: &Event) -> () {
+        let area = self.gview(id!(drop_zone)).area();
+        match event.drag_hits(cx, area) {
+            DragHit::Drag(f) => match f.state {
+                DragState::In | DragState::Over => {
+                    *f.response.lock().unwrap() = DragResponse::Copy;
+                    self.set_drag_over(cx, true);
+                }
+                DragState::Out => self.set_drag_over(cx, false),
+            },
+            DragHit::Drop(f) => {
+                self.set_drag_over(cx, false);
+                let paths = f
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        DragItem::FilePath { path, .. } => Some(PathBuf::from(path)),
+                        _ => None,
+                    })
+                    .collect();
+                self.add_files(cx, paths);
+            }
+            _ => {}
+        }
+    }
+    /// highlight the drop zone while files are dragged over it
+    fn set_drag_over(&mut self, cx: &mut Cx, drag_over: bool) -> () {
+        if self.drag_over == drag_over {
+            return;
+        }
+        self.drag_over = drag_over;
+        if let Some(mut zone) = self.gview(id!(drop_zone)).borrow_mut() {
+            zone.style.basic.background_visible = drag_over;
+        }
+        self.redraw(cx);
+    }
+    fn active(&mut self, cx: &mut Cx, e: UploadEvent) -> () {
+        if !self.event_open {
+            return;
+        }
+        self.scope_path.as_ref().map(|path| {
+            cx.widget_action(self.widget_uid(), path, e);
+        });
+    }
+    /// validate and add the files, the rejected files are emitted by `UploadEvent::Rejected`
+    pub fn add_files(&mut self, cx: &mut Cx, paths: Vec<PathBuf>) -> () {
+        let paths = if self.multiple {
+            paths
+        } else {
+            paths.into_iter().take(1).collect()
+        };
+        let mut added = vec![];
+        for path in paths {
+            // the only file is replaced, so it does not count
+            let count = if self.multiple { self.files.len() } else { 0 };
+            let checked = UploadFile::from_path(&path)
+                .map_err(|e| UploadReject::Io(e.to_string()))
+                .and_then(|file| {
+                    if self.max_count > 0 && count >= self.max_count {
+                        Err(UploadReject::Count(self.max_count))
+                    } else {
+                        file.validate(&self.accept, self.max_size).map(|_| file)
+                    }
+                });
+            match checked {
+                Ok(file) => {
+                    if !self.multiple {
+                        // replace the file only when the new one is accepted
+                        for index in (0..self.files.len()).rev() {
+                            let _ = self.remove(cx, index);
+                        }
+                    }
+                    added.push(file.clone());
+                    let widget = self.item_widget(cx, &file);
+                    self.files.push(UploadItem {
+                        file,
+                        status: UploadStatus::Waiting,
+                        task: 0,
+                        canceled: None,
+                        widget,
+                    });
+                    self.sync_item(cx, self.files.len() - 1);
+                }
+                Err(reason) => self.active(
+                    cx,
+                    UploadEvent::Rejected(UploadRejected {
+                        path: path.to_string_lossy().to_string(),
+                        reason,
+                    }),
+                ),
+            }
+        }
+        self.sync_list(cx);
+        if added.is_empty() {
+            return;
+        }
+        let start = self.files.len() - added.len();
+        self.active(
+            cx,
+            UploadEvent::Added(UploadAdded {
+                index: start,
+                files: added,
+            }),
+        );
+        if self.auto_upload {
+            for index in start..self.files.len() {
+                self.start(cx, index);
+            }
+        }
+    }
+    /// start (or retry) the upload of the file by the uploader
+    pub fn start(&mut self, cx: &mut Cx, index: usize) -> () {
+        let uid = self.widget_uid();
+        let task = self.next_task;
+        let Some(item) = self.files.get_mut(index) else {
+            return;
+        };
+        if matches!(item.status, UploadStatus::Uploading(_)) {
+            return;
+        }
+        item.status = UploadStatus::Uploading(0.0);
+        if let Some(uploader) = self.uploader.as_ref() {
+            item.task = task;
+            item.canceled
+                .replace(spawn_upload(uploader.clone(), uid, task, item.file.clone()));
+            self.next_task += 1;
+        }
+        self.sync_item(cx, index);
+    }
+    /// cancel the upload, the uploader stops when it checks `UploadReporter::is_canceled`
+    pub fn cancel(&mut self, cx: &mut Cx, index: usize) -> () {
+        let Some(item) = self.files.get_mut(index) else {
+            return;
+        };
+        if !matches!(item.status, UploadStatus::Uploading(_)) {
+            return;
+        }
+        if let Some(canceled) = item.canceled.take() {
+            canceled.store(true, Ordering::Relaxed);
+        }
+        item.task = 0;
+        item.status = UploadStatus::Canceled;
+        self.sync_item(cx, index);
+    }
+    /// remove the file, a running upload is canceled
+    pub fn remove(&mut self, cx: &mut Cx, index: usize) -> Option<UploadFile> {
+        if index >= self.files.len() {
+            return None;
+        }
+        self.cancel(cx, index);
+        let item = self.files.remove(index);
+        self.sync_list(cx);
+        self.active(
+            cx,
+            UploadEvent::Removed(UploadRemoved {
+                index,
+                file: item.file.clone(),
+            }),
+        );
+        Some(item.file)
+    }
+    pub fn clear(&mut self, cx: &mut Cx) -> () {
+        for index in (0..self.files.len()).rev() {
+            let _ = self.remove(cx, index);
+        }
+    }
+    /// set the progress (`0.0..=1.0`) when the upload is done without an uploader
+    pub fn set_progress(&mut self, cx: &mut Cx, index: usize, progress: f32) -> () {
+        if let Some(item) = self.files.get_mut(index) {
+            if matches!(
+                item.status,
+                UploadStatus::Waiting | UploadStatus::Uploading(_)
+            ) {
+                item.status = UploadStatus::Uploading(progress.clamp(0.0, 1.0));
+                self.sync_item(cx, index);
+            }
+        }
+    }
+    /// finish the upload, emits `UploadEvent::Done` or `UploadEvent::Failed`
+    pub fn finish(&mut self, cx: &mut Cx, index: usize, result: Result<String, String>) -> () {
+        let Some(item) = self.files.get_mut(index) else {
+            return;
+        };
+        item.task = 0;
+        item.canceled = None;
+        let file = item.file.clone();
+        let e = match result {
+            Ok(response) => {
+                item.status = UploadStatus::Done(response.to_string());
+                UploadEvent::Done(UploadDone {
+                    index,
+                    file,
+                    response,
+                })
+            }
+            Err(error) => {
+                item.status = UploadStatus::Failed(error.to_string());
+                UploadEvent::Failed(UploadFailed { index, file, error })
+            }
+        };
+        self.sync_item(cx, index);
+        self.active(cx, e);
+    }
+    fn handle_message(&mut self, cx: &mut Cx, id: u64, kind: &UploadMessageKind) -> () {
+        // the task is canceled or the file is removed
+        let Some(index) = self.files.iter().position(|item| item.task == id) else {
+            return;
+        };
+        match kind {
+            UploadMessageKind::Progress(progress) => self.set_progress(cx, index, *progress),
+            UploadMessageKind::Finished(result) => self.finish(cx, index, result.clone()),
+        }
+    }
+    pub fn set_uploader<U>(&mut self, uploader: U) -> ()
+    where
+        U: Uploader,
+    {
+        self.uploader.replace(Arc::new(uploader));
+    }
+    pub fn files(&self) -> Vec<UploadFile> {
+        self.files.iter().map(|item| item.file.clone()).collect()
+    }
+    pub fn status(&self, index: usize) -> Option<UploadStatus> {
+        self.files.get(index).map(|item| item.status.clone())
+    }
+    fn sync_list(&mut self, cx: &mut Cx) -> () {
+        if let Some(mut list) = self.gview(id!(list)).borrow_mut() {
+            list.children = self
+                .files
+                .iter()
+                .enumerate()
+                .map(|(index, item)| (LiveId(index as u64 + 1), item.widget.clone()))
+                .collect();
+            list.visible = !self.files.is_empty();
+        }
+        self.redraw(cx);
+    }
+    /// create the widget of the file, the name, size and thumbnail do not change after created
+    fn item_widget(&self, cx: &mut Cx, file: &UploadFile) -> WidgetRef {
+        let widget = WidgetRef::new_from_ptr(cx, self.template());
+        let _ = widget.glabel(id!(name)).set_text(cx, file.name.to_string());
+        let _ = widget
+            .glabel(id!(size))
+            .set_text(cx, format_size(file.size));
+        for label in [id!(size), id!(message)] {
+            widget.glabel(label).set_default_font_color(cx, SECONDARY);
+        }
+        let thumbnail = self.mode.is_card() && file.is_image();
+        if let Some(mut image) = widget.gimage(id!(thumbnail)).borrow_mut() {
+            image.visible = thumbnail;
+            if thumbnail {
+                if let Err(e) = image.load(cx, &file.path.to_string_lossy()) {
+                    error!("GUpload can not load thumbnail {}: {}", file.name, e);
+                }
+            }
+        }
+        widget
+    }
+    /// update the widget of the file by its status
+    fn sync_item(&mut self, cx: &mut Cx, index: usize) -> () {
+        let Some(item) = self.files.get(index) else {
+            return;
+        };
+        let widget = &item.widget;
+        let (progress, message) = match &item.status {
+            UploadStatus::Waiting => (None, "waiting".to_string()),
+            UploadStatus::Uploading(progress) => {
+                (Some(*progress), format!("{:.0}%", progress * 100.0))
+            }
+            UploadStatus::Done(_) => (None, "done".to_string()),
+            UploadStatus::Failed(error) => (None, error.to_string()),
+            UploadStatus::Canceled => (None, "canceled".to_string()),
+        };
+        if let Some(mut bar) = widget.gprogress(id!(progress)).borrow_mut() {
+            bar.visible = progress.is_some();
+            bar.value = progress.unwrap_or_default();
+        }
+        let _ = widget.glabel(id!(message)).set_text(cx, message);
+        let uploading = matches!(item.status, UploadStatus::Uploading(_));
+        let retry = matches!(
+            item.status,
+            UploadStatus::Failed(_) | UploadStatus::Canceled
+        ) || (item.status == UploadStatus::Waiting && !self.auto_upload);
+        let _ = widget.gbutton(id!(cancel)).set_visible(cx, uploading);
+        let _ = widget.gbutton(id!(retry)).set_visible(cx, retry);
+        let _ = widget.gbutton(id!(remove)).set_visible(cx, !uploading);
+        widget.redraw(cx);
+    }
+    event_option! {
+        pick: UploadEvent::Pick => UploadPick,
+        added: UploadEvent::Added => UploadAdded,
+        rejected: UploadEvent::Rejected => UploadRejected,
+        done: UploadEvent::Done => UploadDone,
+        failed: UploadEvent::Failed => UploadFailed,
+        removed: UploadEvent::Removed => UploadRemoved
+    }
+}
+
+impl GUploadRef {
+    pub fn set_uploader<U>(&self, uploader: U) -> ()
+    where
+        U: Uploader,
+    {
+        self.borrow_mut().map(|mut c| c.set_uploader(uploader));
+    }
+    pub fn add_files(&self, cx: &mut Cx, paths: Vec<PathBuf>) -> () {
+        self.borrow_mut().map(|mut c| c.add_files(cx, paths));
+    }
+    pub fn start(&self, cx: &mut Cx, index: usize) -> () {
+        self.borrow_mut().map(|mut c| c.start(cx, index));
+    }
+    pub fn cancel(&self, cx: &mut Cx, index: usize) -> () {
+        self.borrow_mut().map(|mut c| c.cancel(cx, index));
+    }
+    pub fn remove(&self, cx: &mut Cx, index: usize) -> Option<UploadFile> {
+        self.borrow_mut().and_then(|mut c| c.remove(cx, index))
+    }
+    pub fn clear(&self, cx: &mut Cx) -> () {
+        self.borrow_mut().map(|mut c| c.clear(cx));
+    }
+    pub fn set_progress(&self, cx: &mut Cx, index: usize, progress: f32) -> () {
+        self.borrow_mut()
+            .map(|mut c| c.set_progress(cx, index, progress));
+    }
+    pub fn finish(&self, cx: &mut Cx, index: usize, result: Result<String, String>) -> () {
+        self.borrow_mut().map(|mut c| c.finish(cx, index, result));
+    }
+    pub fn files(&self) -> Vec<UploadFile> {
+        self.borrow().map_or(vec![], |c| c.files())
+    }
+    pub fn status(&self, index: usize) -> Option<UploadStatus> {
+        self.borrow().and_then(|c| c.status(index))
+    }
+    event_option_ref! {
+        pick => UploadPick,
+        added => UploadAdded,
+        rejected => UploadRejected,
+        done => UploadDone,
+        failed => UploadFailed,
+        removed => UploadRemoved
+    }
+}
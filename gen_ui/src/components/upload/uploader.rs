@@ -0,0 +1,218 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU32, Ordering},
+    },
+};
+
+use makepad_widgets::WidgetUid;
+
+use super::file::UploadFile;
+
+/// # Uploader
+/// Transfer a file of `GUpload`, `upload` runs in a background thread so it can block
+/// (http request, s3 put object, copy, ...). Report the progress by the `UploadReporter`
+/// and stop when it is canceled, the `Ok` value is the response (such as the url of the file).
+/// Set it by `GUpload::set_uploader`, if no uploader is set, handle `UploadEvent::Added`
+/// and call `GUpload::set_progress` / `GUpload::finish` yourself.
+pub trait Uploader: Send + Sync + 'static {
+    fn upload(&self, file: &UploadFile, reporter: &UploadReporter) -> Result<String, String>;
+}
+
+impl<F> Uploader for F
+where
+    F: Fn(&UploadFile, &UploadReporter) -> Result<String, String> + Send + Sync + 'static,
+{
+    fn upload(&self, file: &UploadFile, reporter: &UploadReporter) -> Result<String, String> {
+        self(file, reporter)
+    }
+}
+
+/// handed to the uploader to report the progress and check the cancel
+#[derive(Debug, Clone)]
+pub struct UploadReporter {
+    upload: WidgetUid,
+    id: u64,
+    canceled: Arc<AtomicBool>,
+    /// the last posted percent + 1, `0` if nothing is posted
+    posted: Arc<AtomicU32>,
+}
+
+impl UploadReporter {
+    /// `progress` is in `0.0..=1.0`, it is posted only when the percent changes,
+    /// so it can be called for every chunk
+    pub fn progress(&self, progress: f32) -> () {
+        let progress = progress.clamp(0.0, 1.0);
+        let percent = (progress * 100.0) as u32 + 1;
+        if self.posted.swap(percent, Ordering::Relaxed) == percent {
+            return;
+        }
+        makepad_widgets::Cx::post_action(UploadMessage {
+            upload: self.upload,
+            id: self.id,
+            kind: UploadMessageKind::Progress(progress),
+        });
+    }
+    pub fn is_canceled(&self) -> bool {
+        self.canceled.load(Ordering::Relaxed)
+    }
+}
+
+/// posted from the uploader thread back to the `GUpload` which started the upload
+#[derive(Debug)]
+pub struct UploadMessage {
+    pub upload: WidgetUid,
+    /// id of the upload task, a retry starts a new task
+    pub id: u64,
+    pub kind: UploadMessageKind,
+}
+
+#[derive(Debug)]
+pub enum UploadMessageKind {
+    Progress(f32),
+    Finished(Result<String, String>),
+}
+
+pub(crate) fn spawn_upload(
+    uploader: Arc<dyn Uploader>,
+    upload: WidgetUid,
+    id: u64,
+    file: UploadFile,
+) -> Arc<AtomicBool> {
+    let canceled = Arc::new(AtomicBool::new(false));
+    let reporter = UploadReporter {
+        upload,
+        id,
+        canceled: canceled.clone(),
+        posted: Arc::new(AtomicU32::new(0)),
+    };
+    std::thread::spawn(move || {
+        let result = uploader.upload(&file, &reporter);
+        makepad_widgets::Cx::post_action(UploadMessage {
+            upload,
+            id,
+            kind: UploadMessageKind::Finished(result),
+        });
+    });
+    canceled
+}
+
+/// copy the files into `dir`, the response is the path of the copy.
+/// Existing files are never overwritten, the copy is renamed as `name (1).ext` instead
+#[derive(Debug, Clone)]
+pub struct LocalUploader {
+    pub dir: PathBuf,
+}
+
+impl LocalUploader {
+    pub fn new<P>(dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { dir: dir.into() }
+    }
+}
+
+impl Uploader for LocalUploader {
+    fn upload(&self, file: &UploadFile, reporter: &UploadReporter) -> Result<String, String> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| e.to_string())?;
+        let mut from = File::open(&file.path).map_err(|e| e.to_string())?;
+        let (target, mut to) = create_new(&self.dir, &file.name).map_err(|e| e.to_string())?;
+        let mut copy = || -> Result<(), String> {
+            let mut buf = vec![0; 64 * 1024];
+            let mut copied = 0;
+            loop {
+                if reporter.is_canceled() {
+                    return Err("canceled".to_string());
+                }
+                let len = from.read(&mut buf).map_err(|e| e.to_string())?;
+                if len == 0 {
+                    return Ok(());
+                }
+                to.write_all(&buf[..len]).map_err(|e| e.to_string())?;
+                copied += len as u64;
+                reporter.progress(copied as f32 / file.size.max(1) as f32);
+            }
+        };
+        match copy() {
+            Ok(_) => Ok(target.to_string_lossy().to_string()),
+            Err(e) => {
+                // the file is created by this upload, remove the partial copy
+                drop(to);
+                let _ = std::fs::remove_file(&target);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// create a file which does not exist in `dir`, `name (1).ext`, `name (2).ext` ... are tried
+/// when `name` is taken, only the file name of `name` is used so the file stays in `dir`
+fn create_new(dir: &Path, name: &str) -> std::io::Result<(PathBuf, File)> {
+    let name = match Path::new(name).file_name() {
+        Some(file_name) => file_name.to_string_lossy().to_string(),
+        None => {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid file name: {}", name),
+            ));
+        }
+    };
+    let path = Path::new(&name);
+    let stem = path
+        .file_stem()
+        .map_or(name.to_string(), |stem| stem.to_string_lossy().to_string());
+    let ext = path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    let mut index = 0;
+    loop {
+        let target = if index == 0 {
+            dir.join(&name)
+        } else {
+            dir.join(format!("{} ({}){}", stem, index, ext))
+        };
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&target)
+        {
+            Ok(file) => return Ok((target, file)),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists && index < 9999 => index += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn create_new_renames() {
+        let dir = std::env::temp_dir().join(format!("genui_upload_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "keep").unwrap();
+        let (first, _) = create_new(&dir, "a.txt").unwrap();
+        let (second, _) = create_new(&dir, "a.txt").unwrap();
+        assert_eq!(first, dir.join("a (1).txt"));
+        assert_eq!(second, dir.join("a (2).txt"));
+        assert_eq!(std::fs::read_to_string(dir.join("a.txt")).unwrap(), "keep");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn create_new_keeps_dir() {
+        let dir = std::env::temp_dir().join(format!("genui_upload_dir_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (target, _) = create_new(&dir, "../../b.txt").unwrap();
+        assert_eq!(target, dir.join("b.txt"));
+        assert!(create_new(&dir, "..").is_err());
+        assert!(create_new(&dir, "").is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
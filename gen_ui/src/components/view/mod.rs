@@ -1021,6 +1021,14 @@ impl GView {
         }
     }
 
+    /// scroll by `delta` if the view has scroll bars, return true if the scroll position changed
+    pub fn scroll_by(&mut self, cx: &mut Cx, delta: DVec2) -> bool {
+        if let Some(scroll_bars) = self.scroll_bars_obj.as_mut() {
+            let pos = scroll_bars.get_scroll_pos() + delta;
+            return scroll_bars.set_scroll_pos(cx, pos);
+        }
+        false
+    }
     getter! {
         GView {
             get_theme(Theme) {|c| {c.style.basic.get_theme()}},
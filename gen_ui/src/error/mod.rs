@@ -8,6 +8,8 @@ pub enum Error {
     ConflictHeight,
     /// called when widget width is fixed and bigger than max width or smaller than min width.
     ConflictWidth,
+    /// called when range mode is used with a mode which can not draw two draggers. (In GSlider)
+    ConflictRangeMode,
     /// can not load theme style file
     ThemeStyleFileLoad(String),
     ThemeStyleParse(String),
@@ -31,6 +33,9 @@ impl Display for Error {
             Error::ConflictWidth => f.write_str(
                 "Widget width is fixed and bigger than max width or smaller than min width.",
             ),
+            Error::ConflictRangeMode => f.write_str(
+                "Range mode only works with horizontal or vertical mode, it is turned off.",
+            ),
             Error::ThemeStyleFileLoad(e) => {
                 f.write_fmt(format_args!("Cannot load theme style file: {}", e))
             }
@@ -210,7 +210,7 @@ impl Default for Position4 {
     }
 }
 
-#[derive(Copy, Clone, Debug, Live, LiveHook)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Live, LiveHook)]
 #[live_ignore]
 #[repr(u32)]
 pub enum Position {
@@ -244,6 +244,65 @@ impl Position {
             Position::Bottom | Position::BottomLeft | Position::BottomRight => Position::Bottom,
         }
     }
+    /// the position on the opposite side of the trigger, the alignment is kept
+    pub fn flip(&self) -> Self {
+        match self {
+            Position::Left => Position::Right,
+            Position::LeftTop => Position::RightTop,
+            Position::LeftBottom => Position::RightBottom,
+            Position::Right => Position::Left,
+            Position::RightTop => Position::LeftTop,
+            Position::RightBottom => Position::LeftBottom,
+            Position::Top => Position::Bottom,
+            Position::TopLeft => Position::BottomLeft,
+            Position::TopRight => Position::BottomRight,
+            Position::Bottom => Position::Top,
+            Position::BottomLeft => Position::TopLeft,
+            Position::BottomRight => Position::TopRight,
+        }
+    }
+    /// is the popup placed beside the trigger (left or right)
+    pub fn is_horizontal(&self) -> bool {
+        matches!(
+            self,
+            Position::Left
+                | Position::LeftTop
+                | Position::LeftBottom
+                | Position::Right
+                | Position::RightTop
+                | Position::RightBottom
+        )
+    }
+    /// the side of the popup facing the trigger, used by the arrow of `DrawPopup`
+    /// - 1.0: top
+    /// - 2.0: right
+    /// - 3.0: bottom
+    /// - 4.0: left
+    pub fn arrow_side(&self) -> f32 {
+        match self {
+            Position::Bottom | Position::BottomLeft | Position::BottomRight => 1.0,
+            Position::Left | Position::LeftTop | Position::LeftBottom => 2.0,
+            Position::Top | Position::TopLeft | Position::TopRight => 3.0,
+            Position::Right | Position::RightTop | Position::RightBottom => 4.0,
+        }
+    }
+    /// shift of the popup from the top left corner of the trigger
+    pub fn popup_shift(&self, trigger: DVec2, popup: DVec2, offset: f64) -> DVec2 {
+        match self {
+            Position::Bottom => dvec2(trigger.x / 2.0 - popup.x / 2.0, trigger.y + offset),
+            Position::BottomLeft => dvec2(0.0, trigger.y + offset),
+            Position::BottomRight => dvec2(trigger.x - popup.x, trigger.y + offset),
+            Position::Top => dvec2(trigger.x / 2.0 - popup.x / 2.0, -offset - popup.y),
+            Position::TopLeft => dvec2(0.0, -offset - popup.y),
+            Position::TopRight => dvec2(trigger.x - popup.x, -offset - popup.y),
+            Position::Left => dvec2(-offset - popup.x, trigger.y / 2.0 - popup.y / 2.0),
+            Position::LeftTop => dvec2(-offset - popup.x, 0.0),
+            Position::LeftBottom => dvec2(-offset - popup.x, trigger.y - popup.y),
+            Position::Right => dvec2(trigger.x + offset, trigger.y / 2.0 - popup.y / 2.0),
+            Position::RightTop => dvec2(trigger.x + offset, 0.0),
+            Position::RightBottom => dvec2(trigger.x + offset, trigger.y - popup.y),
+        }
+    }
     /// return angle offset
     pub fn angle_offset(&self, size: DVec2) -> f32 {
         match self {
@@ -269,6 +328,8 @@ pub enum TriggerMode {
     Click = shader_enum(1),
     Hover = shader_enum(2),
     Press = shader_enum(3),
+    /// secondary (right) click, the popup opens at the pointer position
+    ContextMenu = shader_enum(4),
 }
 
 impl Default for TriggerMode {
@@ -287,4 +348,7 @@ impl TriggerMode {
     pub fn is_press(&self) -> bool {
         matches!(self, TriggerMode::Press)
     }
+    pub fn is_context_menu(&self) -> bool {
+        matches!(self, TriggerMode::ContextMenu)
+    }
 }
@@ -0,0 +1,123 @@
+/// # Image Format
+/// Format of the image data, detected by content sniffing (magic bytes), never by extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImgFormat {
+    Png,
+    /// animated png (png with `acTL` chunk before `IDAT`)
+    Apng,
+    Jpeg,
+    Gif,
+    WebP,
+    Bmp,
+    /// svg can not be decoded into a texture, `GImage` draws it by `GSvg` wherever it comes from
+    Svg,
+}
+
+impl ImgFormat {
+    pub fn sniff(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            return if is_apng(data) {
+                Some(ImgFormat::Apng)
+            } else {
+                Some(ImgFormat::Png)
+            };
+        }
+        if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            return Some(ImgFormat::Jpeg);
+        }
+        if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            return Some(ImgFormat::Gif);
+        }
+        if data.len() >= 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+            return Some(ImgFormat::WebP);
+        }
+        if data.len() >= 14 && data.starts_with(b"BM") {
+            return Some(ImgFormat::Bmp);
+        }
+        if is_svg(data) {
+            return Some(ImgFormat::Svg);
+        }
+        None
+    }
+    /// can this format be decoded into a texture
+    pub fn is_decodable(&self) -> bool {
+        !matches!(self, ImgFormat::Svg)
+    }
+}
+
+/// png chunks: length(4) + type(4) + data(length) + crc(4), `acTL` must appear before `IDAT`
+fn is_apng(data: &[u8]) -> bool {
+    let mut offset = 8;
+    while offset + 8 <= data.len() {
+        let len = u32::from_be_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ]) as usize;
+        match &data[offset + 4..offset + 8] {
+            b"acTL" => return true,
+            b"IDAT" => return false,
+            _ => {}
+        }
+        offset += 12 + len;
+    }
+    false
+}
+
+fn is_svg(data: &[u8]) -> bool {
+    let head = &data[..data.len().min(1024)];
+    let head = String::from_utf8_lossy(head);
+    let head = head.trim_start_matches('\u{feff}').trim_start();
+    (head.starts_with("<?xml") || head.starts_with("<svg") || head.starts_with("<!--"))
+        && head.contains("<svg")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImgFormat;
+
+    #[test]
+    fn sniff_by_content() {
+        assert_eq!(
+            ImgFormat::sniff(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR"),
+            Some(ImgFormat::Png)
+        );
+        assert_eq!(
+            ImgFormat::sniff(&[0xFF, 0xD8, 0xFF, 0xE0]),
+            Some(ImgFormat::Jpeg)
+        );
+        assert_eq!(
+            ImgFormat::sniff(b"GIF89a\x01\0\x01\0"),
+            Some(ImgFormat::Gif)
+        );
+        assert_eq!(
+            ImgFormat::sniff(b"RIFF\x24\0\0\0WEBPVP8 "),
+            Some(ImgFormat::WebP)
+        );
+        assert_eq!(
+            ImgFormat::sniff(b"BM\x46\0\0\0\0\0\0\0\x36\0\0\0"),
+            Some(ImgFormat::Bmp)
+        );
+        assert_eq!(
+            ImgFormat::sniff(b"<?xml version=\"1.0\"?>\n<svg xmlns=\"\"></svg>"),
+            Some(ImgFormat::Svg)
+        );
+        assert_eq!(ImgFormat::sniff(b"<html></html>"), None);
+    }
+
+    #[test]
+    fn sniff_apng() {
+        let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+        // IHDR (13 bytes)
+        data.extend_from_slice(&[0, 0, 0, 13]);
+        data.extend_from_slice(b"IHDR");
+        data.extend_from_slice(&[0; 13 + 4]);
+        let png = [data.clone(), [0, 0, 0, 0].to_vec(), b"IDAT".to_vec()].concat();
+        assert_eq!(ImgFormat::sniff(&png), Some(ImgFormat::Png));
+        // acTL (8 bytes) before IDAT
+        data.extend_from_slice(&[0, 0, 0, 8]);
+        data.extend_from_slice(b"acTL");
+        assert_eq!(ImgFormat::sniff(&data), Some(ImgFormat::Apng));
+    }
+}
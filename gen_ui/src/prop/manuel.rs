@@ -67,6 +67,7 @@ pub const LINE_SPACING: &str = "line_spacing";
 // --- state -----------------------------------------------------
 pub const HOVER: &str = "hover";
 pub const PRESSED: &str = "pressed";
+pub const VISITED: &str = "visited";
 pub const DISABLED: &str = "disabled";
 pub const BASIC: &str = "basic";
 pub const ACTIVE: &str = "active";
@@ -90,6 +91,7 @@ pub const SUFFIX: &str = "suffix";
 pub const PREFIX: &str = "prefix";
 pub const SELECTION: &str = "selection";
 pub const ITEM: &str = "item";
+pub const DESCRIPTION: &str = "description";
 // --- component -------------------------------------------------
 pub const LABEL: &str = "label";
 pub const VIEW: &str = "view";
@@ -7,6 +7,7 @@ mod slot;
 mod mode;
 mod direction;
 mod src;
+mod img_format;
 mod route;
 mod prop_converter;
 
@@ -16,4 +17,5 @@ pub use defer_walk::*;
 pub use slot::*;
 pub use mode::*;
 pub use direction::*;
-pub use src::*;
\ No newline at end of file
+pub use src::*;
+pub use img_format::*;
\ No newline at end of file
@@ -0,0 +1,22 @@
+use makepad_widgets::*;
+
+/// who sends the message of `GBubble`, decides the side of the avatar and the alignment
+#[derive(Live, LiveHook, PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[live_ignore]
+#[repr(u32)]
+pub enum BubbleRole {
+    /// aligned to the right, avatar after the content
+    User = shader_enum(1),
+    /// aligned to the left, avatar before the content
+    #[pick]
+    #[default]
+    Assistant = shader_enum(2),
+    /// centered, without avatar
+    System = shader_enum(3),
+}
+
+impl BubbleRole {
+    pub fn is_user(&self) -> bool {
+        matches!(self, BubbleRole::User)
+    }
+}
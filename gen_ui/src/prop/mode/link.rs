@@ -0,0 +1,20 @@
+use makepad_widgets::*;
+
+/// where `GLink` opens its `href`
+#[derive(Live, LiveHook, PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[live_ignore]
+#[repr(u32)]
+pub enum LinkTarget {
+    /// routes navigate the router, external urls are opened as usual (`_self`)
+    #[pick]
+    #[default]
+    Current = shader_enum(1),
+    /// ask the opener for a new window or tab (`_blank`)
+    Blank = shader_enum(2),
+}
+
+impl LinkTarget {
+    pub fn is_blank(&self) -> bool {
+        matches!(self, LinkTarget::Blank)
+    }
+}
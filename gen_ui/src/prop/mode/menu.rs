@@ -1,3 +1,25 @@
+use makepad_widgets::*;
+
+/// layout of `GMenu`
+#[derive(Live, LiveHook, PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[live_ignore]
+#[repr(u32)]
+pub enum MenuMode {
+    /// items are stacked vertically, sub menus expand inline
+    #[pick]
+    #[default]
+    Vertical = shader_enum(1),
+    /// menu bar: top level items are laid out horizontally and their sub menus drop down,
+    /// once a sub menu is opened, hovering another top level sub menu switches to it
+    Horizontal = shader_enum(2),
+}
+
+impl MenuMode {
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, MenuMode::Horizontal)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum MenuItemMode {
     /// sub menu which has a title and items, items can be sub menu or menu item
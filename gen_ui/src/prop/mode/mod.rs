@@ -3,8 +3,14 @@ mod active;
 mod popup;
 mod router;
 mod menu;
+mod link;
+mod chat;
+mod upload;
 
 pub use active::*;
 pub use popup::*;
 pub use router::*;
 pub use menu::*;
+pub use link::*;
+pub use chat::*;
+pub use upload::*;
@@ -0,0 +1,20 @@
+use makepad_widgets::*;
+
+/// how `GUpload` shows the files
+#[derive(Live, LiveHook, PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[live_ignore]
+#[repr(u32)]
+pub enum UploadMode {
+    /// a row for each file: name, size, progress and actions
+    #[pick]
+    #[default]
+    List = shader_enum(1),
+    /// wrapped cards with the thumbnail of the image files
+    Card = shader_enum(2),
+}
+
+impl UploadMode {
+    pub fn is_card(&self) -> bool {
+        matches!(self, UploadMode::Card)
+    }
+}
@@ -3,6 +3,8 @@ use std::{path::PathBuf, str::FromStr};
 use base64::{engine::general_purpose, Engine};
 use makepad_widgets::{image_cache::ImageError, *};
 
+use super::ImgFormat;
+
 #[derive(Clone, Debug, Live, LiveHook)]
 #[live_ignore]
 pub enum Src {
@@ -67,7 +69,7 @@ impl ToString for Src {
 pub enum SrcType {
     Path(PathBuf),
     Url(String),
-    Base64 { data: Vec<u8>, ty: imghdr::Type },
+    Base64 { data: Vec<u8>, format: ImgFormat },
 }
 
 impl FromStr for SrcType {
@@ -76,24 +78,13 @@ impl FromStr for SrcType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.trim();
         if s.starts_with("data:image") {
-            // remove the prefix, split `,`
-            let base_slice = s.split(',').collect::<Vec<&str>>();
-            let ty = base_slice.get(0).map_or_else(
-                || Err(ImageError::UnsupportedFormat),
-                |ty| match *ty {
-                    "data:image/png;base64" => Ok(imghdr::Type::Png),
-                    "data:image/jpeg;base64" => Ok(imghdr::Type::Jpeg),
-                    _ => return Err(ImageError::UnsupportedFormat),
-                },
-            )?;
-            base_slice
-                .get(1)
-                .map_or(Err(ImageError::UnsupportedFormat), |data| {
-                    let buf = general_purpose::STANDARD
-                        .decode(data)
-                        .map_err(|_| ImageError::UnsupportedFormat)?;
-                    Ok(SrcType::Base64 { data: buf, ty })
-                })
+            // remove the prefix, split `,`, format is detected from the data not the mime type
+            let (_, data) = s.split_once(";base64,").ok_or(ImageError::UnsupportedFormat)?;
+            let data = general_purpose::STANDARD
+                .decode(data)
+                .map_err(|_| ImageError::UnsupportedFormat)?;
+            let format = ImgFormat::sniff(&data).ok_or(ImageError::UnsupportedFormat)?;
+            Ok(SrcType::Base64 { data, format })
         } else if s.starts_with("http") || s.starts_with("https") {
             Ok(SrcType::Url(s.to_string()))
         } else {
@@ -27,7 +27,19 @@ live_design! {
             }
             // draw border
             sdf.stroke(self.border_color, self.border_width);
-            // draw inner
+            // draw inner, indeterminate (partially checked) is always a dash
+            if self.indeterminate > 0.5 {
+                let dash = vec2(self.rect_size.x * 0.5, self.rect_size.y * 0.12);
+                sdf.box(
+                    center.x - dash.x * 0.5,
+                    center.y - dash.y * 0.5,
+                    dash.x,
+                    dash.y,
+                    dash.y * 0.5
+                );
+                sdf.fill(self.stroke_color);
+                return sdf.result;
+            }
             match self.mode {
                 ActiveMode::Round => {
                     sdf.box(
@@ -89,6 +101,9 @@ pub struct DrawCheckbox {
     // ---- type
     #[live]
     pub mode: ActiveMode,
+    /// partially checked (`1.0`), draw a dash instead of `mode`
+    #[live]
+    pub indeterminate: f32,
 }
 
 impl DrawCheckbox {
@@ -0,0 +1,44 @@
+use makepad_widgets::*;
+
+live_design! {
+    use link::shaders::*;
+    DrawContribution = {{DrawContribution}}{
+
+        fn get_color(self) -> vec4 { return self.color; }
+
+        fn pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            // [cell] ---------------------------------------------------------------------
+            sdf.box(
+                1.0,
+                1.0,
+                self.rect_size.x - 2.0,
+                self.rect_size.y - 2.0,
+                self.border_radius
+            );
+            sdf.fill_keep(self.get_color());
+            // [hover border] -------------------------------------------------------------
+            if self.hover > 0.0 {
+                sdf.stroke(self.hover_color, 1.0);
+            }
+            return sdf.result;
+        }
+    }
+}
+
+/// a cell of `GContribution`, all the cells are instances of the same shader so the grid is
+/// drawn in one draw call
+#[derive(Live, LiveRegister, LiveHook)]
+#[repr(C)]
+pub struct DrawContribution {
+    #[deref]
+    pub draw_super: DrawQuad,
+    #[live]
+    pub color: Vec4,
+    #[live(2.0)]
+    pub border_radius: f32,
+    #[live]
+    pub hover_color: Vec4,
+    #[live]
+    pub hover: f32,
+}
@@ -6,8 +6,22 @@ live_design! {
     DrawImg = {{DrawImg}}{
         texture image: texture2d
 
+        // rotate clockwise in 90° steps (rotation: 0, 1, 2, 3)
+        fn get_rotated_pos(self) -> vec2 {
+            if self.rotation > 2.5 {
+                return vec2(1.0 - self.pos.y, self.pos.x);
+            }
+            if self.rotation > 1.5 {
+                return vec2(1.0 - self.pos.x, 1.0 - self.pos.y);
+            }
+            if self.rotation > 0.5 {
+                return vec2(self.pos.y, 1.0 - self.pos.x);
+            }
+            return self.pos;
+        }
+
         fn get_color_scale_pan(self, scale: vec2, pan: vec2) -> vec4 {
-            return sample2d(self.image, self.pos * scale + pan).xyzw;
+            return sample2d(self.image, self.get_rotated_pos() * scale + pan).xyzw;
         }
 
         fn get_color(self) -> vec4 {
@@ -34,4 +48,7 @@ pub struct DrawImg {
     pub image_pan: Vec2,
     #[live]
     pub load: f32,
+    /// clockwise rotation in 90° steps, `0.0` ~ `3.0`
+    #[live]
+    pub rotation: f32,
 }
@@ -6,7 +6,64 @@ use crate::{prop::Position, shader::draw_view::DrawView};
 live_design! {
     use link::shaders::*;
 
-    DrawPopup = {{DrawPopup}}{}
+    DrawPopup = {{DrawPopup}}{
+        // popup with an arrow pointing at the trigger, use it as `fn pixel(self) -> vec4 { return self.arrow_pixel(); }`
+        // the arrow takes `arrow_size` from the side facing the trigger, `angle_offset` is where the arrow is
+        fn arrow_pixel(self) -> vec4 {
+            let sdf = Sdf2d::viewport(self.pos * self.rect_size);
+            let a = self.arrow_size;
+            let offset = self.angle_offset;
+            let body_pos = vec2(0.0, 0.0);
+            let body_size = self.rect_size;
+            let tip = vec2(offset, 0.0);
+            let base1 = vec2(offset - a, a);
+            let base2 = vec2(offset + a, a);
+            if self.arrow_side == 1.0 {
+                body_pos.y = a;
+                body_size.y = body_size.y - a;
+            } else if self.arrow_side == 2.0 {
+                body_size.x = body_size.x - a;
+                tip = vec2(self.rect_size.x, offset);
+                base1 = vec2(self.rect_size.x - a, offset - a);
+                base2 = vec2(self.rect_size.x - a, offset + a);
+            } else if self.arrow_side == 3.0 {
+                body_size.y = body_size.y - a;
+                tip = vec2(offset, self.rect_size.y);
+                base1 = vec2(offset - a, self.rect_size.y - a);
+                base2 = vec2(offset + a, self.rect_size.y - a);
+            } else {
+                body_pos.x = a;
+                body_size.x = body_size.x - a;
+                tip = vec2(0.0, offset);
+                base1 = vec2(a, offset - a);
+                base2 = vec2(a, offset + a);
+            }
+            let border_radius = self.count_border_radius();
+            sdf.box_all(
+                body_pos.x,
+                body_pos.y,
+                body_size.x,
+                body_size.y,
+                border_radius.x,
+                border_radius.y,
+                border_radius.z,
+                border_radius.w
+            );
+            if a > 0.0 {
+                sdf.move_to(tip.x, tip.y);
+                sdf.line_to(base1.x, base1.y);
+                sdf.line_to(base2.x, base2.y);
+                sdf.close_path();
+            }
+            if self.border_width != 0.0 {
+                sdf.stroke_keep(self.get_border_color(), self.border_width);
+            }
+            if self.background_visible == 1.0 {
+                sdf.fill(self.get_background_color());
+            }
+            return sdf.result;
+        }
+    }
 }
 
 #[derive(Live, LiveRegister, LiveHook)]
@@ -20,7 +77,29 @@ pub struct DrawPopup {
     pub opacity: f32,
     #[live(0.4)]
     pub proportion: f32,
-    /// The angle offset of the popup, usually used in tooltips to get the angle center when painting
+    /// The angle offset of the popup, usually used in tooltips to get the angle center when painting.
+    /// It is the distance from the start of the side facing the trigger to the trigger center
     #[live]
     pub angle_offset: f32,
+    /// size of the arrow drawn by `arrow_pixel`, `0.0` means no arrow
+    #[live]
+    pub arrow_size: f32,
+    /// the side facing the trigger, see `Position::arrow_side`
+    #[live(1.0)]
+    pub arrow_side: f32,
+}
+
+impl DrawPopup {
+    /// add `arrow_size` to the padding on the side facing the trigger, so the content does not
+    /// cover the arrow
+    pub fn arrow_padding(&self, mut padding: Padding) -> Padding {
+        let size = self.arrow_size as f64;
+        match self.arrow_side as u8 {
+            1 => padding.top += size,
+            2 => padding.right += size,
+            3 => padding.bottom += size,
+            _ => padding.left += size,
+        }
+        padding
+    }
 }
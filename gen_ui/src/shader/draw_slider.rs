@@ -29,6 +29,43 @@ live_design! {
             // - [draw progress bar] --------------------------------------------------------------
             match self.mode {
                 ProgressMode::Horizontal => {
+                    // - [range mode: track, selected part from start to value, two draggers] --------
+                    if self.range > 0.5 {
+                        let track_height = self.sdf_rect_size.y * self.proportion;
+                        let track_y = self.sdf_rect_pos.y + (self.sdf_rect_size.y - track_height) * 0.5;
+                        let border_radius = self.count_border_radius(min(self.sdf_rect_size.x, track_height) * 0.25);
+                        sdf.box(self.sdf_rect_pos.x, track_y, self.sdf_rect_size.x, track_height, border_radius.x);
+                        if self.border_width != 0.0 {
+                            sdf.stroke_keep(self.get_border_color(), self.border_width);
+                        }
+                        if self.background_visible == 1.0 {
+                            sdf.fill_premul(self.get_background_color());
+                        }
+                        let start_x = self.sdf_rect_pos.x + self.sdf_rect_size.x * self.start;
+                        let end_x = self.sdf_rect_pos.x + self.sdf_rect_size.x * self.value;
+                        if end_x > start_x {
+                            sdf.box(start_x, track_y, end_x - start_x, track_height, 1.0);
+                            sdf.fill(self.get_color());
+                        }
+                        let dragger_width = mix(3.6, 2.4, self.dragging);
+                        sdf.box(
+                            clamp(start_x - dragger_width * 0.5, self.sdf_rect_pos.x, self.sdf_rect_pos.x + self.sdf_rect_size.x - dragger_width),
+                            self.sdf_rect_pos.y,
+                            dragger_width,
+                            self.sdf_rect_size.y,
+                            dragger_width * 0.25
+                        );
+                        sdf.fill(self.get_color());
+                        sdf.box(
+                            clamp(end_x - dragger_width * 0.5, self.sdf_rect_pos.x, self.sdf_rect_pos.x + self.sdf_rect_size.x - dragger_width),
+                            self.sdf_rect_pos.y,
+                            dragger_width,
+                            self.sdf_rect_size.y,
+                            dragger_width * 0.25
+                        );
+                        sdf.fill(self.get_color());
+                        return sdf.result;
+                    }
                     // - [draw shadow and blur] -----------------------------------------------------------
                     if sdf.shape > -1.0 {
                         if self.spread_radius > 0.0 || self.blur_radius > 0.0 {
@@ -134,6 +171,44 @@ live_design! {
                     sdf.fill(self.get_color());
                 }
                 ProgressMode::Vertical => {
+                    // - [range mode: track, selected part from start to value, two draggers] --------
+                    if self.range > 0.5 {
+                        let track_width = self.sdf_rect_size.x * self.proportion;
+                        let track_x = self.sdf_rect_pos.x + (self.sdf_rect_size.x - track_width) * 0.5;
+                        let border_radius = self.count_border_radius(min(track_width, self.sdf_rect_size.y) * 0.25);
+                        sdf.box(track_x, self.sdf_rect_pos.y, track_width, self.sdf_rect_size.y, border_radius.x);
+                        if self.border_width != 0.0 {
+                            sdf.stroke_keep(self.get_border_color(), self.border_width);
+                        }
+                        if self.background_visible == 1.0 {
+                            sdf.fill_premul(self.get_background_color());
+                        }
+                        // y increases downward, progress increases upward
+                        let start_y = self.sdf_rect_pos.y + self.sdf_rect_size.y * (1.0 - self.start);
+                        let end_y = self.sdf_rect_pos.y + self.sdf_rect_size.y * (1.0 - self.value);
+                        if start_y > end_y {
+                            sdf.box(track_x, end_y, track_width, start_y - end_y, 1.0);
+                            sdf.fill(self.get_color());
+                        }
+                        let dragger_height = mix(4.08, 2.72, self.dragging);
+                        sdf.box(
+                            self.sdf_rect_pos.x,
+                            clamp(start_y - dragger_height * 0.5, self.sdf_rect_pos.y, self.sdf_rect_pos.y + self.sdf_rect_size.y - dragger_height),
+                            self.sdf_rect_size.x,
+                            dragger_height,
+                            dragger_height * 0.25
+                        );
+                        sdf.fill(self.get_color());
+                        sdf.box(
+                            self.sdf_rect_pos.x,
+                            clamp(end_y - dragger_height * 0.5, self.sdf_rect_pos.y, self.sdf_rect_pos.y + self.sdf_rect_size.y - dragger_height),
+                            self.sdf_rect_size.x,
+                            dragger_height,
+                            dragger_height * 0.25
+                        );
+                        sdf.fill(self.get_color());
+                        return sdf.result;
+                    }
                     // - [draw shadow and blur] -----------------------------------------------------------
                     if sdf.shape > -1.0 {
                         if self.spread_radius > 0.0 || self.blur_radius > 0.0 {
@@ -360,6 +435,12 @@ pub struct DrawSlider {
     pub dragging: f32,
     #[live(0.1)]
     pub step: f32,
+    /// range mode (`1.0`), draw two draggers at `start` and `value`
+    #[live]
+    pub range: f32,
+    /// 归一化的范围起点，范围0.0到1.0 (only range mode)
+    #[live]
+    pub start: f32,
 }
 
 impl DrawSlider {
@@ -14,6 +14,7 @@ pub mod draw_view;
 pub mod draw_rate;
 pub mod draw_dot;
 pub mod draw_input;
+pub mod draw_contribution;
 
 use makepad_widgets::Cx;
 
@@ -33,5 +34,6 @@ pub fn shader_register(cx: &mut Cx) {
     draw_rate::live_design(cx);
     draw_dot::live_design(cx);
     draw_input::live_design(cx);
+    draw_contribution::live_design(cx);
     animation::live_design(cx);
 }
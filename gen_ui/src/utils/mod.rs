@@ -73,6 +73,26 @@ pub fn round_step(v: f32, step: f32) -> f32 {
     }
 }
 
+/// find the first case-insensitive match of `keyword` in `text`, return the byte range in `text`
+pub fn find_ignore_case(text: &str, keyword: &str) -> Option<std::ops::Range<usize>> {
+    if keyword.is_empty() {
+        return None;
+    }
+    text.char_indices().find_map(|(start, _)| {
+        let mut rest = text[start..].char_indices();
+        let mut end = start;
+        for k in keyword.chars() {
+            match rest.next() {
+                Some((i, c)) if c.to_lowercase().eq(k.to_lowercase()) => {
+                    end = start + i + c.len_utf8();
+                }
+                _ => return None,
+            }
+        }
+        Some(start..end)
+    })
+}
+
 #[cfg(test)]
 mod test{
     #[test]
@@ -89,4 +109,12 @@ mod test{
         assert_eq!(r3, 2.5);
 
     }
+
+    #[test]
+    fn find_ignore_case_test() {
+        assert_eq!(super::find_ignore_case("Cargo.toml", "TOML"), Some(6..10));
+        assert_eq!(super::find_ignore_case("树节点 Node", "node"), Some(10..14));
+        assert_eq!(super::find_ignore_case("src", "lib"), None);
+        assert_eq!(super::find_ignore_case("src", ""), None);
+    }
 }
\ No newline at end of file
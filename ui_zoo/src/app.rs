@@ -48,6 +48,8 @@ live_design! {
                     padding: {left: 0., right: 0., top: 0., bottom: 0.}
                     // padding: {left: 10., right: 10., top: 0., bottom: 0.}
                     body = <HomePage> {}
+                    // dialogs opened by `CxDialogExt` are drawn over the whole window
+                    <GDialogManager> {}
 
                     // <GColorPanel>{
                     //     color: #348b8f
@@ -96,6 +96,74 @@ live_design! {
                         text: "url online"
                     }
                 }
+                <GHLayout> {
+                    style: {
+                    basic: {
+                            height: Fit,
+                            width: Fill,
+                            flow: Down,
+                        }
+                    }
+                    <GImage> {
+                        src: Url("https://example.com/not_found.png"),
+                        placeholder: Live(dep("crate://self/resources/install.png")),
+                        error: Live(dep("crate://self/resources/install.png")),
+                        retry: 1,
+                        timeout: 5.0,
+                        style: {
+                            basic: {
+                                height: 80.0,
+                                width: 160.0,
+                            }
+                        }
+                    }
+                    <GLabel>{
+                        text: "url failed (retry once, then fallback to error image)"
+                    }
+                }
+                <GHLayout> {
+                    style: {
+                    basic: {
+                            height: Fit,
+                            width: Fill,
+                            flow: Down,
+                        }
+                    }
+                    <GImage> {
+                        src: Live(dep("crate://self/resources/star.svg")),
+                        style: {
+                            basic: {
+                                height: 48.0,
+                                width: 48.0,
+                            }
+                        }
+                    }
+                    <GLabel>{
+                        text: "svg (delegate to GSvg)"
+                    }
+                }
+                <GHLayout> {
+                    style: {
+                    basic: {
+                            height: Fit,
+                            width: Fill,
+                            flow: Down,
+                        }
+                    }
+                    <GImage> {
+                        preview: true,
+                        src: Live(dep("crate://self/resources/cat.jpg")),
+                        style: {
+                            basic: {
+                                height: 120.0,
+                                width: 160.0,
+                            }
+                        }
+                    }
+                    <GLabel>{
+                        text: "click to preview (wheel/pinch zoom, drag, rotate)"
+                    }
+                }
             }
             desc = {
                 text: ""
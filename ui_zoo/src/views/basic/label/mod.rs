@@ -73,7 +73,35 @@ live_design! {
                 text: ""
             }
         }
-
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 12.0,
+                    }
+                }
+                rich_label = <GLabel>{
+                    rich: true,
+                    text: "Rich text: **bold**, *italic*, ~~strike~~, `inline code`, {#FF5500|colored} and a [clickable link](https://github.com/genrs/GenM3)"
+                }
+                spans_label = <GLabel>{}
+                <GLabel>{
+                    style: {
+                        basic: {
+                            width: 240.0,
+                        }
+                    },
+                    max_lines: 2,
+                    text: "A long text is wrapped by the width of the label and truncated with an ellipsis when it is longer than max_lines lines"
+                }
+            }
+            desc = {
+                text: "`rich: true` parses the text as markup, `set_spans` builds the spans by `RichSpan`, clicking a span emits `LabelEvent::SpanClicked`"
+            }
+        }
     }
 }
 
@@ -87,6 +115,17 @@ impl LiveHook for LabelPage {
     inherits_view_livehook!();
     fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
         self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.glabel(id!(spans_label)).set_spans(
+            cx,
+            vec![
+                RichSpan::new("Spans from the builder: "),
+                RichSpan::new("bold ").bold(),
+                RichSpan::new("red ").color(vec4(1.0, 0.0, 0.0, 1.0)),
+                RichSpan::new("cargo run").code(),
+                RichSpan::new(" "),
+                RichSpan::new("route").link("route:/button_page"),
+            ],
+        );
     }
 }
 
@@ -103,7 +142,13 @@ impl Widget for LabelPage {
 }
 
 impl MatchEvent for LabelPage {
-    fn handle_actions(&mut self, _cx: &mut Cx, _actions: &Actions) {}
+    fn handle_actions(&mut self, _cx: &mut Cx, actions: &Actions) {
+        for label in [id!(rich_label), id!(spans_label)] {
+            if let Some(e) = self.glabel(label).span_clicked(actions) {
+                log!("span clicked: {} {} {:?}", e.index, e.text, e.href);
+            }
+        }
+    }
 }
 
 widget_node!(LabelPage);
@@ -72,7 +72,39 @@ live_design! {
                 text: ""
             }
         }
-
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                <GLink>{
+                    text: "Route: go to the button page",
+                    href: "route:/button_page",
+                }
+                <GLink>{
+                    style: {
+                        visited: {
+                            theme: Success,
+                        }
+                    },
+                    text: "External: open GitHub in a new tab",
+                    href: "https://github.com/genrs/GenM3",
+                    target: Blank,
+                }
+                <GLink>{
+                    text: "Mail: mailto link",
+                    href: "mailto:someone@example.com",
+                }
+            }
+            desc = {
+                text: "`route:/...` navigates the nearest GRouter, http:, https: and mailto: urls are opened by the LinkOpener (replace it by `set_link_opener`), other schemes are refused. A followed link uses the `visited` style."
+            }
+        }
     }
 }
 
@@ -0,0 +1,152 @@
+use gen_ui::{components::*, inherits_view_livehook, prop::BubbleRole};
+use makepad_widgets::*;
+
+use crate::widget_node;
+
+live_design! {
+    use link::widgets::*;
+    use link::genui::*;
+    use crate::views::cbox::*;
+
+    pub ChatPage = {{ChatPage}} {
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 16.0,
+                    }
+                }
+                <GWelcome> {
+                    title: "Hello, I'm GenUI",
+                    description: "Ask me anything about the components, or pick a prompt below",
+                }
+                prompts = <GPrompts> {
+                    prompts: ["What is GenUI?", "How to stream a message?", "Show me some markdown"]
+                }
+            }
+            desc = {
+                text: "GWelcome and GPrompts, click a prompt to send it"
+            }
+        }
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 16.0,
+                    }
+                }
+                messages = <GBubbleList> {
+                    style: {basic: {height: 360.0}}
+                }
+                sender = <GSender> {}
+            }
+            desc = {
+                text: "GBubbleList and GSender, the answer is streamed token by token into the bubble, `+` adds an attachment"
+            }
+        }
+    }
+}
+
+const ANSWER: &str = "**GenUI** streams the answer into the bubble:\n\n- `push_typing` shows the loading\n- `append` adds the tokens\n\n```rust\nlist.append(cx, index, token);\n```";
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct ChatPage {
+    #[deref]
+    pub deref_widget: GView,
+    #[rust]
+    timer: Timer,
+    /// index of the streaming answer and the bytes of `ANSWER` appended
+    #[rust]
+    answer: Option<(usize, usize)>,
+}
+
+impl LiveHook for ChatPage {
+    inherits_view_livehook!();
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+    }
+}
+
+impl Widget for ChatPage {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        self.match_event(cx, event);
+        self.deref_widget.handle_event(cx, event, scope)
+    }
+}
+
+impl MatchEvent for ChatPage {
+    fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        if let Some(e) = self.gprompts(id!(prompts)).clicked(actions) {
+            self.ask(cx, e.prompt);
+        }
+        let sender = self.gsender(id!(sender));
+        if let Some(e) = sender.send(actions) {
+            let mut text = e.text;
+            for attachment in e.attachments {
+                text.push_str(&format!("\n\n> {}", attachment));
+            }
+            self.ask(cx, text);
+        }
+        if sender.stop(actions).is_some() {
+            self.finish(cx);
+        }
+        if sender.attach(actions).is_some() {
+            let count = sender.attachments().len();
+            sender.add_attachment(cx, format!("file_{}.txt", count + 1));
+        }
+    }
+
+    fn handle_timer(&mut self, cx: &mut Cx, _e: &TimerEvent) {
+        if let Some((index, sent)) = self.answer {
+            // a token is a word with its following whitespace
+            let rest = &ANSWER[sent..];
+            let len = rest
+                .char_indices()
+                .skip_while(|(_, c)| c.is_whitespace())
+                .find(|(_, c)| c.is_whitespace())
+                .map_or(rest.len(), |(i, _)| i);
+            self.gbubble_list(id!(messages))
+                .append(cx, index, &rest[..len]);
+            if sent + len >= ANSWER.len() {
+                self.finish(cx);
+            } else {
+                self.answer = Some((index, sent + len));
+            }
+        }
+    }
+}
+
+impl ChatPage {
+    fn ask(&mut self, cx: &mut Cx, text: String) -> () {
+        if self.answer.is_some() {
+            return;
+        }
+        let messages = self.gbubble_list(id!(messages));
+        messages.push(cx, BubbleRole::User, text);
+        let index = messages.push_typing(cx, BubbleRole::Assistant);
+        self.answer = Some((index, 0));
+        self.gsender(id!(sender)).set_loading(cx, true);
+        self.timer = cx.start_interval(0.1);
+    }
+    fn finish(&mut self, cx: &mut Cx) -> () {
+        cx.stop_timer(self.timer);
+        if let Some((index, _)) = self.answer.take() {
+            self.gbubble_list(id!(messages))
+                .set_typing(cx, index, false);
+        }
+        self.gsender(id!(sender)).set_loading(cx, false);
+    }
+}
+
+widget_node!(ChatPage);
@@ -0,0 +1,111 @@
+use gen_ui::{components::*, inherits_view_livehook};
+use makepad_widgets::*;
+
+use crate::widget_node;
+
+live_design! {
+    use link::widgets::*;
+    use link::genui::*;
+    use crate::views::cbox::*;
+
+    pub CodePage = {{CodePage}} {
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                rust_code = <GCode> {
+                    lang: "rust",
+                    code: "use gen_ui::components::*;\n\n/// a counter\nfn count(items: &[&str]) -> usize {\n    let mut n = 0;\n    for item in items {\n        if !item.is_empty() { n += 1; } // skip empty\n    }\n    println!(\"count: {}\", n);\n    n\n}"
+                }
+                <GCode> {
+                    lang: "toml",
+                    code: "[package]\nname = \"ui_zoo\"\nversion = \"0.1.0\"\n\n[dependencies]\ngen_ui = { path = \"../gen_ui\" }"
+                }
+                <GCode> {
+                    lang: "json",
+                    code: "{\n  \"name\": \"GenUI\",\n  \"stars\": 128,\n  \"dark\": true\n}"
+                }
+                <GCode> {
+                    lang: "ts",
+                    line_numbers: false,
+                    code: "interface Props { name: string }\nexport const hello = (props: Props) => `Hello ${props.name}`;"
+                }
+                <GCode> {
+                    lang: "python",
+                    code: "def fib(n):\n    \"\"\"fibonacci\"\"\"\n    return n if n < 2 else fib(n - 1) + fib(n - 2)"
+                }
+                <GCode> {
+                    lang: "bash",
+                    code: "# run the zoo\ncd ui_zoo && cargo run --release\necho $HOME"
+                }
+                copied = <GLabel> {text: "Copied: -"}
+            }
+            desc = {
+                text: "Syntax highlighting of rust, toml, json, js/ts, python and shell, token colors use the theme palette at `token_level`"
+            }
+        }
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                <GCode> {
+                    lang: "js",
+                    fold_lines: 4,
+                    code: "const a = 1;\nconst b = 2;\nconst c = 3;\nconst d = 4;\nconst e = 5;\nconst f = 6;\nconsole.log(a + b + c + d + e + f);"
+                }
+            }
+            desc = {
+                text: "The lines after `fold_lines` are folded into a `GCollapse`"
+            }
+        }
+    }
+}
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct CodePage {
+    #[deref]
+    pub deref_widget: GView,
+}
+
+impl LiveHook for CodePage {
+    inherits_view_livehook!();
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+    }
+}
+
+impl Widget for CodePage {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        self.match_event(cx, event);
+        self.deref_widget.handle_event(cx, event, scope)
+    }
+}
+
+impl MatchEvent for CodePage {
+    fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        if let Some(e) = self.gcode(id!(rust_code)).copied(actions) {
+            let _ = self
+                .glabel(id!(copied))
+                .set_text(cx, format!("Copied: {} chars", e.code.len()));
+        }
+    }
+}
+
+widget_node!(CodePage);
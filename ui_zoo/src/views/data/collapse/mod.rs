@@ -79,6 +79,52 @@ live_design! {
                 text: ""
             }
         }
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                accordion = <GCollapseGroup> {
+                    accordion: true,
+                    active: ["basic"],
+                    <GCollapse> {
+                        value: "basic",
+                        header: <GView> {<GLabel> {text: "Basic"}}
+                        body: <GView> {<GLabel> {text: "Only one collapse is opened in accordion mode"}}
+                    }
+                    <GCollapse> {
+                        value: "advanced",
+                        header: <GView> {<GLabel> {text: "Advanced"}}
+                        body: <GView> {<GLabel> {text: "Opening this one closes the others"}}
+                    }
+                    <GCollapse> {
+                        value: "about",
+                        header: <GView> {<GLabel> {text: "About"}}
+                        body: <GView> {<GLabel> {text: "GCollapseGroup"}}
+                    }
+                }
+                <GCollapseGroup> {
+                    max_open: 2,
+                    <GCollapse> {
+                        header: <GView> {<GLabel> {text: "Max open: 2 (1)"}}
+                    }
+                    <GCollapse> {
+                        header: <GView> {<GLabel> {text: "Max open: 2 (2)"}}
+                    }
+                    <GCollapse> {
+                        header: <GView> {<GLabel> {text: "Max open: 2 (3)"}}
+                    }
+                }
+            }
+            desc = {
+                text: "GCollapseGroup: `accordion` keeps only one collapse opened, `max_open` limits the opened count, `active` binds the opened values"
+            }
+        }
     }
 }
 
@@ -108,7 +154,12 @@ impl Widget for CollapsePage {
 }
 
 impl MatchEvent for CollapsePage {
-    fn handle_actions(&mut self, _cx: &mut Cx, _actions: &Actions) {
+    fn handle_actions(&mut self, _cx: &mut Cx, actions: &Actions) {
+        for action in actions {
+            if let CollapseGroupEvent::Changed(e) = action.as_widget_action().cast() {
+                log!("collapse group changed: {:?}", e.value);
+            }
+        }
     }
 }
 
@@ -0,0 +1,73 @@
+use gen_ui::{components::*, inherits_view_livehook};
+use makepad_widgets::*;
+
+use crate::widget_node;
+
+live_design! {
+    use link::widgets::*;
+    use link::genui::*;
+    use crate::views::cbox::*;
+
+    pub ContributionPage = {{ContributionPage}} {
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                year = <GContribution> {
+                    end: "2025-12-31",
+                }
+                recent = <GContribution> {
+                    theme: Primary,
+                    end: "2025-12-31",
+                    weeks: 20,
+                    cell_size: 16.0,
+                    legend: false,
+                }
+            }
+            desc = {
+                text: "GContribution colors the cells by the quartiles of the counts, hover a cell to see its count"
+            }
+        }
+    }
+}
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct ContributionPage {
+    #[deref]
+    pub deref_widget: GView,
+}
+
+impl LiveHook for ContributionPage {
+    inherits_view_livehook!();
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        // fake data of 2025, some days have no contribution
+        let data = (0..365_u32)
+            .filter_map(|day| {
+                let count = (day * 37 + day / 7 * 11) % 17;
+                (count > 4).then(|| (format_date(20089 + day as i64), count - 4))
+            })
+            .collect::<Vec<_>>();
+        self.gcontribution(id!(year)).set_data(cx, data.clone());
+        self.gcontribution(id!(recent)).set_data(cx, data);
+    }
+}
+
+impl Widget for ContributionPage {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        self.deref_widget.handle_event(cx, event, scope)
+    }
+}
+
+widget_node!(ContributionPage);
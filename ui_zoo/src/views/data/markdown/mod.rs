@@ -0,0 +1,102 @@
+use gen_ui::{components::*, inherits_view_livehook};
+use makepad_widgets::*;
+
+use crate::widget_node;
+
+/// chunks of a streaming response, appended one by one
+const STREAM_CHUNKS: [&str; 6] = [
+    "## Streaming\n\nThe text is ",
+    "appended **chunk by chunk**, ",
+    "only the changed blocks are updated.\n\n",
+    "- first\n- sec",
+    "ond\n\n```rust\nfn main() {\n",
+    "    println!(\"GenUI\");\n}\n```\n",
+];
+
+live_design! {
+    use link::widgets::*;
+    use link::genui::*;
+    use crate::views::cbox::*;
+
+    pub MarkdownPage = {{MarkdownPage}} {
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                <GMarkdown> {
+                    open_links: true,
+                    text: "# GMarkdown\n\nRender **CommonMark** by *GenUI* widgets, ~~no webview~~, see [GenUI](https://github.com/genrs/GenM3) or [the link page](route:/link_page).\n\n## List\n\n1. headings\n2. lists with `inline code`\n3. quotes, code blocks and tables\n\n- [x] task list\n- [ ] images\n\n> Every block is a GenUI widget, styled by the theme.\n\n```rust\nlet md = self.gmarkdown(id!(md));\nmd.append(cx, \"more\");\n```\n\n| Widget | Block |\n|---|---|\n| GLabel | paragraph |\n| GView | list |\n\n---\n"
+                }
+            }
+            desc = {
+                text: "Links are `GLink`s, clicking one emits `link_clicked`, set `open_links: true` to follow them as `GLink` does"
+            }
+        }
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                append_btn = <GButton> {slot: {text: "Append Chunk"}}
+                stream_md = <GMarkdown> {}
+            }
+            desc = {
+                text: "Use `append` for streaming responses"
+            }
+        }
+    }
+}
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct MarkdownPage {
+    #[deref]
+    pub deref_widget: GView,
+    #[rust]
+    chunk: usize,
+}
+
+impl LiveHook for MarkdownPage {
+    inherits_view_livehook!();
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+    }
+}
+
+impl Widget for MarkdownPage {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        self.match_event(cx, event);
+        self.deref_widget.handle_event(cx, event, scope)
+    }
+}
+
+impl MatchEvent for MarkdownPage {
+    fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        if let Some(_) = self.gbutton(id!(append_btn)).clicked(actions) {
+            let md = self.gmarkdown(id!(stream_md));
+            if self.chunk == STREAM_CHUNKS.len() {
+                self.chunk = 0;
+                md.clear(cx);
+            }
+            md.append(cx, STREAM_CHUNKS[self.chunk]);
+            self.chunk += 1;
+        }
+    }
+}
+
+widget_node!(MarkdownPage);
@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use gen_ui::{components::*, inherits_view_livehook};
+use makepad_widgets::*;
+
+use crate::widget_node;
+
+live_design! {
+    use link::widgets::*;
+    use link::genui::*;
+    use crate::views::cbox::*;
+
+    pub MediaPage = {{MediaPage}} {
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                audio = <GAudio> {}
+                video = <GVideo> {
+                    looping: true,
+                }
+            }
+            desc = {
+                text: "GAudio and GVideo decode by pluggable decoders, the demo generates a tone and a moving gradient"
+            }
+        }
+    }
+}
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct MediaPage {
+    #[deref]
+    pub deref_widget: GView,
+}
+
+impl LiveHook for MediaPage {
+    inherits_view_livehook!();
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        self.gaudio(id!(audio))
+            .set_decoder(cx, |_: &Path| -> Result<AudioData, String> { Ok(tone()) });
+        self.gvideo(id!(video)).set_decoder(
+            cx,
+            |_: &Path| -> Result<Box<dyn VideoStream>, String> {
+                Ok(Box::new(Gradient { index: 0 }))
+            },
+        );
+        // the decoders are set, the src is only a name for them
+        self.gaudio(id!(audio)).set_src(cx, "tone");
+        self.gvideo(id!(video)).set_src(cx, "gradient");
+    }
+}
+
+impl Widget for MediaPage {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        self.deref_widget.handle_event(cx, event, scope)
+    }
+}
+
+/// 4 seconds of a 440Hz tone fading out
+fn tone() -> AudioData {
+    let sample_rate = 44100;
+    let samples = (0..sample_rate * 4)
+        .map(|i| {
+            let t = i as f32 / sample_rate as f32;
+            (t * 440.0 * std::f32::consts::TAU).sin() * 0.3 * (1.0 - t / 4.0)
+        })
+        .collect();
+    AudioData {
+        sample_rate,
+        channels: 1,
+        samples,
+    }
+}
+
+const WIDTH: usize = 320;
+const HEIGHT: usize = 180;
+const FPS: f64 = 25.0;
+const FRAMES: usize = 250;
+
+/// a gradient moving from left to right
+struct Gradient {
+    index: usize,
+}
+
+impl VideoStream for Gradient {
+    fn info(&self) -> VideoInfo {
+        VideoInfo {
+            width: WIDTH,
+            height: HEIGHT,
+            duration: FRAMES as f64 / FPS,
+        }
+    }
+    fn next_frame(&mut self) -> Result<Option<VideoFrame>, String> {
+        if self.index >= FRAMES {
+            return Ok(None);
+        }
+        let offset = self.index * WIDTH / FRAMES;
+        let data = (0..WIDTH * HEIGHT)
+            .map(|i| {
+                let x = ((i % WIDTH + offset) % WIDTH * 255 / WIDTH) as u32;
+                let y = ((i / WIDTH) * 255 / HEIGHT) as u32;
+                0xFF000000 | x << 16 | y << 8 | 0x80
+            })
+            .collect();
+        let frame = VideoFrame {
+            time: self.index as f64 / FPS,
+            width: WIDTH,
+            height: HEIGHT,
+            data,
+        };
+        self.index += 1;
+        Ok(Some(frame))
+    }
+    fn seek(&mut self, time: f64) -> Result<(), String> {
+        self.index = ((time * FPS) as usize).min(FRAMES);
+        Ok(())
+    }
+}
+
+widget_node!(MediaPage);
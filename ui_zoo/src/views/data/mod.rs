@@ -10,6 +10,13 @@ pub mod badge;
 pub mod pagination;
 pub mod tree;
 pub mod number_input;
+pub mod markdown;
+pub mod code;
+pub mod chat;
+pub mod timeline;
+pub mod contribution;
+pub mod upload;
+pub mod media;
 
 pub fn register(cx: &mut Cx) {
     tag::live_design(cx);
@@ -22,4 +29,11 @@ pub fn register(cx: &mut Cx) {
     pagination::live_design(cx);
     tree::live_design(cx);
     number_input::live_design(cx);
+    markdown::live_design(cx);
+    code::live_design(cx);
+    chat::live_design(cx);
+    timeline::live_design(cx);
+    contribution::live_design(cx);
+    upload::live_design(cx);
+    media::live_design(cx);
 }
\ No newline at end of file
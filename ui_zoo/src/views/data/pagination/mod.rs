@@ -36,21 +36,32 @@ live_design! {
                     current: 2,
                     total: 4,
                 }
-                <GPagination>{
-                    current: 5,
-                    total: 5,
-                }
                 <GPagination>{
                     current: 5,
                     total: 7,
                 }
                 <GPagination>{
                     current: 6,
-                    total: 10,
+                    total: 20,
+                    siblings: 1,
+                    boundaries: 2,
+                }
+                <GPagination>{
+                    current: 3,
+                    total_items: 500,
+                    page_size: 10,
+                    total_text: {visible: true},
+                    size: {visible: true},
+                    jumper: {visible: true},
+                }
+                <GPagination>{
+                    current: 3,
+                    total_items: 500,
+                    simple: true,
                 }
             }
             desc = {
-                text: ""
+                text: "`total` is the count of pages, use `total_items` with `page_size` to count the pages by items"
             }
         }
     }
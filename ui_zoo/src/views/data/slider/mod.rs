@@ -23,7 +23,7 @@ live_design! {
                 <GVLayout> {
                     style: {
                         basic: {
-                            height: 248.0,
+                            height: 360.0,
                             width: Fill,
                             spacing: 12.0,
                         }
@@ -75,6 +75,26 @@ live_design! {
                         min: 0.0,
                         max: 100.0,
                     }  
+                    <GSlider>{
+                        style: {basic: {margin: {top: 32.0, bottom: 24.0}}},
+                        range: true,
+                        start: 20.0,
+                        value: 80.0,
+                        min: 0.0,
+                        max: 100.0,
+                        step: 5.0,
+                        grab_key_focus: true,
+                        marks: [0.0, 25.0, 50.0, 75.0, 100.0],
+                        mark_labels: ["0°C", "25°C", "50°C", "75°C", "100°C"],
+                    }
+                    <GSlider>{
+                        style: {basic: {margin: {bottom: 24.0}}},
+                        value: 50.0,
+                        min: 0.0,
+                        max: 100.0,
+                        marks: [0.0, 20.0, 50.0, 100.0],
+                        snap_to_marks: true,
+                    }
                     
                 }
                 <GHLayout> {
@@ -96,6 +116,19 @@ live_design! {
                         proportion: 0.9,
                         mode: Vertical,
                     }
+                    <GSlider>{
+                        style: {
+                            basic: {theme: Primary, height: Fill, width: 16.0, margin: {right: 48.0}}
+                        },
+                        range: true,
+                        start: 20.0,
+                        value: 60.0,
+                        min: 0.0,
+                        max: 100.0,
+                        step: 1.0,
+                        mode: Vertical,
+                        marks: [0.0, 50.0, 100.0],
+                    }
                     <GSlider> {
                         style: {
                             basic: {theme: Primary, height: 164.0, width: 164.0}
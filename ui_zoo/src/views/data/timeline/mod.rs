@@ -0,0 +1,160 @@
+use gen_ui::{components::*, inherits_view_livehook};
+use makepad_widgets::*;
+
+use crate::widget_node;
+
+live_design! {
+    use link::widgets::*;
+    use link::genui::*;
+    use crate::views::cbox::*;
+
+    pub TimelinePage = {{TimelinePage}} {
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                <GTimeline> {
+                    <GTimelineItem> {
+                        time: "2025-01-01",
+                        theme: Success,
+                        content = {
+                            <GLabel> {text: "Create the project"}
+                        }
+                    }
+                    <GTimelineItem> {
+                        time: "2025-03-12",
+                        theme: Primary,
+                        content = {
+                            <GLabel> {text: "Release v0.1.0"}
+                            <GLabel> {text: "The first version with the basic components", style: {basic: {color: #ABABAB}}}
+                        }
+                    }
+                    <GTimelineItem> {
+                        time: "2025-05-20",
+                        theme: Error,
+                        content = {
+                            <GLabel> {text: "Fix the crash of the router"}
+                        }
+                    }
+                    <GTimelineItem> {
+                        pending: true,
+                        content = {
+                            <GLabel> {text: "Recording..."}
+                        }
+                    }
+                }
+                <GTimeline> {
+                    direction: Horizontal,
+                    <GTimelineItem> {
+                        time: "Step 1",
+                        content = {<GLabel> {text: "Design"}}
+                    }
+                    <GTimelineItem> {
+                        time: "Step 2",
+                        theme: Primary,
+                        content = {<GLabel> {text: "Develop"}}
+                    }
+                    <GTimelineItem> {
+                        time: "Step 3",
+                        theme: Warning,
+                        content = {<GLabel> {text: "Test"}}
+                    }
+                }
+            }
+            desc = {
+                text: "Vertical and horizontal GTimeline, each item has its own theme, the pending item shows the loading"
+            }
+        }
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                steps = <GSteps> {
+                    current: 1,
+                    <GStep> {title: "Account", description: "Create the account"}
+                    <GStep> {title: "Verify", description: "Check the email"}
+                    <GStep> {title: "Profile"}
+                    <GStep> {title: "Done"}
+                }
+                <GHLayout> {
+                    style: {basic: {height: Fit, spacing: 12.0}}
+                    prev_btn = <GButton> {slot: {text: "Prev"}}
+                    next_btn = <GButton> {slot: {text: "Next"}}
+                    error_btn = <GButton> {slot: {text: "Error"}}
+                    current = <GLabel> {text: "Click a step"}
+                }
+                <GSteps> {
+                    direction: Vertical,
+                    current: 1,
+                    error: true,
+                    clickable: false,
+                    <GStep> {title: "Upload", description: "The file is uploaded"}
+                    <GStep> {title: "Parse", description: "The format is not supported"}
+                    <GStep> {title: "Import"}
+                }
+            }
+            desc = {
+                text: "GSteps with finished, current, error and waiting steps, click a step to change the current one"
+            }
+        }
+    }
+}
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct TimelinePage {
+    #[deref]
+    pub deref_widget: GView,
+}
+
+impl LiveHook for TimelinePage {
+    inherits_view_livehook!();
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+    }
+}
+
+impl Widget for TimelinePage {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        self.match_event(cx, event);
+        self.deref_widget.handle_event(cx, event, scope)
+    }
+}
+
+impl MatchEvent for TimelinePage {
+    fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        let steps = self.gsteps(id!(steps));
+        if self.gbutton(id!(prev_btn)).clicked(actions).is_some() {
+            steps.prev(cx);
+        }
+        if self.gbutton(id!(next_btn)).clicked(actions).is_some() {
+            steps.next(cx);
+        }
+        if self.gbutton(id!(error_btn)).clicked(actions).is_some() {
+            steps.set_error(cx, true);
+        }
+        if let Some(e) = steps.changed(actions) {
+            let _ = self
+                .glabel(id!(current))
+                .set_text(cx, format!("Changed: {} -> {}", e.last + 1, e.current + 1));
+        }
+    }
+}
+
+widget_node!(TimelinePage);
@@ -86,6 +86,43 @@ live_design! {
                         }
                     }
                 }
+                // build from data, `lazy` branches load children when expanded
+                // type to filter nodes, press enter to scroll to the first match
+                tree_search = <GInputArea> {
+                    placeholder: "Search nodes...",
+                }
+                data_tree = <GTree> {
+                    // drag nodes to reorder or move them into another branch
+                    draggable: true,
+                    // click the tree, then navigate it with the arrow keys
+                    event_key: true,
+                }
+                <GTree> {
+                    checkable: true,
+                    checked: ["fruit_apple"],
+                    body: {
+                        <GBranch> {
+                            value: "fruit",
+                            text: {
+                                text: "fruit"
+                            }
+                            body: {
+                                <GLeaf>{
+                                    value: "fruit_apple",
+                                    text: <GLabel> {
+                                        text: "apple"
+                                    }
+                                }
+                                <GLeaf>{
+                                    value: "fruit_pear",
+                                    text: <GLabel> {
+                                        text: "pear"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
             }
             desc = {
                 text: ""
@@ -120,7 +157,52 @@ impl Widget for TreePage {
 }
 
 impl MatchEvent for TreePage {
-    fn handle_actions(&mut self, _cx: &mut Cx, _actions: &Actions) {}
+    fn handle_startup(&mut self, cx: &mut Cx) {
+        let tree = self.gtree(id!(data_tree));
+        tree.set_loader(|value: &str| {
+            // pretend to be a slow request
+            std::thread::sleep(std::time::Duration::from_millis(800));
+            Ok((0..3)
+                .map(|i| {
+                    let value = format!("{}-{}", value, i);
+                    if i == 2 {
+                        TreeNode::lazy(value.clone(), value)
+                    } else {
+                        TreeNode::leaf(value.clone(), value)
+                    }
+                })
+                .collect())
+        });
+        tree.set_nodes(
+            cx,
+            vec![
+                TreeNode::branch(
+                    "data",
+                    "data",
+                    vec![TreeNode::leaf("data-0", "data-0"), TreeNode::lazy("remote", "remote")],
+                ),
+                TreeNode::lazy("lazy", "lazy"),
+            ],
+        );
+    }
+    fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        let search = self.ginput_area(id!(tree_search));
+        if let Some(e) = search.changed(actions) {
+            let _ = self.gtree(id!(data_tree)).filter(cx, &e.value);
+        }
+        if let Some(e) = search.returned(actions) {
+            let tree = self.gtree(id!(data_tree));
+            if let Some(first) = tree.filter(cx, &e.value).first() {
+                tree.scroll_to(cx, first);
+            }
+        }
+        if let Some(e) = self.gtree(id!(data_tree)).load_failed(actions) {
+            log!("load {} failed: {}", e.value, e.error);
+        }
+        if let Some(e) = self.gtree(id!(data_tree)).dropped(actions) {
+            log!("move {} {:?} {}", e.source, e.position, e.target);
+        }
+    }
 }
 
 widget_node!(TreePage);
@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use gen_ui::{components::*, inherits_view_livehook};
+use makepad_widgets::*;
+
+use crate::widget_node;
+
+live_design! {
+    use link::widgets::*;
+    use link::genui::*;
+    use crate::views::cbox::*;
+
+    pub UploadPage = {{UploadPage}} {
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 20.0,
+                    }
+                }
+                files = <GUpload> {
+                    accept: ".png, .jpg, .svg",
+                    max_size: 1048576,
+                }
+                images = <GUpload> {
+                    accept: "image/*",
+                    mode: Card,
+                    max_count: 4,
+                }
+            }
+            desc = {
+                text: "GUpload validates the files picked or dropped, the uploader here copies them into the temp dir"
+            }
+        }
+    }
+}
+
+#[derive(Live, WidgetRef, WidgetSet, LiveRegisterWidget)]
+pub struct UploadPage {
+    #[deref]
+    pub deref_widget: GView,
+}
+
+impl LiveHook for UploadPage {
+    inherits_view_livehook!();
+    fn after_apply(&mut self, cx: &mut Cx, apply: &mut Apply, index: usize, nodes: &[LiveNode]) {
+        self.deref_widget.after_apply(cx, apply, index, nodes);
+        let dir = std::env::temp_dir().join("gen_ui_upload");
+        for id in [id!(files), id!(images)] {
+            self.gupload(id).set_uploader(LocalUploader::new(&dir));
+        }
+    }
+}
+
+impl Widget for UploadPage {
+    fn draw_walk(&mut self, cx: &mut Cx2d, scope: &mut Scope, walk: Walk) -> DrawStep {
+        let _ = self.deref_widget.draw_walk(cx, scope, walk);
+
+        DrawStep::done()
+    }
+    fn handle_event(&mut self, cx: &mut Cx, event: &Event, scope: &mut Scope) {
+        let actions = cx.capture_actions(|cx| self.deref_widget.handle_event(cx, event, scope));
+        for id in [id!(files), id!(images)] {
+            let upload = self.gupload(id);
+            // no file dialog in the demo, pick the resources of the zoo
+            if upload.pick(&actions).is_some() {
+                upload.add_files(cx, resources());
+            }
+            if let Some(e) = upload.rejected(&actions) {
+                log!("{} is rejected: {}", e.path, e.reason);
+            }
+        }
+    }
+}
+
+fn resources() -> Vec<PathBuf> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("resources");
+    ["cat.jpg", "bg.png", "news.svg"]
+        .iter()
+        .map(|name| dir.join(name))
+        .collect()
+}
+
+widget_node!(UploadPage);
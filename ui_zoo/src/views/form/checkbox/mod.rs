@@ -206,6 +206,70 @@ live_design! {
                 text: ""
             }
         }
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Down,
+                        spacing: 12.0,
+                    }
+                }
+                <GCheckbox>{
+                    indeterminate: true,
+                    extra: {
+                        <GLabel>{
+                            text: "Indeterminate"
+                        }
+                    }
+                }
+                <GCheckboxGroup>{
+                    active: ["apple"],
+                    style: {
+                        basic: {
+                            flow: Down,
+                            align: {x: 0.0}
+                        }
+                    }
+                    <GCheckbox>{
+                        check_all: true,
+                        extra: {
+                            <GLabel>{
+                                text: "Check all"
+                            }
+                        }
+                    }
+                    <GCheckbox>{
+                        value: "apple",
+                        extra: {
+                            <GLabel>{
+                                text: "Apple"
+                            }
+                        }
+                    }
+                    <GCheckbox>{
+                        value: "pear",
+                        extra: {
+                            <GLabel>{
+                                text: "Pear"
+                            }
+                        }
+                    }
+                    <GCheckbox>{
+                        value: "orange",
+                        extra: {
+                            <GLabel>{
+                                text: "Orange"
+                            }
+                        }
+                    }
+                }
+            }
+            desc = {
+                text: "`indeterminate` draws a dash, a `check_all` checkbox checks all others in the group"
+            }
+        }
 
     }
 }
@@ -67,6 +67,28 @@ live_design! {
                     }
                     item: <GSelectItem> {}
                 }
+                <GSelect> {
+                    select_options: {
+                        searchable: true,
+                        <GSelectGroup> {text: {text: "Fruits"}}
+                        <GSelectItem> {value: "apple", text: {text: "Apple"}}
+                        <GSelectItem> {
+                            value: "banana",
+                            text: {text: "Banana"},
+                            description: {visible: true, text: "Out of stock"},
+                            disabled: true
+                        }
+                        <GSelectGroup> {text: {text: "Vegetables"}}
+                        <GSelectItem> {
+                            value: "carrot",
+                            icon: <IconGift> {},
+                            text: {text: "Carrot"},
+                            description: {visible: true, text: "Fresh from the farm"}
+                        }
+                        <GSelectItem> {value: "potato", text: {text: "Potato"}}
+                    }
+                    item: <GSelectItem> {}
+                }
 
             }
             desc = {
@@ -37,6 +37,13 @@ live_design! {
     use crate::views::form::verification::*;
     use crate::views::data::tree::*;
     use crate::views::data::number_input::*;
+    use crate::views::data::markdown::*;
+    use crate::views::data::code::*;
+    use crate::views::data::chat::*;
+    use crate::views::data::timeline::*;
+    use crate::views::data::contribution::*;
+    use crate::views::data::upload::*;
+    use crate::views::data::media::*;
 
     pub HomePage = {{HomePage}} {
         style: {
@@ -434,6 +441,97 @@ live_design! {
                             },
                             value: "tab_number_input"
                         }
+                        <GMenuItem> {
+                            style: {
+                                basic: {
+                                    container: {
+                                        theme: Primary,
+                                    }
+                                }
+                            }
+                            text: {
+                                text: "Markdown"
+                            },
+                            value: "tab_markdown"
+                        }
+                        <GMenuItem> {
+                            style: {
+                                basic: {
+                                    container: {
+                                        theme: Primary,
+                                    }
+                                }
+                            }
+                            text: {
+                                text: "Code"
+                            },
+                            value: "tab_code"
+                        }
+                        <GMenuItem> {
+                            style: {
+                                basic: {
+                                    container: {
+                                        theme: Primary,
+                                    }
+                                }
+                            }
+                            text: {
+                                text: "Chat"
+                            },
+                            value: "tab_chat"
+                        }
+                        <GMenuItem> {
+                            style: {
+                                basic: {
+                                    container: {
+                                        theme: Primary,
+                                    }
+                                }
+                            }
+                            text: {
+                                text: "Timeline"
+                            },
+                            value: "tab_timeline"
+                        }
+                        <GMenuItem> {
+                            style: {
+                                basic: {
+                                    container: {
+                                        theme: Primary,
+                                    }
+                                }
+                            }
+                            text: {
+                                text: "Contribution"
+                            },
+                            value: "tab_contribution"
+                        }
+                        <GMenuItem> {
+                            style: {
+                                basic: {
+                                    container: {
+                                        theme: Primary,
+                                    }
+                                }
+                            }
+                            text: {
+                                text: "Upload"
+                            },
+                            value: "tab_upload"
+                        }
+                        <GMenuItem> {
+                            style: {
+                                basic: {
+                                    container: {
+                                        theme: Primary,
+                                    }
+                                }
+                            }
+                            text: {
+                                text: "Media"
+                            },
+                            value: "tab_media"
+                        }
                     }
                 }
                 <GSubMenu> {
@@ -647,6 +745,27 @@ live_design! {
                     number_input_page = <GBarPage> {
                         <NumberInputPage>{}
                     }
+                    markdown_page = <GBarPage> {
+                        <MarkdownPage>{}
+                    }
+                    code_page = <GBarPage> {
+                        <CodePage>{}
+                    }
+                    chat_page = <GBarPage> {
+                        <ChatPage>{}
+                    }
+                    timeline_page = <GBarPage> {
+                        <TimelinePage>{}
+                    }
+                    contribution_page = <GBarPage> {
+                        <ContributionPage>{}
+                    }
+                    upload_page = <GBarPage> {
+                        <UploadPage>{}
+                    }
+                    media_page = <GBarPage> {
+                        <MediaPage>{}
+                    }
                 }
             }
         }
@@ -735,6 +854,13 @@ impl Widget for HomePage {
                             verification_page,
                             tree_page,
                             number_input_page,
+                            markdown_page,
+                            code_page,
+                            chat_page,
+                            timeline_page,
+                            contribution_page,
+                            upload_page,
+                            media_page,
                         ),
                         None,
                         None,
@@ -853,6 +979,27 @@ impl MatchEvent for HomePage {
                     "tab_number_input" => {
                         router.nav_to(cx, id!(number_input_page));
                     }
+                    "tab_markdown" => {
+                        router.nav_to(cx, id!(markdown_page));
+                    }
+                    "tab_code" => {
+                        router.nav_to(cx, id!(code_page));
+                    }
+                    "tab_chat" => {
+                        router.nav_to(cx, id!(chat_page));
+                    }
+                    "tab_timeline" => {
+                        router.nav_to(cx, id!(timeline_page));
+                    }
+                    "tab_contribution" => {
+                        router.nav_to(cx, id!(contribution_page));
+                    }
+                    "tab_upload" => {
+                        router.nav_to(cx, id!(upload_page));
+                    }
+                    "tab_media" => {
+                        router.nav_to(cx, id!(media_page));
+                    }
                     _ => {}
                 }
             }
@@ -35,13 +35,14 @@ live_design! {
                         spacing: 20.0,
                     }
                 }
-
+                confirm_btn = <GButton> {slot: {text: "Confirm Dialog"}}
+                prompt_btn = <GButton> {slot: {text: "Prompt Dialog"}}
+                result = <GLabel> {text: "Result: -"}
             }
             desc = {
-                text: ""
+                text: "Dialogs opened by `cx.confirm_dialog` / `cx.prompt_dialog` are drawn by the `GDialogManager` at the app root, the result comes back as `DialogEvent::Closed`"
             }
         }
-
     }
 }
 
@@ -49,6 +50,10 @@ live_design! {
 pub struct DialogPage {
     #[deref]
     pub deref_widget: GView,
+    #[rust]
+    confirm_id: Option<DialogId>,
+    #[rust]
+    prompt_id: Option<DialogId>,
 }
 
 impl LiveHook for DialogPage {
@@ -72,6 +77,19 @@ impl Widget for DialogPage {
 
 impl MatchEvent for DialogPage {
     fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        if let Some(_) = self.gbutton(id!(confirm_btn)).clicked(actions) {
+            self.confirm_id = cx.confirm_dialog("Delete file", "Are you sure to delete this file?");
+        }
+        if let Some(_) = self.gbutton(id!(prompt_btn)).clicked(actions) {
+            self.prompt_id = cx.prompt_dialog("Rename", "Input the new name", "new name...");
+        }
+        for id in [self.confirm_id, self.prompt_id].into_iter().flatten() {
+            if let Some(result) = dialog_closed(actions, id) {
+                let _ = self
+                    .glabel(id!(result))
+                    .set_text(cx, format!("Result: {:?}", result));
+            }
+        }
     }
 }
 
@@ -218,6 +218,156 @@ live_design! {
                             }
                         }
                 }
+                // collapse the menu to an icon-only rail, sub menus open on hover
+                rail_toggle = <GButton> {
+                    slot: {text: "Toggle rail"}
+                }
+                rail_menu = <GMenu> {
+                    style: {basic: {container: {width: 220.0, height: Fit}}},
+                    body: {
+                        <GMenuItem> {
+                            value: "home",
+                            icon: <IconHome> {}
+                            text: <GLabel> {text: "Home"}
+                        }
+                        <GMenuItem> {
+                            value: "gallery",
+                            icon: <IconGallery> {}
+                            text: <GLabel> {text: "Gallery"}
+                        }
+                        <GSubMenu> {
+                            value: "charts",
+                            header: <GView> {
+                                style: {basic: {flow: Right, height: Fit, align: {y: 0.5}}},
+                                <IconChart> {}
+                                <GLabel> {text: "Charts"}
+                            }
+                            body: <GView> {
+                                <GMenuItem> {
+                                    value: "charts_scatter",
+                                    text: <GLabel> {text: "Scatter"}
+                                }
+                            }
+                        }
+                    }
+                }
+                // menu bar, alt + f / alt + e opens the menus
+                <GMenu> {
+                    mode: Horizontal,
+                    style: {basic: {container: {height: Fit, width: Fill}}},
+                    body: {
+                        <GSubMenu> {
+                            value: "file",
+                            mnemonic: "F",
+                            style: {basic: {container: {width: Fit}, header: {width: Fit}}},
+                            header: <GView> {
+                                <GLabel> {text: "File"}
+                            }
+                            body: <GView> {
+                                style: {basic: {width: 180.0}},
+                                <GMenuItem> {
+                                    value: "file_new",
+                                    text: <GLabel> {text: "New"}
+                                    shortcut: {text: "Ctrl+N"}
+                                }
+                                <GMenuItem> {
+                                    value: "file_open",
+                                    text: <GLabel> {text: "Open"}
+                                    shortcut: {text: "Ctrl+O"}
+                                }
+                                <GDivider> {}
+                                <GSubMenu> {
+                                    value: "file_recent",
+                                    header: <GView> {
+                                        <GLabel> {text: "Open Recent"}
+                                    }
+                                    body: <GView> {
+                                        style: {basic: {width: 160.0}},
+                                        <GMenuItem> {
+                                            value: "file_recent_0",
+                                            text: <GLabel> {text: "main.rs"}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        <GSubMenu> {
+                            value: "edit",
+                            mnemonic: "E",
+                            style: {basic: {container: {width: Fit}, header: {width: Fit}}},
+                            header: <GView> {
+                                <GLabel> {text: "Edit"}
+                            }
+                            body: <GView> {
+                                style: {basic: {width: 180.0}},
+                                <GMenuItem> {
+                                    value: "edit_undo",
+                                    text: <GLabel> {text: "Undo"}
+                                    shortcut: {text: "Ctrl+Z"}
+                                }
+                                <GMenuItem> {
+                                    value: "edit_redo",
+                                    text: <GLabel> {text: "Redo"}
+                                    shortcut: {text: "Ctrl+Y"}
+                                }
+                            }
+                        }
+                    }
+                }
+                // right click to open a context menu, sub menus fly out on hover,
+                // use arrow keys / enter to navigate and escape to close
+                <GDropDown> {
+                    mode: Popover,
+                    trigger_mode: ContextMenu,
+                    style: {basic: {height: 120.0, width: Fill, align: {x: 0.5, y: 0.5}, background_visible: true}},
+                    <GLabel> {
+                        text: "Right click here",
+                    }
+                    popup: {
+                        style: {basic: {height: Fit, width: Fit, background_visible: false}},
+                        popup: {
+                            <GMenu> {
+                                style: {basic: {container: {width: 200.0, height: Fit}}},
+                                body: {
+                                    <GMenuItem> {
+                                        value: "copy",
+                                        text: <GLabel> {text: "Copy"}
+                                        shortcut: {text: "Ctrl+C"}
+                                    }
+                                    <GMenuItem> {
+                                        value: "paste",
+                                        text: <GLabel> {text: "Paste"}
+                                        shortcut: {text: "Ctrl+V"}
+                                    }
+                                    <GMenuItem> {
+                                        value: "delete",
+                                        disabled: true,
+                                        text: <GLabel> {text: "Delete"}
+                                    }
+                                    <GDivider> {}
+                                    <GSubMenu> {
+                                        value: "share",
+                                        flyout: true,
+                                        header: <GView> {
+                                            <GLabel> {text: "Share"}
+                                        }
+                                        body: <GView> {
+                                            style: {basic: {width: 160.0}},
+                                            <GMenuItem> {
+                                                value: "share_mail",
+                                                text: <GLabel> {text: "Mail"}
+                                            }
+                                            <GMenuItem> {
+                                                value: "share_link",
+                                                text: <GLabel> {text: "Copy link"}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
             }
             desc = {
                 text: ""
@@ -252,7 +402,11 @@ impl Widget for MenuPage {
 }
 
 impl MatchEvent for MenuPage {
-    fn handle_actions(&mut self, _cx: &mut Cx, _actions: &Actions) {}
+    fn handle_actions(&mut self, cx: &mut Cx, actions: &Actions) {
+        if let Some(_) = self.gbutton(id!(rail_toggle)).clicked(actions) {
+            self.gmenu(id!(rail_menu)).toggle_collapsed(cx);
+        }
+    }
 }
 
 widget_node!(MenuPage);
@@ -115,6 +115,34 @@ live_design! {
                 text: ""
             }
         }
+        <CBox> {
+            show = {
+                style: {
+                    basic: {
+                        height: Fit,
+                        width: Fill,
+                        flow: Right,
+                        spacing: 20.0,
+                    }
+                }
+                <GToolTip> {
+                    position: Left
+                    <GButton> {
+                        slot: {text: "Left (flipped)"}
+                    }
+                }
+                <GToolTip> {
+                    position: Left
+                    auto_placement: false
+                    <GButton> {
+                        slot: {text: "Left (fixed)"}
+                    }
+                }
+            }
+            desc = {
+                text: "Popovers and tooltips flip to the other side and shift along it to stay inside the window, set `auto_placement: false` to keep the fixed position"
+            }
+        }
     }
 }
 